@@ -1,11 +1,14 @@
+use std::f64::consts::{PI, TAU};
+
 use nalgebra::Point2;
 use tui::{
     backend::Backend,
     layout::Rect,
-    style::Color,
+    style::{Color, Style},
     symbols,
+    text::Span,
     widgets::{
-        canvas::{Canvas, Line, Points},
+        canvas::{Canvas, Line, Painter, Points, Shape},
         Block, Borders,
     },
     Frame,
@@ -13,7 +16,35 @@ use tui::{
 
 use crate::app::App;
 
-use super::graham_scan::GrahamScan;
+use super::{
+    art_gallery::ArtGallery,
+    bentley_ottmann::BentleyOttmann,
+    bowyer_watson::BowyerWatson,
+    chans_algorithm::ChansAlgorithm,
+    closest_pair::ClosestPair,
+    divide_and_conquer_hull::DivideAndConquerHull,
+    ear_clipping::EarClipping,
+    fortune::Fortune,
+    geometry::{clip_segment, Segment},
+    graham_scan::GrahamScan,
+    greiner_hormann::GreinerHormann,
+    jarvis_march::JarvisMarch,
+    kd_tree::KdTree,
+    linear_programming::LinearProgramming,
+    map_overlay::MapOverlay,
+    melkman::Melkman,
+    minimum_enclosing_disk::MinimumEnclosingDisk,
+    minkowski_sum::MinkowskiSum,
+    monotone_triangulation::MonotoneTriangulation,
+    quick_hull::QuickHull,
+    randomized_incremental_delaunay::RandomizedIncrementalDelaunay,
+    randomized_incremental_hull::RandomizedIncrementalHull,
+    range_tree::RangeTree,
+    rotating_calipers::RotatingCalipers,
+    trapezoidal_map::TrapezoidalMap,
+    visibility_graph::VisibilityGraph,
+    voronoi_diagram::VoronoiDiagram,
+};
 
 /// # Different drawing methods.
 /// Defines how the steps of an algorithm
@@ -22,18 +53,379 @@ pub enum DrawMethod {
     Edge,
 }
 
+/// # Different input kinds.
+/// Defines which kind of input the app
+/// generates for an algorithm.
+pub enum InputKind {
+    PointCloud,
+    SimplePolyline,
+    SimplePolygon,
+    CombPolygon,
+    Segments,
+    NonCrossingSegments,
+    SimplePolygonPair,
+    ConvexObstacles,
+}
+
+/// # Different query kinds.
+/// Defines which kind of query the user can enter
+/// for an algorithm. Algorithms with the same kind
+/// share the last query entered.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum QueryKind {
+    Rectangle,
+    Point,
+    Objective,
+    StartAndGoal,
+}
+
+impl QueryKind {
+    /// Retrieves the format of the numbers the user enters.
+    pub fn format(&self) -> &str {
+        match self {
+            QueryKind::Rectangle => "x1 y1 x2 y2",
+            QueryKind::Point => "x y",
+            QueryKind::Objective => "cx cy",
+            QueryKind::StartAndGoal => "sx sy gx gy",
+        }
+    }
+}
+
+/// # Drawable parts of a step.
+/// Every step of an algorithm is a collection of these.
+/// Lines and circles are rendered below the initial points,
+/// highlighted points are rendered on top of them. Labels are
+/// printed on top of everything.
+#[derive(Clone)]
+pub enum Drawable {
+    Line(Line),
+    Circle(Circle),
+    Points(Vec<(f64, f64)>, Color),
+    Label(Label),
+}
+
+/// # Text label.
+/// Printed with its first character at the given position.
+#[derive(Clone)]
+pub struct Label {
+    pub x: f64,
+    pub y: f64,
+    pub text: String,
+    pub color: Color,
+}
+
+/// # Circle shape.
+/// The canvas has no circle shape, so it is sampled along its circumference.
+#[derive(Clone)]
+pub struct Circle {
+    pub x: f64,
+    pub y: f64,
+    pub radius: f64,
+    pub color: Color,
+}
+
+impl Shape for Circle {
+    fn draw(&self, painter: &mut Painter) {
+        // Sample densely enough to get connected dots on the Braille grid.
+        let samples = (self.radius * 4.0 * PI).clamp(64.0, 20_000.0) as usize;
+        for sample in 0..samples {
+            let angle = sample as f64 * TAU / samples as f64;
+            let x = self.x + self.radius * angle.cos();
+            let y = self.y + self.radius * angle.sin();
+            if let Some((x, y)) = painter.get_point(x, y) {
+                painter.paint(x, y, self.color);
+            }
+        }
+    }
+}
+
+impl Drawable {
+    pub fn line(from: &Point2<f64>, to: &Point2<f64>, color: Color) -> Self {
+        Drawable::Line(Line {
+            x1: from.x,
+            x2: to.x,
+            y1: from.y,
+            y2: to.y,
+            color,
+        })
+    }
+
+    pub fn circle(center: &Point2<f64>, radius: f64, color: Color) -> Self {
+        Drawable::Circle(Circle {
+            x: center.x,
+            y: center.y,
+            radius,
+            color,
+        })
+    }
+
+    pub fn label(position: &Point2<f64>, text: String, color: Color) -> Self {
+        Drawable::Label(Label {
+            x: position.x,
+            y: position.y,
+            text,
+            color,
+        })
+    }
+
+    pub fn points(points: &[Point2<f64>], color: Color) -> Self {
+        Drawable::Points(
+            points.iter().map(|point| (point.x, point.y)).collect(),
+            color,
+        )
+    }
+}
+
 /// This is a wrapper for usage in a vector.
 /// Because we are not allowed to use trait objects,
 /// we need this abstraction.
 pub enum AlgorithmWrapper {
     GrahamScan(GrahamScan),
+    JarvisMarch(JarvisMarch),
+    QuickHull(QuickHull),
+    ChansAlgorithm(ChansAlgorithm),
+    DivideAndConquerHull(DivideAndConquerHull),
+    RandomizedIncrementalHull(RandomizedIncrementalHull),
+    Melkman(Melkman),
+    BentleyOttmann(BentleyOttmann),
+    EarClipping(EarClipping),
+    MonotoneTriangulation(MonotoneTriangulation),
+    ArtGallery(ArtGallery),
+    BowyerWatson(BowyerWatson),
+    RandomizedIncrementalDelaunay(RandomizedIncrementalDelaunay),
+    Fortune(Fortune),
+    VoronoiDiagram(VoronoiDiagram),
+    ClosestPair(ClosestPair),
+    KdTree(KdTree),
+    RangeTree(RangeTree),
+    TrapezoidalMap(TrapezoidalMap),
+    MapOverlay(MapOverlay),
+    LinearProgramming(LinearProgramming),
+    MinimumEnclosingDisk(MinimumEnclosingDisk),
+    RotatingCalipers(RotatingCalipers),
+    MinkowskiSum(MinkowskiSum),
+    GreinerHormann(GreinerHormann),
+    VisibilityGraph(VisibilityGraph),
 }
 
 impl AlgorithmWrapper {
+    /// Retrieves the title of the algorithm shown in its tab.
+    pub fn get_title(&self) -> &str {
+        match self {
+            AlgorithmWrapper::GrahamScan(algorithm) => algorithm.get_title(),
+            AlgorithmWrapper::JarvisMarch(algorithm) => algorithm.get_title(),
+            AlgorithmWrapper::QuickHull(algorithm) => algorithm.get_title(),
+            AlgorithmWrapper::ChansAlgorithm(algorithm) => algorithm.get_title(),
+            AlgorithmWrapper::DivideAndConquerHull(algorithm) => algorithm.get_title(),
+            AlgorithmWrapper::RandomizedIncrementalHull(algorithm) => algorithm.get_title(),
+            AlgorithmWrapper::Melkman(algorithm) => algorithm.get_title(),
+            AlgorithmWrapper::BentleyOttmann(algorithm) => algorithm.get_title(),
+            AlgorithmWrapper::EarClipping(algorithm) => algorithm.get_title(),
+            AlgorithmWrapper::MonotoneTriangulation(algorithm) => algorithm.get_title(),
+            AlgorithmWrapper::ArtGallery(algorithm) => algorithm.get_title(),
+            AlgorithmWrapper::BowyerWatson(algorithm) => algorithm.get_title(),
+            AlgorithmWrapper::RandomizedIncrementalDelaunay(algorithm) => algorithm.get_title(),
+            AlgorithmWrapper::Fortune(algorithm) => algorithm.get_title(),
+            AlgorithmWrapper::VoronoiDiagram(algorithm) => algorithm.get_title(),
+            AlgorithmWrapper::ClosestPair(algorithm) => algorithm.get_title(),
+            AlgorithmWrapper::KdTree(algorithm) => algorithm.get_title(),
+            AlgorithmWrapper::RangeTree(algorithm) => algorithm.get_title(),
+            AlgorithmWrapper::TrapezoidalMap(algorithm) => algorithm.get_title(),
+            AlgorithmWrapper::MapOverlay(algorithm) => algorithm.get_title(),
+            AlgorithmWrapper::LinearProgramming(algorithm) => algorithm.get_title(),
+            AlgorithmWrapper::MinimumEnclosingDisk(algorithm) => algorithm.get_title(),
+            AlgorithmWrapper::RotatingCalipers(algorithm) => algorithm.get_title(),
+            AlgorithmWrapper::MinkowskiSum(algorithm) => algorithm.get_title(),
+            AlgorithmWrapper::GreinerHormann(algorithm) => algorithm.get_title(),
+            AlgorithmWrapper::VisibilityGraph(algorithm) => algorithm.get_title(),
+        }
+    }
+
     /// Sets the initial point set the algorithm works with.
     pub fn set_points(&mut self, points: Vec<Point2<f64>>) {
         match self {
             AlgorithmWrapper::GrahamScan(algorithm) => algorithm.set_points(points),
+            AlgorithmWrapper::JarvisMarch(algorithm) => algorithm.set_points(points),
+            AlgorithmWrapper::QuickHull(algorithm) => algorithm.set_points(points),
+            AlgorithmWrapper::ChansAlgorithm(algorithm) => algorithm.set_points(points),
+            AlgorithmWrapper::DivideAndConquerHull(algorithm) => algorithm.set_points(points),
+            AlgorithmWrapper::RandomizedIncrementalHull(algorithm) => algorithm.set_points(points),
+            AlgorithmWrapper::Melkman(algorithm) => algorithm.set_points(points),
+            AlgorithmWrapper::BentleyOttmann(algorithm) => algorithm.set_points(points),
+            AlgorithmWrapper::EarClipping(algorithm) => algorithm.set_points(points),
+            AlgorithmWrapper::MonotoneTriangulation(algorithm) => algorithm.set_points(points),
+            AlgorithmWrapper::ArtGallery(algorithm) => algorithm.set_points(points),
+            AlgorithmWrapper::BowyerWatson(algorithm) => algorithm.set_points(points),
+            AlgorithmWrapper::Fortune(algorithm) => algorithm.set_points(points),
+            AlgorithmWrapper::VoronoiDiagram(algorithm) => algorithm.set_points(points),
+            AlgorithmWrapper::ClosestPair(algorithm) => algorithm.set_points(points),
+            AlgorithmWrapper::KdTree(algorithm) => algorithm.set_points(points),
+            AlgorithmWrapper::RangeTree(algorithm) => algorithm.set_points(points),
+            AlgorithmWrapper::TrapezoidalMap(algorithm) => algorithm.set_points(points),
+            AlgorithmWrapper::MapOverlay(algorithm) => algorithm.set_points(points),
+            AlgorithmWrapper::LinearProgramming(algorithm) => algorithm.set_points(points),
+            AlgorithmWrapper::MinimumEnclosingDisk(algorithm) => algorithm.set_points(points),
+            AlgorithmWrapper::RotatingCalipers(algorithm) => algorithm.set_points(points),
+            AlgorithmWrapper::MinkowskiSum(algorithm) => algorithm.set_points(points),
+            AlgorithmWrapper::GreinerHormann(algorithm) => algorithm.set_points(points),
+            AlgorithmWrapper::VisibilityGraph(algorithm) => algorithm.set_points(points),
+            AlgorithmWrapper::RandomizedIncrementalDelaunay(algorithm) => {
+                algorithm.set_points(points)
+            }
+        }
+    }
+
+    /// Sets the initial segments the algorithm works with.
+    pub fn set_segments(&mut self, segments: Vec<Segment>) {
+        match self {
+            AlgorithmWrapper::GrahamScan(algorithm) => algorithm.set_segments(segments),
+            AlgorithmWrapper::JarvisMarch(algorithm) => algorithm.set_segments(segments),
+            AlgorithmWrapper::QuickHull(algorithm) => algorithm.set_segments(segments),
+            AlgorithmWrapper::ChansAlgorithm(algorithm) => algorithm.set_segments(segments),
+            AlgorithmWrapper::DivideAndConquerHull(algorithm) => algorithm.set_segments(segments),
+            AlgorithmWrapper::BentleyOttmann(algorithm) => algorithm.set_segments(segments),
+            AlgorithmWrapper::EarClipping(algorithm) => algorithm.set_segments(segments),
+            AlgorithmWrapper::MonotoneTriangulation(algorithm) => algorithm.set_segments(segments),
+            AlgorithmWrapper::ArtGallery(algorithm) => algorithm.set_segments(segments),
+            AlgorithmWrapper::BowyerWatson(algorithm) => algorithm.set_segments(segments),
+            AlgorithmWrapper::Fortune(algorithm) => algorithm.set_segments(segments),
+            AlgorithmWrapper::VoronoiDiagram(algorithm) => algorithm.set_segments(segments),
+            AlgorithmWrapper::ClosestPair(algorithm) => algorithm.set_segments(segments),
+            AlgorithmWrapper::KdTree(algorithm) => algorithm.set_segments(segments),
+            AlgorithmWrapper::RangeTree(algorithm) => algorithm.set_segments(segments),
+            AlgorithmWrapper::TrapezoidalMap(algorithm) => algorithm.set_segments(segments),
+            AlgorithmWrapper::MapOverlay(algorithm) => algorithm.set_segments(segments),
+            AlgorithmWrapper::LinearProgramming(algorithm) => algorithm.set_segments(segments),
+            AlgorithmWrapper::MinimumEnclosingDisk(algorithm) => algorithm.set_segments(segments),
+            AlgorithmWrapper::RotatingCalipers(algorithm) => algorithm.set_segments(segments),
+            AlgorithmWrapper::MinkowskiSum(algorithm) => algorithm.set_segments(segments),
+            AlgorithmWrapper::GreinerHormann(algorithm) => algorithm.set_segments(segments),
+            AlgorithmWrapper::VisibilityGraph(algorithm) => algorithm.set_segments(segments),
+            AlgorithmWrapper::RandomizedIncrementalDelaunay(algorithm) => {
+                algorithm.set_segments(segments)
+            }
+            AlgorithmWrapper::RandomizedIncrementalHull(algorithm) => {
+                algorithm.set_segments(segments)
+            }
+            AlgorithmWrapper::Melkman(algorithm) => algorithm.set_segments(segments),
+        }
+    }
+
+    /// Sets the initial polygons the algorithm works with.
+    pub fn set_polygons(&mut self, polygons: Vec<Vec<Point2<f64>>>) {
+        match self {
+            AlgorithmWrapper::GrahamScan(algorithm) => algorithm.set_polygons(polygons),
+            AlgorithmWrapper::JarvisMarch(algorithm) => algorithm.set_polygons(polygons),
+            AlgorithmWrapper::QuickHull(algorithm) => algorithm.set_polygons(polygons),
+            AlgorithmWrapper::ChansAlgorithm(algorithm) => algorithm.set_polygons(polygons),
+            AlgorithmWrapper::DivideAndConquerHull(algorithm) => algorithm.set_polygons(polygons),
+            AlgorithmWrapper::GreinerHormann(algorithm) => algorithm.set_polygons(polygons),
+            AlgorithmWrapper::VisibilityGraph(algorithm) => algorithm.set_polygons(polygons),
+            AlgorithmWrapper::RandomizedIncrementalHull(algorithm) => {
+                algorithm.set_polygons(polygons)
+            }
+            AlgorithmWrapper::Melkman(algorithm) => algorithm.set_polygons(polygons),
+            AlgorithmWrapper::BentleyOttmann(algorithm) => algorithm.set_polygons(polygons),
+            AlgorithmWrapper::EarClipping(algorithm) => algorithm.set_polygons(polygons),
+            AlgorithmWrapper::MonotoneTriangulation(algorithm) => algorithm.set_polygons(polygons),
+            AlgorithmWrapper::ArtGallery(algorithm) => algorithm.set_polygons(polygons),
+            AlgorithmWrapper::BowyerWatson(algorithm) => algorithm.set_polygons(polygons),
+            AlgorithmWrapper::RandomizedIncrementalDelaunay(algorithm) => {
+                algorithm.set_polygons(polygons)
+            }
+            AlgorithmWrapper::Fortune(algorithm) => algorithm.set_polygons(polygons),
+            AlgorithmWrapper::VoronoiDiagram(algorithm) => algorithm.set_polygons(polygons),
+            AlgorithmWrapper::ClosestPair(algorithm) => algorithm.set_polygons(polygons),
+            AlgorithmWrapper::KdTree(algorithm) => algorithm.set_polygons(polygons),
+            AlgorithmWrapper::RangeTree(algorithm) => algorithm.set_polygons(polygons),
+            AlgorithmWrapper::TrapezoidalMap(algorithm) => algorithm.set_polygons(polygons),
+            AlgorithmWrapper::MapOverlay(algorithm) => algorithm.set_polygons(polygons),
+            AlgorithmWrapper::LinearProgramming(algorithm) => algorithm.set_polygons(polygons),
+            AlgorithmWrapper::MinimumEnclosingDisk(algorithm) => algorithm.set_polygons(polygons),
+            AlgorithmWrapper::RotatingCalipers(algorithm) => algorithm.set_polygons(polygons),
+            AlgorithmWrapper::MinkowskiSum(algorithm) => algorithm.set_polygons(polygons),
+        }
+    }
+
+    /// Sets the bounds the input of the algorithm is generated in.
+    pub fn set_bounds(&mut self, x_bounds: [f64; 2], y_bounds: [f64; 2]) {
+        match self {
+            AlgorithmWrapper::GrahamScan(algorithm) => algorithm.set_bounds(x_bounds, y_bounds),
+            AlgorithmWrapper::JarvisMarch(algorithm) => algorithm.set_bounds(x_bounds, y_bounds),
+            AlgorithmWrapper::QuickHull(algorithm) => algorithm.set_bounds(x_bounds, y_bounds),
+            AlgorithmWrapper::ChansAlgorithm(algorithm) => algorithm.set_bounds(x_bounds, y_bounds),
+            AlgorithmWrapper::VoronoiDiagram(algorithm) => algorithm.set_bounds(x_bounds, y_bounds),
+            AlgorithmWrapper::ClosestPair(algorithm) => algorithm.set_bounds(x_bounds, y_bounds),
+            AlgorithmWrapper::KdTree(algorithm) => algorithm.set_bounds(x_bounds, y_bounds),
+            AlgorithmWrapper::RangeTree(algorithm) => algorithm.set_bounds(x_bounds, y_bounds),
+            AlgorithmWrapper::TrapezoidalMap(algorithm) => algorithm.set_bounds(x_bounds, y_bounds),
+            AlgorithmWrapper::MapOverlay(algorithm) => algorithm.set_bounds(x_bounds, y_bounds),
+            AlgorithmWrapper::MinkowskiSum(algorithm) => algorithm.set_bounds(x_bounds, y_bounds),
+            AlgorithmWrapper::GreinerHormann(algorithm) => algorithm.set_bounds(x_bounds, y_bounds),
+            AlgorithmWrapper::VisibilityGraph(algorithm) => {
+                algorithm.set_bounds(x_bounds, y_bounds)
+            }
+            AlgorithmWrapper::RotatingCalipers(algorithm) => {
+                algorithm.set_bounds(x_bounds, y_bounds)
+            }
+            AlgorithmWrapper::MinimumEnclosingDisk(algorithm) => {
+                algorithm.set_bounds(x_bounds, y_bounds)
+            }
+            AlgorithmWrapper::LinearProgramming(algorithm) => {
+                algorithm.set_bounds(x_bounds, y_bounds)
+            }
+            AlgorithmWrapper::DivideAndConquerHull(algorithm) => {
+                algorithm.set_bounds(x_bounds, y_bounds)
+            }
+            AlgorithmWrapper::RandomizedIncrementalHull(algorithm) => {
+                algorithm.set_bounds(x_bounds, y_bounds)
+            }
+            AlgorithmWrapper::Melkman(algorithm) => algorithm.set_bounds(x_bounds, y_bounds),
+            AlgorithmWrapper::BentleyOttmann(algorithm) => algorithm.set_bounds(x_bounds, y_bounds),
+            AlgorithmWrapper::EarClipping(algorithm) => algorithm.set_bounds(x_bounds, y_bounds),
+            AlgorithmWrapper::MonotoneTriangulation(algorithm) => {
+                algorithm.set_bounds(x_bounds, y_bounds)
+            }
+            AlgorithmWrapper::ArtGallery(algorithm) => algorithm.set_bounds(x_bounds, y_bounds),
+            AlgorithmWrapper::BowyerWatson(algorithm) => algorithm.set_bounds(x_bounds, y_bounds),
+            AlgorithmWrapper::RandomizedIncrementalDelaunay(algorithm) => {
+                algorithm.set_bounds(x_bounds, y_bounds)
+            }
+            AlgorithmWrapper::Fortune(algorithm) => algorithm.set_bounds(x_bounds, y_bounds),
+        }
+    }
+
+    /// Retrieves the kind of input the algorithm works with.
+    pub fn get_input_kind(&self) -> InputKind {
+        match self {
+            AlgorithmWrapper::GrahamScan(algorithm) => algorithm.get_input_kind(),
+            AlgorithmWrapper::JarvisMarch(algorithm) => algorithm.get_input_kind(),
+            AlgorithmWrapper::QuickHull(algorithm) => algorithm.get_input_kind(),
+            AlgorithmWrapper::ChansAlgorithm(algorithm) => algorithm.get_input_kind(),
+            AlgorithmWrapper::DivideAndConquerHull(algorithm) => algorithm.get_input_kind(),
+            AlgorithmWrapper::RandomizedIncrementalHull(algorithm) => algorithm.get_input_kind(),
+            AlgorithmWrapper::Melkman(algorithm) => algorithm.get_input_kind(),
+            AlgorithmWrapper::BentleyOttmann(algorithm) => algorithm.get_input_kind(),
+            AlgorithmWrapper::EarClipping(algorithm) => algorithm.get_input_kind(),
+            AlgorithmWrapper::MonotoneTriangulation(algorithm) => algorithm.get_input_kind(),
+            AlgorithmWrapper::ArtGallery(algorithm) => algorithm.get_input_kind(),
+            AlgorithmWrapper::BowyerWatson(algorithm) => algorithm.get_input_kind(),
+            AlgorithmWrapper::Fortune(algorithm) => algorithm.get_input_kind(),
+            AlgorithmWrapper::VoronoiDiagram(algorithm) => algorithm.get_input_kind(),
+            AlgorithmWrapper::ClosestPair(algorithm) => algorithm.get_input_kind(),
+            AlgorithmWrapper::KdTree(algorithm) => algorithm.get_input_kind(),
+            AlgorithmWrapper::RangeTree(algorithm) => algorithm.get_input_kind(),
+            AlgorithmWrapper::TrapezoidalMap(algorithm) => algorithm.get_input_kind(),
+            AlgorithmWrapper::MapOverlay(algorithm) => algorithm.get_input_kind(),
+            AlgorithmWrapper::LinearProgramming(algorithm) => algorithm.get_input_kind(),
+            AlgorithmWrapper::MinimumEnclosingDisk(algorithm) => algorithm.get_input_kind(),
+            AlgorithmWrapper::RotatingCalipers(algorithm) => algorithm.get_input_kind(),
+            AlgorithmWrapper::MinkowskiSum(algorithm) => algorithm.get_input_kind(),
+            AlgorithmWrapper::GreinerHormann(algorithm) => algorithm.get_input_kind(),
+            AlgorithmWrapper::VisibilityGraph(algorithm) => algorithm.get_input_kind(),
+            AlgorithmWrapper::RandomizedIncrementalDelaunay(algorithm) => {
+                algorithm.get_input_kind()
+            }
         }
     }
 
@@ -41,6 +433,132 @@ impl AlgorithmWrapper {
     pub fn calculate(&mut self) {
         match self {
             AlgorithmWrapper::GrahamScan(algorithm) => algorithm.calculate(),
+            AlgorithmWrapper::JarvisMarch(algorithm) => algorithm.calculate(),
+            AlgorithmWrapper::QuickHull(algorithm) => algorithm.calculate(),
+            AlgorithmWrapper::ChansAlgorithm(algorithm) => algorithm.calculate(),
+            AlgorithmWrapper::DivideAndConquerHull(algorithm) => algorithm.calculate(),
+            AlgorithmWrapper::RandomizedIncrementalHull(algorithm) => algorithm.calculate(),
+            AlgorithmWrapper::Melkman(algorithm) => algorithm.calculate(),
+            AlgorithmWrapper::BentleyOttmann(algorithm) => algorithm.calculate(),
+            AlgorithmWrapper::EarClipping(algorithm) => algorithm.calculate(),
+            AlgorithmWrapper::MonotoneTriangulation(algorithm) => algorithm.calculate(),
+            AlgorithmWrapper::ArtGallery(algorithm) => algorithm.calculate(),
+            AlgorithmWrapper::BowyerWatson(algorithm) => algorithm.calculate(),
+            AlgorithmWrapper::RandomizedIncrementalDelaunay(algorithm) => algorithm.calculate(),
+            AlgorithmWrapper::Fortune(algorithm) => algorithm.calculate(),
+            AlgorithmWrapper::VoronoiDiagram(algorithm) => algorithm.calculate(),
+            AlgorithmWrapper::ClosestPair(algorithm) => algorithm.calculate(),
+            AlgorithmWrapper::KdTree(algorithm) => algorithm.calculate(),
+            AlgorithmWrapper::RangeTree(algorithm) => algorithm.calculate(),
+            AlgorithmWrapper::TrapezoidalMap(algorithm) => algorithm.calculate(),
+            AlgorithmWrapper::MapOverlay(algorithm) => algorithm.calculate(),
+            AlgorithmWrapper::LinearProgramming(algorithm) => algorithm.calculate(),
+            AlgorithmWrapper::MinimumEnclosingDisk(algorithm) => algorithm.calculate(),
+            AlgorithmWrapper::RotatingCalipers(algorithm) => algorithm.calculate(),
+            AlgorithmWrapper::MinkowskiSum(algorithm) => algorithm.calculate(),
+            AlgorithmWrapper::GreinerHormann(algorithm) => algorithm.calculate(),
+            AlgorithmWrapper::VisibilityGraph(algorithm) => algorithm.calculate(),
+        }
+    }
+
+    /// Switches the algorithm to its next mode. Returns whether it has modes at all.
+    pub fn switch_mode(&mut self) -> bool {
+        match self {
+            AlgorithmWrapper::GrahamScan(algorithm) => algorithm.switch_mode(),
+            AlgorithmWrapper::JarvisMarch(algorithm) => algorithm.switch_mode(),
+            AlgorithmWrapper::QuickHull(algorithm) => algorithm.switch_mode(),
+            AlgorithmWrapper::ChansAlgorithm(algorithm) => algorithm.switch_mode(),
+            AlgorithmWrapper::DivideAndConquerHull(algorithm) => algorithm.switch_mode(),
+            AlgorithmWrapper::RandomizedIncrementalHull(algorithm) => algorithm.switch_mode(),
+            AlgorithmWrapper::Melkman(algorithm) => algorithm.switch_mode(),
+            AlgorithmWrapper::BentleyOttmann(algorithm) => algorithm.switch_mode(),
+            AlgorithmWrapper::EarClipping(algorithm) => algorithm.switch_mode(),
+            AlgorithmWrapper::MonotoneTriangulation(algorithm) => algorithm.switch_mode(),
+            AlgorithmWrapper::ArtGallery(algorithm) => algorithm.switch_mode(),
+            AlgorithmWrapper::BowyerWatson(algorithm) => algorithm.switch_mode(),
+            AlgorithmWrapper::RandomizedIncrementalDelaunay(algorithm) => algorithm.switch_mode(),
+            AlgorithmWrapper::Fortune(algorithm) => algorithm.switch_mode(),
+            AlgorithmWrapper::VoronoiDiagram(algorithm) => algorithm.switch_mode(),
+            AlgorithmWrapper::ClosestPair(algorithm) => algorithm.switch_mode(),
+            AlgorithmWrapper::KdTree(algorithm) => algorithm.switch_mode(),
+            AlgorithmWrapper::RangeTree(algorithm) => algorithm.switch_mode(),
+            AlgorithmWrapper::TrapezoidalMap(algorithm) => algorithm.switch_mode(),
+            AlgorithmWrapper::MapOverlay(algorithm) => algorithm.switch_mode(),
+            AlgorithmWrapper::LinearProgramming(algorithm) => algorithm.switch_mode(),
+            AlgorithmWrapper::MinimumEnclosingDisk(algorithm) => algorithm.switch_mode(),
+            AlgorithmWrapper::RotatingCalipers(algorithm) => algorithm.switch_mode(),
+            AlgorithmWrapper::MinkowskiSum(algorithm) => algorithm.switch_mode(),
+            AlgorithmWrapper::GreinerHormann(algorithm) => algorithm.switch_mode(),
+            AlgorithmWrapper::VisibilityGraph(algorithm) => algorithm.switch_mode(),
+        }
+    }
+
+    /// Retrieves the kind of query the user can enter, if the algorithm
+    /// answers queries.
+    pub fn get_query_kind(&self) -> Option<QueryKind> {
+        match self {
+            AlgorithmWrapper::GrahamScan(algorithm) => algorithm.get_query_kind(),
+            AlgorithmWrapper::JarvisMarch(algorithm) => algorithm.get_query_kind(),
+            AlgorithmWrapper::QuickHull(algorithm) => algorithm.get_query_kind(),
+            AlgorithmWrapper::ChansAlgorithm(algorithm) => algorithm.get_query_kind(),
+            AlgorithmWrapper::DivideAndConquerHull(algorithm) => algorithm.get_query_kind(),
+            AlgorithmWrapper::RandomizedIncrementalHull(algorithm) => algorithm.get_query_kind(),
+            AlgorithmWrapper::Melkman(algorithm) => algorithm.get_query_kind(),
+            AlgorithmWrapper::BentleyOttmann(algorithm) => algorithm.get_query_kind(),
+            AlgorithmWrapper::EarClipping(algorithm) => algorithm.get_query_kind(),
+            AlgorithmWrapper::MonotoneTriangulation(algorithm) => algorithm.get_query_kind(),
+            AlgorithmWrapper::ArtGallery(algorithm) => algorithm.get_query_kind(),
+            AlgorithmWrapper::BowyerWatson(algorithm) => algorithm.get_query_kind(),
+            AlgorithmWrapper::KdTree(algorithm) => algorithm.get_query_kind(),
+            AlgorithmWrapper::RangeTree(algorithm) => algorithm.get_query_kind(),
+            AlgorithmWrapper::TrapezoidalMap(algorithm) => algorithm.get_query_kind(),
+            AlgorithmWrapper::MapOverlay(algorithm) => algorithm.get_query_kind(),
+            AlgorithmWrapper::LinearProgramming(algorithm) => algorithm.get_query_kind(),
+            AlgorithmWrapper::MinimumEnclosingDisk(algorithm) => algorithm.get_query_kind(),
+            AlgorithmWrapper::RotatingCalipers(algorithm) => algorithm.get_query_kind(),
+            AlgorithmWrapper::MinkowskiSum(algorithm) => algorithm.get_query_kind(),
+            AlgorithmWrapper::GreinerHormann(algorithm) => algorithm.get_query_kind(),
+            AlgorithmWrapper::VisibilityGraph(algorithm) => algorithm.get_query_kind(),
+            AlgorithmWrapper::RandomizedIncrementalDelaunay(algorithm) => {
+                algorithm.get_query_kind()
+            }
+            AlgorithmWrapper::Fortune(algorithm) => algorithm.get_query_kind(),
+            AlgorithmWrapper::VoronoiDiagram(algorithm) => algorithm.get_query_kind(),
+            AlgorithmWrapper::ClosestPair(algorithm) => algorithm.get_query_kind(),
+        }
+    }
+
+    /// Sets the query entered by the user. Returns whether it matches the format.
+    pub fn set_query(&mut self, query: Vec<f64>) -> bool {
+        match self {
+            AlgorithmWrapper::GrahamScan(algorithm) => algorithm.set_query(query),
+            AlgorithmWrapper::JarvisMarch(algorithm) => algorithm.set_query(query),
+            AlgorithmWrapper::QuickHull(algorithm) => algorithm.set_query(query),
+            AlgorithmWrapper::ChansAlgorithm(algorithm) => algorithm.set_query(query),
+            AlgorithmWrapper::DivideAndConquerHull(algorithm) => algorithm.set_query(query),
+            AlgorithmWrapper::RandomizedIncrementalHull(algorithm) => algorithm.set_query(query),
+            AlgorithmWrapper::Melkman(algorithm) => algorithm.set_query(query),
+            AlgorithmWrapper::BentleyOttmann(algorithm) => algorithm.set_query(query),
+            AlgorithmWrapper::EarClipping(algorithm) => algorithm.set_query(query),
+            AlgorithmWrapper::MonotoneTriangulation(algorithm) => algorithm.set_query(query),
+            AlgorithmWrapper::ArtGallery(algorithm) => algorithm.set_query(query),
+            AlgorithmWrapper::BowyerWatson(algorithm) => algorithm.set_query(query),
+            AlgorithmWrapper::KdTree(algorithm) => algorithm.set_query(query),
+            AlgorithmWrapper::RangeTree(algorithm) => algorithm.set_query(query),
+            AlgorithmWrapper::TrapezoidalMap(algorithm) => algorithm.set_query(query),
+            AlgorithmWrapper::MapOverlay(algorithm) => algorithm.set_query(query),
+            AlgorithmWrapper::LinearProgramming(algorithm) => algorithm.set_query(query),
+            AlgorithmWrapper::MinimumEnclosingDisk(algorithm) => algorithm.set_query(query),
+            AlgorithmWrapper::RotatingCalipers(algorithm) => algorithm.set_query(query),
+            AlgorithmWrapper::MinkowskiSum(algorithm) => algorithm.set_query(query),
+            AlgorithmWrapper::GreinerHormann(algorithm) => algorithm.set_query(query),
+            AlgorithmWrapper::VisibilityGraph(algorithm) => algorithm.set_query(query),
+            AlgorithmWrapper::RandomizedIncrementalDelaunay(algorithm) => {
+                algorithm.set_query(query)
+            }
+            AlgorithmWrapper::Fortune(algorithm) => algorithm.set_query(query),
+            AlgorithmWrapper::VoronoiDiagram(algorithm) => algorithm.set_query(query),
+            AlgorithmWrapper::ClosestPair(algorithm) => algorithm.set_query(query),
         }
     }
 
@@ -49,6 +567,76 @@ impl AlgorithmWrapper {
     pub fn get_maximum_step_count(&self) -> usize {
         match self {
             AlgorithmWrapper::GrahamScan(algorithm) => algorithm.maximum_step_count,
+            AlgorithmWrapper::JarvisMarch(algorithm) => algorithm.maximum_step_count,
+            AlgorithmWrapper::QuickHull(algorithm) => algorithm.maximum_step_count,
+            AlgorithmWrapper::ChansAlgorithm(algorithm) => algorithm.maximum_step_count,
+            AlgorithmWrapper::DivideAndConquerHull(algorithm) => algorithm.maximum_step_count,
+            AlgorithmWrapper::RandomizedIncrementalHull(algorithm) => algorithm.maximum_step_count,
+            AlgorithmWrapper::Melkman(algorithm) => algorithm.maximum_step_count,
+            AlgorithmWrapper::BentleyOttmann(algorithm) => algorithm.maximum_step_count,
+            AlgorithmWrapper::EarClipping(algorithm) => algorithm.maximum_step_count,
+            AlgorithmWrapper::MonotoneTriangulation(algorithm) => algorithm.maximum_step_count,
+            AlgorithmWrapper::ArtGallery(algorithm) => algorithm.maximum_step_count,
+            AlgorithmWrapper::BowyerWatson(algorithm) => algorithm.maximum_step_count,
+            AlgorithmWrapper::Fortune(algorithm) => algorithm.maximum_step_count,
+            AlgorithmWrapper::VoronoiDiagram(algorithm) => algorithm.maximum_step_count,
+            AlgorithmWrapper::ClosestPair(algorithm) => algorithm.maximum_step_count,
+            AlgorithmWrapper::KdTree(algorithm) => algorithm.maximum_step_count,
+            AlgorithmWrapper::RangeTree(algorithm) => algorithm.maximum_step_count,
+            AlgorithmWrapper::TrapezoidalMap(algorithm) => algorithm.maximum_step_count,
+            AlgorithmWrapper::MapOverlay(algorithm) => algorithm.maximum_step_count,
+            AlgorithmWrapper::LinearProgramming(algorithm) => algorithm.maximum_step_count,
+            AlgorithmWrapper::MinimumEnclosingDisk(algorithm) => algorithm.maximum_step_count,
+            AlgorithmWrapper::RotatingCalipers(algorithm) => algorithm.maximum_step_count,
+            AlgorithmWrapper::MinkowskiSum(algorithm) => algorithm.maximum_step_count,
+            AlgorithmWrapper::GreinerHormann(algorithm) => algorithm.maximum_step_count,
+            AlgorithmWrapper::VisibilityGraph(algorithm) => algorithm.maximum_step_count,
+            AlgorithmWrapper::RandomizedIncrementalDelaunay(algorithm) => {
+                algorithm.maximum_step_count
+            }
+        }
+    }
+
+    /// Retrieves the description of the given step, if the algorithm
+    /// provides one.
+    pub fn get_step_description(&self, step: usize) -> Option<String> {
+        match self {
+            AlgorithmWrapper::GrahamScan(algorithm) => algorithm.get_step_description(step),
+            AlgorithmWrapper::JarvisMarch(algorithm) => algorithm.get_step_description(step),
+            AlgorithmWrapper::QuickHull(algorithm) => algorithm.get_step_description(step),
+            AlgorithmWrapper::ChansAlgorithm(algorithm) => algorithm.get_step_description(step),
+            AlgorithmWrapper::Melkman(algorithm) => algorithm.get_step_description(step),
+            AlgorithmWrapper::BentleyOttmann(algorithm) => algorithm.get_step_description(step),
+            AlgorithmWrapper::EarClipping(algorithm) => algorithm.get_step_description(step),
+            AlgorithmWrapper::ArtGallery(algorithm) => algorithm.get_step_description(step),
+            AlgorithmWrapper::BowyerWatson(algorithm) => algorithm.get_step_description(step),
+            AlgorithmWrapper::Fortune(algorithm) => algorithm.get_step_description(step),
+            AlgorithmWrapper::VoronoiDiagram(algorithm) => algorithm.get_step_description(step),
+            AlgorithmWrapper::ClosestPair(algorithm) => algorithm.get_step_description(step),
+            AlgorithmWrapper::KdTree(algorithm) => algorithm.get_step_description(step),
+            AlgorithmWrapper::RangeTree(algorithm) => algorithm.get_step_description(step),
+            AlgorithmWrapper::TrapezoidalMap(algorithm) => algorithm.get_step_description(step),
+            AlgorithmWrapper::MapOverlay(algorithm) => algorithm.get_step_description(step),
+            AlgorithmWrapper::LinearProgramming(algorithm) => algorithm.get_step_description(step),
+            AlgorithmWrapper::RotatingCalipers(algorithm) => algorithm.get_step_description(step),
+            AlgorithmWrapper::MinkowskiSum(algorithm) => algorithm.get_step_description(step),
+            AlgorithmWrapper::GreinerHormann(algorithm) => algorithm.get_step_description(step),
+            AlgorithmWrapper::VisibilityGraph(algorithm) => algorithm.get_step_description(step),
+            AlgorithmWrapper::MinimumEnclosingDisk(algorithm) => {
+                algorithm.get_step_description(step)
+            }
+            AlgorithmWrapper::RandomizedIncrementalDelaunay(algorithm) => {
+                algorithm.get_step_description(step)
+            }
+            AlgorithmWrapper::MonotoneTriangulation(algorithm) => {
+                algorithm.get_step_description(step)
+            }
+            AlgorithmWrapper::RandomizedIncrementalHull(algorithm) => {
+                algorithm.get_step_description(step)
+            }
+            AlgorithmWrapper::DivideAndConquerHull(algorithm) => {
+                algorithm.get_step_description(step)
+            }
         }
     }
 
@@ -59,6 +647,33 @@ impl AlgorithmWrapper {
     {
         match self {
             AlgorithmWrapper::GrahamScan(algorithm) => algorithm.draw(f, area, app),
+            AlgorithmWrapper::JarvisMarch(algorithm) => algorithm.draw(f, area, app),
+            AlgorithmWrapper::QuickHull(algorithm) => algorithm.draw(f, area, app),
+            AlgorithmWrapper::ChansAlgorithm(algorithm) => algorithm.draw(f, area, app),
+            AlgorithmWrapper::DivideAndConquerHull(algorithm) => algorithm.draw(f, area, app),
+            AlgorithmWrapper::RandomizedIncrementalHull(algorithm) => algorithm.draw(f, area, app),
+            AlgorithmWrapper::Melkman(algorithm) => algorithm.draw(f, area, app),
+            AlgorithmWrapper::BentleyOttmann(algorithm) => algorithm.draw(f, area, app),
+            AlgorithmWrapper::EarClipping(algorithm) => algorithm.draw(f, area, app),
+            AlgorithmWrapper::MonotoneTriangulation(algorithm) => algorithm.draw(f, area, app),
+            AlgorithmWrapper::ArtGallery(algorithm) => algorithm.draw(f, area, app),
+            AlgorithmWrapper::BowyerWatson(algorithm) => algorithm.draw(f, area, app),
+            AlgorithmWrapper::Fortune(algorithm) => algorithm.draw(f, area, app),
+            AlgorithmWrapper::VoronoiDiagram(algorithm) => algorithm.draw(f, area, app),
+            AlgorithmWrapper::ClosestPair(algorithm) => algorithm.draw(f, area, app),
+            AlgorithmWrapper::KdTree(algorithm) => algorithm.draw(f, area, app),
+            AlgorithmWrapper::RangeTree(algorithm) => algorithm.draw(f, area, app),
+            AlgorithmWrapper::TrapezoidalMap(algorithm) => algorithm.draw(f, area, app),
+            AlgorithmWrapper::MapOverlay(algorithm) => algorithm.draw(f, area, app),
+            AlgorithmWrapper::LinearProgramming(algorithm) => algorithm.draw(f, area, app),
+            AlgorithmWrapper::MinimumEnclosingDisk(algorithm) => algorithm.draw(f, area, app),
+            AlgorithmWrapper::RotatingCalipers(algorithm) => algorithm.draw(f, area, app),
+            AlgorithmWrapper::MinkowskiSum(algorithm) => algorithm.draw(f, area, app),
+            AlgorithmWrapper::GreinerHormann(algorithm) => algorithm.draw(f, area, app),
+            AlgorithmWrapper::VisibilityGraph(algorithm) => algorithm.draw(f, area, app),
+            AlgorithmWrapper::RandomizedIncrementalDelaunay(algorithm) => {
+                algorithm.draw(f, area, app)
+            }
         }
     }
 }
@@ -72,13 +687,53 @@ pub trait Algorithm {
     /// Set the initial point set of the algorithm.
     fn set_points(&mut self, points: Vec<Point2<f64>>);
 
+    /// Set the initial segments of the algorithm. Only algorithms working
+    /// on segments need to store them.
+    fn set_segments(&mut self, _segments: Vec<Segment>) {}
+
+    /// Set the initial polygons of the algorithm. Only algorithms working
+    /// on several polygons need to store them.
+    fn set_polygons(&mut self, _polygons: Vec<Vec<Point2<f64>>>) {}
+
+    /// Set the bounds the input is generated in. Only algorithms clipping
+    /// their output need to store them.
+    fn set_bounds(&mut self, _x_bounds: [f64; 2], _y_bounds: [f64; 2]) {}
+
+    /// Get the kind of input the initial point set is generated as.
+    fn get_input_kind(&self) -> InputKind {
+        InputKind::PointCloud
+    }
+
     /// Get all computed steps. Every step is stored as a vector of
-    /// lines. This is convenient because we can iterate through the algorithm
+    /// drawables. This is convenient because we can iterate through the algorithm
     /// steps after a single computation.
-    fn get_steps(&self) -> Vec<Vec<Line>>;
+    fn get_steps(&self) -> Vec<Vec<Drawable>>;
 
     fn get_draw_method(&self) -> DrawMethod;
 
+    /// Switch to the next mode, e.g. another set of visible layers. Returns
+    /// whether the algorithm has modes and needs to be recalculated.
+    fn switch_mode(&mut self) -> bool {
+        false
+    }
+
+    /// Get the kind of query the user can enter, e.g. the corners of a
+    /// rectangle. Only algorithms answering queries need to define it.
+    fn get_query_kind(&self) -> Option<QueryKind> {
+        None
+    }
+
+    /// Set the numbers of the query entered by the user. Returns whether they
+    /// match the query format.
+    fn set_query(&mut self, _query: Vec<f64>) -> bool {
+        false
+    }
+
+    /// Get a short text explaining what happens in the given step.
+    fn get_step_description(&self, _step: usize) -> Option<String> {
+        None
+    }
+
     // TODO: If needed this draw method could be shifted to the individual algorithms
     // to enable customized rendering easily.
     fn draw<B>(&self, f: &mut Frame<B>, area: Rect, app: &App)
@@ -96,12 +751,36 @@ pub trait Algorithm {
                 }
 
                 let steps = self.get_steps();
+                let step = &steps[app.get_current_tab().step];
 
                 // Draw steps calculated by algorithm.
                 match self.get_draw_method() {
-                    DrawMethod::Edge => steps[app.get_current_tab().step]
-                        .iter()
-                        .for_each(|line| ctx.draw(line)),
+                    DrawMethod::Edge => step.iter().for_each(|drawable| match drawable {
+                        // The canvas skips lines leaving its bounds, so clip them first.
+                        Drawable::Line(line) => {
+                            if let Some((from, to)) = clip_segment(
+                                &Point2::new(line.x1, line.y1),
+                                &Point2::new(line.x2, line.y2),
+                                app.x_bounds,
+                                app.y_bounds,
+                            ) {
+                                ctx.draw(&Line {
+                                    x1: from.x,
+                                    y1: from.y,
+                                    x2: to.x,
+                                    y2: to.y,
+                                    color: line.color,
+                                })
+                            }
+                        }
+                        Drawable::Circle(circle) => ctx.draw(circle),
+                        Drawable::Label(label) => ctx.print(
+                            label.x,
+                            label.y,
+                            Span::styled(label.text.clone(), Style::default().fg(label.color)),
+                        ),
+                        Drawable::Points(..) => {}
+                    }),
                 }
                 ctx.layer();
 
@@ -112,7 +791,19 @@ pub trait Algorithm {
                         .map(|point| (point.x, point.y))
                         .collect::<Vec<_>>(),
                     color: Color::Red,
-                })
+                });
+                ctx.layer();
+
+                // Highlighted points of a step have to be visible on top
+                // of the initial points.
+                step.iter().for_each(|drawable| {
+                    if let Drawable::Points(coords, color) = drawable {
+                        ctx.draw(&Points {
+                            coords,
+                            color: *color,
+                        })
+                    }
+                });
             })
             .marker(symbols::Marker::Braille)
             .x_bounds(app.x_bounds)
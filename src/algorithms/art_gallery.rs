@@ -0,0 +1,434 @@
+use std::{collections::HashMap, f64::consts::TAU};
+
+use itertools::Itertools;
+use nalgebra::{Point2, Vector2};
+use tui::style::Color;
+
+use super::{
+    algorithm::{Algorithm, DrawMethod, Drawable, InputKind},
+    ear_clipping::EarClipping,
+};
+
+const COLORS: [(Color, &str); 3] = [
+    (Color::Green, "green"),
+    (Color::Blue, "blue"),
+    (Color::Magenta, "magenta"),
+];
+
+pub struct ArtGallery {
+    // Later passed to app.
+    pub maximum_step_count: usize,
+    pub points: Vec<Point2<f64>>,
+    pub steps: Vec<Vec<Drawable>>,
+    pub descriptions: Vec<String>,
+    // Indices of the chosen guard vertices.
+    pub guards: Vec<usize>,
+    triangles: Vec<[usize; 3]>,
+    // The color class of every vertex, if it is colored already.
+    colors: Vec<Option<usize>>,
+}
+
+impl ArtGallery {
+    pub fn new() -> Self {
+        ArtGallery {
+            maximum_step_count: 0,
+            points: vec![],
+            steps: vec![],
+            descriptions: vec![],
+            guards: vec![],
+            triangles: vec![],
+            colors: vec![],
+        }
+    }
+
+    /// Stores the polygon, its triangulation and the colored vertices together
+    /// with the given drawables.
+    fn add_step(&mut self, drawables: Vec<Drawable>, description: String) {
+        let mut step = self
+            .triangles
+            .iter()
+            .flat_map(|triangle| {
+                triangle
+                    .iter()
+                    .circular_tuple_windows()
+                    .map(|(from, to)| {
+                        Drawable::line(&self.points[*from], &self.points[*to], Color::DarkGray)
+                    })
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+        step.extend(
+            self.points
+                .iter()
+                .circular_tuple_windows()
+                .map(|(from, to)| Drawable::line(from, to, Color::White)),
+        );
+        step.extend(drawables);
+
+        for (class, (color, _)) in COLORS.iter().enumerate() {
+            let points = self
+                .points
+                .iter()
+                .zip(&self.colors)
+                .filter(|(_, other)| **other == Some(class))
+                .map(|(point, _)| *point)
+                .collect::<Vec<_>>();
+            step.push(Drawable::points(&points, *color));
+        }
+        step.push(Drawable::points(
+            &self
+                .guards
+                .iter()
+                .map(|guard| self.points[*guard])
+                .collect::<Vec<_>>(),
+            Color::Yellow,
+        ));
+
+        self.steps.push(step);
+        self.descriptions.push(description);
+        self.maximum_step_count += 1;
+    }
+
+    fn triangle_lines(&self, triangle: &[usize; 3], color: Color) -> Vec<Drawable> {
+        triangle
+            .iter()
+            .circular_tuple_windows()
+            .map(|(from, to)| Drawable::line(&self.points[*from], &self.points[*to], color))
+            .collect()
+    }
+
+    /// Casts a ray from the origin and returns the closest hit with a polygon edge
+    /// that is not incident to the given vertex.
+    fn cast_ray(
+        &self,
+        origin: &Point2<f64>,
+        direction: &Vector2<f64>,
+        vertex: usize,
+    ) -> Option<Point2<f64>> {
+        let length = self.points.len();
+        (0..length)
+            .filter(|edge| *edge != vertex && (edge + 1) % length != vertex)
+            .filter_map(|edge| {
+                let from = self.points[edge];
+                let to = self.points[(edge + 1) % length];
+                let edge_direction = to - from;
+                let denominator = direction.x * edge_direction.y - direction.y * edge_direction.x;
+                if denominator == 0.0 {
+                    return None;
+                }
+                let offset = from - origin;
+                let t = (offset.x * edge_direction.y - offset.y * edge_direction.x) / denominator;
+                let u = (offset.x * direction.y - offset.y * direction.x) / denominator;
+                (t > 0.0 && (0.0..=1.0).contains(&u)).then_some(t)
+            })
+            .min_by(|a, b| a.partial_cmp(b).unwrap())
+            .map(|t| origin + direction * t)
+    }
+
+    /// Calculates the region visible from a polygon vertex by casting rays towards
+    /// all other vertices and slightly past them, inside the interior angle of the
+    /// vertex. The result is ordered counterclockwise and starts at the vertex.
+    fn visibility_region(&self, vertex: usize) -> Vec<Point2<f64>> {
+        let length = self.points.len();
+        let origin = self.points[vertex];
+        let angle_to = |point: &Point2<f64>| (point.y - origin.y).atan2(point.x - origin.x);
+
+        // The interior lies counterclockwise between the next and the previous edge.
+        let start = angle_to(&self.points[(vertex + 1) % length]);
+        let end = (angle_to(&self.points[(vertex + length - 1) % length]) - start).rem_euclid(TAU);
+
+        let mut angles = vec![0.0, end];
+        for other in 0..length {
+            if other == vertex {
+                continue;
+            }
+            let angle = (angle_to(&self.points[other]) - start).rem_euclid(TAU);
+            for offset in [-1e-6, 0.0, 1e-6] {
+                let angle = angle + offset;
+                if angle >= 0.0 && angle <= end {
+                    angles.push(angle);
+                }
+            }
+        }
+        angles.sort_by(|a, b| a.partial_cmp(b).unwrap());
+
+        let mut region = vec![origin];
+        region.extend(angles.into_iter().filter_map(|angle| {
+            let direction = Vector2::new((start + angle).cos(), (start + angle).sin());
+            self.cast_ray(&origin, &direction, vertex)
+        }));
+        region
+    }
+
+    pub fn calculate(&mut self) {
+        self.steps = vec![];
+        self.descriptions = vec![];
+        self.maximum_step_count = 0;
+        self.guards = vec![];
+        self.triangles = vec![];
+        self.colors = vec![None; self.points.len()];
+
+        if self.points.len() < 3 {
+            self.add_step(
+                vec![],
+                String::from("A polygon needs at least three vertices."),
+            );
+            return;
+        }
+
+        let mut triangulation = EarClipping::new();
+        triangulation.set_points(self.points.clone());
+        triangulation.calculate();
+        self.triangles = triangulation.triangles;
+        self.add_step(
+            vec![],
+            format!(
+                "Triangulate the polygon with ear clipping into {} triangles.",
+                self.triangles.len()
+            ),
+        );
+        if self.triangles.len() != self.points.len() - 2 {
+            self.add_step(
+                vec![],
+                String::from("The triangulation is incomplete, the polygon is degenerate."),
+            );
+            return;
+        }
+
+        // Triangles sharing a diagonal are adjacent in the dual tree.
+        let mut sharing: HashMap<(usize, usize), Vec<usize>> = HashMap::new();
+        for (index, triangle) in self.triangles.iter().enumerate() {
+            for (from, to) in triangle.iter().circular_tuple_windows() {
+                sharing
+                    .entry((*from.min(to), *from.max(to)))
+                    .or_default()
+                    .push(index);
+            }
+        }
+
+        let first = self.triangles[0];
+        for (class, vertex) in first.iter().enumerate() {
+            self.colors[*vertex] = Some(class);
+        }
+        self.add_step(
+            self.triangle_lines(&first, Color::Yellow),
+            String::from("Start the dual tree traversal by coloring the first triangle."),
+        );
+
+        let mut visited = vec![false; self.triangles.len()];
+        visited[0] = true;
+        let mut stack = vec![0];
+        while let Some(parent) = stack.pop() {
+            let triangle = self.triangles[parent];
+            for (from, to) in triangle.iter().circular_tuple_windows() {
+                let neighbours = &sharing[&(*from.min(to), *from.max(to))];
+                let child = match neighbours.iter().find(|other| !visited[**other]) {
+                    Some(child) => *child,
+                    None => continue,
+                };
+                visited[child] = true;
+                stack.push(child);
+
+                // Two vertices are shared with the parent, the third takes the missing color.
+                let vertex = *self.triangles[child]
+                    .iter()
+                    .find(|vertex| self.colors[**vertex].is_none())
+                    .unwrap();
+                let class = (0..3)
+                    .find(|class| {
+                        !self.triangles[child]
+                            .iter()
+                            .any(|other| self.colors[*other] == Some(*class))
+                    })
+                    .unwrap();
+                self.colors[vertex] = Some(class);
+
+                let mut drawables = self.triangle_lines(&self.triangles[parent], Color::Gray);
+                drawables.extend(self.triangle_lines(&self.triangles[child], Color::Yellow));
+                self.add_step(
+                    drawables,
+                    format!(
+                        "The next triangle shares the diagonal v{from} - v{to}. Color v{vertex} \
+                         {}.",
+                        COLORS[class].1
+                    ),
+                );
+            }
+        }
+
+        let counts = (0..3)
+            .map(|class| {
+                self.colors
+                    .iter()
+                    .filter(|other| **other == Some(class))
+                    .count()
+            })
+            .collect::<Vec<_>>();
+        let smallest = (0..3).min_by_key(|class| counts[*class]).unwrap();
+        self.guards = (0..self.points.len())
+            .filter(|vertex| self.colors[*vertex] == Some(smallest))
+            .collect();
+        self.add_step(
+            vec![],
+            format!(
+                "The color classes have sizes {}. Place guards at the {} {} vertices, at most \
+                 floor(n / 3) = {}.",
+                (0..3)
+                    .map(|class| format!("{} {}", counts[class], COLORS[class].1))
+                    .join(", "),
+                counts[smallest],
+                COLORS[smallest].1,
+                self.points.len() / 3
+            ),
+        );
+
+        // Every triangle has a vertex of each color, so every triangle is seen by a guard.
+        let regions = self
+            .guards
+            .iter()
+            .map(|guard| self.visibility_region(*guard))
+            .collect::<Vec<_>>();
+        for (guard, region) in self.guards.clone().into_iter().zip(&regions) {
+            let mut drawables = region[1..]
+                .iter()
+                .map(|point| Drawable::line(&region[0], point, Color::Gray))
+                .collect::<Vec<_>>();
+            drawables.extend(
+                region
+                    .iter()
+                    .circular_tuple_windows()
+                    .map(|(from, to)| Drawable::line(from, to, Color::Yellow)),
+            );
+            self.add_step(
+                drawables,
+                format!("The guard at v{guard} sees the highlighted region."),
+            );
+        }
+
+        let drawables = regions
+            .iter()
+            .flat_map(|region| {
+                region
+                    .iter()
+                    .circular_tuple_windows()
+                    .map(|(from, to)| Drawable::line(from, to, Color::Yellow))
+                    .collect::<Vec<_>>()
+            })
+            .collect();
+        self.add_step(
+            drawables,
+            format!(
+                "{} guards see the whole polygon with {} vertices.",
+                self.guards.len(),
+                self.points.len()
+            ),
+        );
+    }
+}
+
+impl Algorithm for ArtGallery {
+    fn get_title(&self) -> &str {
+        "Art gallery"
+    }
+
+    fn get_points(&self) -> &Vec<Point2<f64>> {
+        &self.points
+    }
+
+    fn set_points(&mut self, points: Vec<Point2<f64>>) {
+        self.points = points;
+    }
+
+    fn get_input_kind(&self) -> InputKind {
+        InputKind::CombPolygon
+    }
+
+    fn get_steps(&self) -> Vec<Vec<Drawable>> {
+        self.steps.clone()
+    }
+
+    fn get_draw_method(&self) -> DrawMethod {
+        DrawMethod::Edge
+    }
+
+    fn get_step_description(&self, step: usize) -> Option<String> {
+        self.descriptions.get(step).cloned()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::algorithms::{
+        geometry::{in_polygon, Segment},
+        testing::{random_points, random_star_polygon},
+    };
+
+    fn guard(points: Vec<Point2<f64>>) -> ArtGallery {
+        let mut art_gallery = ArtGallery::new();
+        art_gallery.set_points(points);
+        art_gallery.calculate();
+        art_gallery
+    }
+
+    /// Asserts that the coloring is proper and that random points inside the
+    /// polygon are seen by a guard.
+    fn assert_guarded(points: &[Point2<f64>]) {
+        let art_gallery = guard(points.to_vec());
+        assert!(art_gallery.guards.len() <= points.len() / 3);
+        for triangle in &art_gallery.triangles {
+            let classes = triangle.map(|vertex| art_gallery.colors[vertex].unwrap());
+            assert!(classes.iter().all_unique());
+        }
+
+        let edges = points
+            .iter()
+            .circular_tuple_windows()
+            .map(|(from, to)| Segment::new(*from, *to))
+            .collect::<Vec<_>>();
+        for sample in random_points(500) {
+            if !in_polygon(&sample, points) {
+                continue;
+            }
+            assert!(art_gallery.guards.iter().any(|guard| {
+                let sight = Segment::new(points[*guard], sample);
+                edges.iter().all(|edge| sight.intersection(edge).is_none())
+            }));
+        }
+    }
+
+    #[test]
+    fn small_polygons_need_no_guards() {
+        for amount in 0..3 {
+            let art_gallery = guard(random_points(amount));
+            assert!(art_gallery.guards.is_empty());
+        }
+    }
+
+    #[test]
+    fn guards_see_combs() {
+        // Three teeth whose tips lean away from their feet.
+        let comb = [
+            (0.0, 10.0),
+            (100.0, 10.0),
+            (85.0, 30.0),
+            (95.0, 95.0),
+            (75.0, 30.0),
+            (55.0, 30.0),
+            (40.0, 90.0),
+            (45.0, 30.0),
+            (25.0, 30.0),
+            (5.0, 95.0),
+            (15.0, 30.0),
+        ]
+        .map(|(x, y)| Point2::new(x, y));
+        assert_guarded(&comb);
+    }
+
+    #[test]
+    fn guards_see_star_polygons() {
+        for amount in 3..30 {
+            assert_guarded(&random_star_polygon(amount, Point2::new(50.0, 50.0), 50.0));
+        }
+    }
+}
@@ -0,0 +1,369 @@
+use std::collections::HashSet;
+
+use itertools::Itertools;
+use nalgebra::Point2;
+use tui::style::Color;
+
+use super::{
+    algorithm::{Algorithm, DrawMethod, Drawable, InputKind},
+    geometry::Segment,
+};
+
+enum EventKind {
+    Start(usize),
+    End(usize),
+    Intersection(usize, usize),
+}
+
+struct Event {
+    point: Point2<f64>,
+    kind: EventKind,
+}
+
+pub struct BentleyOttmann {
+    // Later passed to app.
+    pub maximum_step_count: usize,
+    pub points: Vec<Point2<f64>>,
+    pub segments: Vec<Segment>,
+    pub steps: Vec<Vec<Drawable>>,
+    pub descriptions: Vec<String>,
+    // Events ordered from left to right.
+    queue: Vec<Event>,
+    // Segments intersecting the sweep line ordered from bottom to top.
+    status: Vec<usize>,
+    intersections: Vec<Point2<f64>>,
+    scheduled: HashSet<(usize, usize)>,
+    y_range: [f64; 2],
+}
+
+impl BentleyOttmann {
+    pub fn new() -> Self {
+        BentleyOttmann {
+            maximum_step_count: 0,
+            points: vec![],
+            segments: vec![],
+            steps: vec![],
+            descriptions: vec![],
+            queue: vec![],
+            status: vec![],
+            intersections: vec![],
+            scheduled: HashSet::new(),
+            y_range: [0.0, 0.0],
+        }
+    }
+
+    /// Stores all segments, the sweep line, the status structure, the event queue
+    /// and the intersections reported so far.
+    fn add_step(&mut self, sweep: Option<&Point2<f64>>, description: String) {
+        let mut step = self
+            .segments
+            .iter()
+            .map(|segment| Drawable::line(&segment.from, &segment.to, Color::DarkGray))
+            .collect::<Vec<_>>();
+        step.extend(self.status.iter().map(|index| {
+            let segment = &self.segments[*index];
+            Drawable::line(&segment.from, &segment.to, Color::Cyan)
+        }));
+        step.push(Drawable::points(
+            &self
+                .queue
+                .iter()
+                .map(|event| event.point)
+                .collect::<Vec<_>>(),
+            Color::Magenta,
+        ));
+        step.push(Drawable::points(&self.intersections, Color::Green));
+
+        let mut description = description;
+        if let Some(sweep) = sweep {
+            step.push(Drawable::line(
+                &Point2::new(sweep.x, self.y_range[0]),
+                &Point2::new(sweep.x, self.y_range[1]),
+                Color::Yellow,
+            ));
+            step.push(Drawable::points(&[*sweep], Color::Yellow));
+
+            let status = self
+                .status
+                .iter()
+                .map(|index| format!("s{index}"))
+                .join(", ");
+            description = format!(
+                "{description} Status (bottom to top): [{status}]. {} events queued.",
+                self.queue.len()
+            );
+        }
+
+        self.steps.push(step);
+        self.descriptions.push(description);
+        self.maximum_step_count += 1;
+    }
+
+    /// Inserts the event while keeping the queue ordered from left to right.
+    fn push_event(&mut self, event: Event) {
+        let index = self.queue.partition_point(|queued| {
+            (queued.point.x, queued.point.y) < (event.point.x, event.point.y)
+        });
+        self.queue.insert(index, event);
+    }
+
+    /// Schedules the intersection of two segments, if it lies right of the sweep line
+    /// and was not found before.
+    fn check_intersection(&mut self, below: usize, above: usize, sweep: &Point2<f64>) {
+        let pair = (below.min(above), below.max(above));
+        if self.scheduled.contains(&pair) {
+            return;
+        }
+        if let Some(point) = self.segments[below].intersection(&self.segments[above]) {
+            if (point.x, point.y) > (sweep.x, sweep.y) {
+                self.scheduled.insert(pair);
+                self.push_event(Event {
+                    point,
+                    kind: EventKind::Intersection(below, above),
+                });
+            }
+        }
+    }
+
+    /// Checks the segments at the given status positions for an intersection.
+    fn check_neighbours(&mut self, below: Option<usize>, above: usize, sweep: &Point2<f64>) {
+        if let (Some(below), Some(&upper)) = (below, self.status.get(above)) {
+            let lower = self.status[below];
+            self.check_intersection(lower, upper, sweep);
+        }
+    }
+
+    /// Returns the position of the segment in the status. Segments stay in the
+    /// status from their start event up to their end event, and intersections
+    /// are only scheduled for segments in it.
+    fn status_position(&self, index: usize) -> usize {
+        self.status
+            .iter()
+            .position(|other| *other == index)
+            .unwrap_or_else(|| panic!("s{index} is not in the status"))
+    }
+
+    fn y_at(&self, index: usize, x: f64) -> f64 {
+        let segment = &self.segments[index];
+        if segment.from.x == segment.to.x {
+            return segment.from.y;
+        }
+        let t = (x - segment.from.x) / (segment.to.x - segment.from.x);
+        segment.from.y + t * (segment.to.y - segment.from.y)
+    }
+
+    fn slope(&self, index: usize) -> f64 {
+        let segment = &self.segments[index];
+        if segment.from.x == segment.to.x {
+            return f64::INFINITY;
+        }
+        (segment.to.y - segment.from.y) / (segment.to.x - segment.from.x)
+    }
+
+    pub fn calculate(&mut self) {
+        self.steps = vec![];
+        self.descriptions = vec![];
+        self.maximum_step_count = 0;
+        self.queue = vec![];
+        self.status = vec![];
+        self.intersections = vec![];
+        self.scheduled = HashSet::new();
+
+        self.y_range = self
+            .points
+            .iter()
+            .fold([f64::MAX, f64::MIN], |range, point| {
+                [range[0].min(point.y), range[1].max(point.y)]
+            });
+
+        // Later events go first among equal points, so a segment of length zero
+        // starts before it ends.
+        for index in 0..self.segments.len() {
+            let segment = self.segments[index];
+            self.push_event(Event {
+                point: segment.to,
+                kind: EventKind::End(index),
+            });
+            self.push_event(Event {
+                point: segment.from,
+                kind: EventKind::Start(index),
+            });
+        }
+        self.add_step(
+            None,
+            format!(
+                "The event queue holds the {} segment endpoints.",
+                self.queue.len()
+            ),
+        );
+
+        while !self.queue.is_empty() {
+            let event = self.queue.remove(0);
+            let sweep = event.point;
+
+            let description = match event.kind {
+                EventKind::Start(index) => {
+                    // Segments starting at the same point are ordered by their slope.
+                    let slope = self.slope(index);
+                    let position = self
+                        .status
+                        .iter()
+                        .position(|other| {
+                            (self.y_at(*other, sweep.x), self.slope(*other)) > (sweep.y, slope)
+                        })
+                        .unwrap_or(self.status.len());
+                    self.status.insert(position, index);
+
+                    self.check_neighbours(position.checked_sub(1), position, &sweep);
+                    self.check_neighbours(Some(position), position + 1, &sweep);
+                    format!("Start of s{index}: insert it and test its neighbours.")
+                }
+                EventKind::End(index) => {
+                    let position = self.status_position(index);
+                    self.status.remove(position);
+
+                    self.check_neighbours(position.checked_sub(1), position, &sweep);
+                    format!("End of s{index}: remove it and test its former neighbours.")
+                }
+                EventKind::Intersection(below, above) => {
+                    self.intersections.push(sweep);
+
+                    // Both segments change their order behind the intersection.
+                    let (lower, upper) = (self.status_position(below), self.status_position(above));
+                    self.status.swap(lower, upper);
+                    let (lower, upper) = (lower.min(upper), lower.max(upper));
+
+                    self.check_neighbours(lower.checked_sub(1), lower, &sweep);
+                    self.check_neighbours(Some(upper), upper + 1, &sweep);
+                    format!("Intersection of s{below} and s{above} reported, swap them.")
+                }
+            };
+            self.add_step(Some(&sweep), description);
+        }
+
+        self.add_step(
+            None,
+            format!("Reported {} intersections.", self.intersections.len()),
+        );
+    }
+}
+
+impl Algorithm for BentleyOttmann {
+    fn get_title(&self) -> &str {
+        "Bentley-Ottmann"
+    }
+
+    fn get_points(&self) -> &Vec<Point2<f64>> {
+        &self.points
+    }
+
+    fn set_points(&mut self, points: Vec<Point2<f64>>) {
+        self.points = points;
+    }
+
+    fn set_segments(&mut self, segments: Vec<Segment>) {
+        // Orient all segments from left to right.
+        self.segments = segments
+            .into_iter()
+            .map(|segment| {
+                if (segment.from.x, segment.from.y) > (segment.to.x, segment.to.y) {
+                    Segment::new(segment.to, segment.from)
+                } else {
+                    segment
+                }
+            })
+            .collect();
+        self.points = self
+            .segments
+            .iter()
+            .flat_map(|segment| [segment.from, segment.to])
+            .collect();
+    }
+
+    fn get_input_kind(&self) -> InputKind {
+        InputKind::Segments
+    }
+
+    fn get_steps(&self) -> Vec<Vec<Drawable>> {
+        self.steps.clone()
+    }
+
+    fn get_draw_method(&self) -> DrawMethod {
+        DrawMethod::Edge
+    }
+
+    fn get_step_description(&self, step: usize) -> Option<String> {
+        self.descriptions.get(step).cloned()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::algorithms::testing::{assert_same_points, random_points};
+
+    fn bentley_ottmann(segments: Vec<Segment>) -> BentleyOttmann {
+        let mut bentley_ottmann = BentleyOttmann::new();
+        bentley_ottmann.set_segments(segments);
+        bentley_ottmann.calculate();
+        bentley_ottmann
+    }
+
+    fn random_segments(amount: usize) -> Vec<Segment> {
+        random_points(2 * amount)
+            .chunks(2)
+            .map(|ends| Segment::new(ends[0], ends[1]))
+            .collect()
+    }
+
+    #[test]
+    fn small_inputs_report_nothing() {
+        for amount in 0..2 {
+            let bentley_ottmann = bentley_ottmann(random_segments(amount));
+            assert!(bentley_ottmann.intersections.is_empty());
+            assert!(bentley_ottmann.maximum_step_count >= 2);
+        }
+    }
+
+    #[test]
+    fn segments_sharing_an_endpoint_do_not_intersect() {
+        let start = Point2::new(0.0, 0.0);
+        let bentley_ottmann = bentley_ottmann(vec![
+            Segment::new(start, Point2::new(10.0, 10.0)),
+            Segment::new(start, Point2::new(10.0, -10.0)),
+            Segment::new(Point2::new(5.0, -20.0), Point2::new(5.0, 20.0)),
+        ]);
+        assert_same_points(
+            &bentley_ottmann.intersections,
+            &[Point2::new(5.0, 5.0), Point2::new(5.0, -5.0)],
+        );
+    }
+
+    #[test]
+    fn segments_of_length_zero_leave_the_status() {
+        let point = Point2::new(5.0, 5.0);
+        let bentley_ottmann = bentley_ottmann(vec![
+            Segment::new(point, point),
+            Segment::new(Point2::new(0.0, 0.0), Point2::new(10.0, 0.0)),
+        ]);
+        assert!(bentley_ottmann.status.is_empty());
+        assert!(bentley_ottmann.intersections.is_empty());
+    }
+
+    #[test]
+    fn matches_brute_force_intersections() {
+        for amount in 2..40 {
+            let segments = random_segments(amount);
+            let expected = segments
+                .iter()
+                .enumerate()
+                .flat_map(|(index, a)| {
+                    segments[index + 1..]
+                        .iter()
+                        .filter_map(|b| a.intersection(b))
+                })
+                .collect::<Vec<_>>();
+            assert_same_points(&bentley_ottmann(segments).intersections, &expected);
+        }
+    }
+}
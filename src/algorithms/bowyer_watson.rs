@@ -0,0 +1,231 @@
+use itertools::Itertools;
+use nalgebra::Point2;
+use tui::style::Color;
+
+use super::{
+    algorithm::{Algorithm, DrawMethod, Drawable},
+    super_triangle::SuperTriangle,
+};
+
+pub struct BowyerWatson {
+    // Later passed to app.
+    pub maximum_step_count: usize,
+    pub points: Vec<Point2<f64>>,
+    pub steps: Vec<Vec<Drawable>>,
+    pub descriptions: Vec<String>,
+    // Counterclockwise triangles as indices into the points. While calculating,
+    // indices past the points refer to the vertices of the super-triangle.
+    pub triangles: Vec<[usize; 3]>,
+}
+
+impl BowyerWatson {
+    pub fn new() -> Self {
+        BowyerWatson {
+            maximum_step_count: 0,
+            points: vec![],
+            steps: vec![],
+            descriptions: vec![],
+            triangles: vec![],
+        }
+    }
+
+    /// Stores the current triangulation together with the given drawables.
+    /// Edges leading to the super-triangle are drawn as gray rays.
+    fn add_step(&mut self, drawables: Vec<Drawable>, description: String) {
+        let super_triangle = SuperTriangle::new(&self.points);
+        let mut step = self
+            .triangles
+            .iter()
+            .flat_map(|triangle| {
+                let color = if triangle
+                    .iter()
+                    .any(|vertex| super_triangle.is_infinite(*vertex))
+                {
+                    Color::DarkGray
+                } else {
+                    Color::Blue
+                };
+                super_triangle.triangle_lines(triangle, color)
+            })
+            .collect::<Vec<_>>();
+        step.extend(drawables);
+
+        self.steps.push(step);
+        self.descriptions.push(description);
+        self.maximum_step_count += 1;
+    }
+
+    pub fn calculate(&mut self) {
+        self.steps = vec![];
+        self.descriptions = vec![];
+        self.maximum_step_count = 0;
+
+        let length = self.points.len();
+        let points = self.points.clone();
+        let super_triangle = SuperTriangle::new(&points);
+        self.triangles = vec![super_triangle.vertices()];
+        self.add_step(
+            vec![],
+            String::from(
+                "Start with a super-triangle containing all points. Its vertices lie \
+                 infinitely far up, lower left and lower right.",
+            ),
+        );
+
+        for index in 0..length {
+            let point = self.points[index];
+            let (bad, good): (Vec<_>, Vec<_>) = self
+                .triangles
+                .iter()
+                .partition(|triangle| super_triangle.in_circumcircle(triangle, &point));
+
+            let mut drawables = vec![];
+            for triangle in bad.iter() {
+                drawables.extend(super_triangle.triangle_lines(triangle, Color::LightRed));
+                drawables.extend(super_triangle.circumcircle(triangle, Color::Magenta));
+            }
+            drawables.push(Drawable::points(&[point], Color::Yellow));
+            self.add_step(
+                drawables,
+                format!(
+                    "Insert v{index}: {} triangles have a circumcircle containing it.",
+                    bad.len()
+                ),
+            );
+
+            // Edges shared by two bad triangles lie inside the cavity.
+            let edges = bad
+                .iter()
+                .flat_map(|triangle| {
+                    triangle
+                        .iter()
+                        .circular_tuple_windows()
+                        .map(|(from, to)| (*from, *to))
+                        .collect::<Vec<_>>()
+                })
+                .collect::<Vec<_>>();
+            let boundary = edges
+                .iter()
+                .filter(|(from, to)| !edges.contains(&(*to, *from)))
+                .copied()
+                .collect::<Vec<_>>();
+
+            self.triangles = good;
+            let mut drawables = boundary
+                .iter()
+                .filter_map(|(from, to)| super_triangle.edge_line(*from, *to, Color::Yellow))
+                .collect::<Vec<_>>();
+            drawables.push(Drawable::points(&[point], Color::Yellow));
+            self.add_step(
+                drawables,
+                format!(
+                    "Remove the bad triangles. The cavity is bounded by {} edges.",
+                    boundary.len()
+                ),
+            );
+
+            // The cavity is star-shaped, so the point sees every boundary edge.
+            let fan = boundary
+                .iter()
+                .map(|(from, to)| [*from, *to, index])
+                .collect::<Vec<_>>();
+            let mut drawables = fan
+                .iter()
+                .flat_map(|triangle| super_triangle.triangle_lines(triangle, Color::Green))
+                .collect::<Vec<_>>();
+            drawables.push(Drawable::points(&[point], Color::Yellow));
+            self.triangles.extend(fan.iter().copied());
+            self.add_step(
+                drawables,
+                format!(
+                    "Connect v{index} to the cavity boundary, creating {} triangles.",
+                    fan.len()
+                ),
+            );
+        }
+
+        let count = self.triangles.len();
+        self.triangles
+            .retain(|triangle| triangle.iter().all(|vertex| *vertex < length));
+        self.add_step(
+            vec![],
+            format!(
+                "Remove the {} triangles touching the super-triangle. The Delaunay \
+                 triangulation has {} triangles.",
+                count - self.triangles.len(),
+                self.triangles.len()
+            ),
+        );
+    }
+}
+
+impl Algorithm for BowyerWatson {
+    fn get_title(&self) -> &str {
+        "Bowyer-Watson"
+    }
+
+    fn get_points(&self) -> &Vec<Point2<f64>> {
+        &self.points
+    }
+
+    fn set_points(&mut self, points: Vec<Point2<f64>>) {
+        self.points = points;
+    }
+
+    fn get_steps(&self) -> Vec<Vec<Drawable>> {
+        self.steps.clone()
+    }
+
+    fn get_draw_method(&self) -> DrawMethod {
+        DrawMethod::Edge
+    }
+
+    fn get_step_description(&self, step: usize) -> Option<String> {
+        self.descriptions.get(step).cloned()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::algorithms::{
+        geometry::{cross_product, in_circle},
+        testing::{brute_force_hull, random_points},
+    };
+
+    fn bowyer_watson(points: Vec<Point2<f64>>) -> BowyerWatson {
+        let mut bowyer_watson = BowyerWatson::new();
+        bowyer_watson.set_points(points);
+        bowyer_watson.calculate();
+        bowyer_watson
+    }
+
+    #[test]
+    fn small_and_collinear_inputs() {
+        for amount in 0..4 {
+            let bowyer_watson = bowyer_watson(random_points(amount));
+            assert!(bowyer_watson.maximum_step_count >= 2);
+            assert_eq!(bowyer_watson.triangles.len(), amount.saturating_sub(2));
+        }
+        let line = (0..5).map(|i| Point2::new(10.0 * i as f64, 5.0)).collect();
+        assert!(bowyer_watson(line).triangles.is_empty());
+    }
+
+    #[test]
+    fn circumcircles_are_empty() {
+        for amount in 3..60 {
+            let points = random_points(amount);
+            let bowyer_watson = bowyer_watson(points.clone());
+            // A triangulation of n points with h on the hull has 2n - 2 - h triangles.
+            assert_eq!(
+                bowyer_watson.triangles.len(),
+                2 * amount - 2 - brute_force_hull(&points).len()
+            );
+            for [a, b, c] in bowyer_watson.triangles {
+                let [a, b, c] = [a, b, c].map(|vertex| points[vertex]);
+                assert!(cross_product(&a, &b, &c) > 0.0);
+                assert!(!points.iter().any(|point| in_circle(&a, &b, &c, point)));
+            }
+        }
+    }
+}
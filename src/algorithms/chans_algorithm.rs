@@ -0,0 +1,362 @@
+use itertools::Itertools;
+use nalgebra::{distance, Point2};
+use tui::style::Color;
+
+use super::{
+    algorithm::{Algorithm, DrawMethod, Drawable},
+    geometry::cross_product,
+    graham_scan::GrahamScan,
+};
+
+/// Colors the mini-hulls cycle through.
+const MINI_HULL_COLORS: [Color; 6] = [
+    Color::Cyan,
+    Color::Green,
+    Color::Magenta,
+    Color::LightBlue,
+    Color::LightGreen,
+    Color::LightMagenta,
+];
+
+/// The first guess for the amount of hull points.
+const INITIAL_GUESS: usize = 4;
+
+pub struct ChansAlgorithm {
+    // Later passed to app.
+    pub maximum_step_count: usize,
+    pub points: Vec<Point2<f64>>,
+    pub steps: Vec<Vec<Drawable>>,
+    pub descriptions: Vec<String>,
+    // The vertices of the hull in counterclockwise order.
+    pub hull: Vec<Point2<f64>>,
+}
+
+impl ChansAlgorithm {
+    pub fn new() -> Self {
+        ChansAlgorithm {
+            maximum_step_count: 0,
+            points: vec![],
+            steps: vec![],
+            descriptions: vec![],
+            hull: vec![],
+        }
+    }
+
+    fn add_step(&mut self, step: Vec<Drawable>, description: String) {
+        self.steps.push(step);
+        self.descriptions.push(description);
+        self.maximum_step_count += 1;
+    }
+
+    pub fn calculate(&mut self) {
+        self.steps = vec![];
+        self.descriptions = vec![];
+        self.maximum_step_count = 0;
+        self.hull = vec![];
+
+        let points = self.points.clone();
+
+        // The leftmost point is guaranteed to be on the hull.
+        let start = match points
+            .iter()
+            .min_by(|a, b| (a.x, a.y).partial_cmp(&(b.x, b.y)).unwrap())
+        {
+            Some(start) => *start,
+            None => {
+                self.add_step(vec![], String::from("There are no points."));
+                return;
+            }
+        };
+
+        let mut guess = INITIAL_GUESS;
+        loop {
+            let mini_hulls = self.build_mini_hulls(&points, guess);
+            match self.wrap(&mini_hulls, &start, guess) {
+                Ok(hull) => {
+                    let mut step = mini_hull_drawables(&mini_hulls);
+                    step.extend(chain_drawables(&hull, Color::Yellow));
+                    step.push(Drawable::line(
+                        &hull[hull.len() - 1],
+                        &hull[0],
+                        Color::Yellow,
+                    ));
+                    self.add_step(
+                        step,
+                        format!(
+                            "Guess m = {guess} succeeded, the hull has {} points.",
+                            hull.len()
+                        ),
+                    );
+                    self.hull = hull;
+                    return;
+                }
+                Err(chain) => {
+                    let mut step = mini_hull_drawables(&mini_hulls);
+                    step.extend(chain_drawables(&chain, Color::LightRed));
+                    self.add_step(
+                        step,
+                        format!(
+                            "Guess m = {guess} abandoned: the hull has more than {guess} points. \
+                             Restarting with m = {}.",
+                            guess * guess
+                        ),
+                    );
+                    guess *= guess;
+                }
+            }
+        }
+    }
+
+    /// Splits the points into groups of at most `guess` points and computes
+    /// the convex hull of every group with a Graham scan.
+    fn build_mini_hulls(&mut self, points: &[Point2<f64>], guess: usize) -> Vec<Vec<Point2<f64>>> {
+        let group_count = points.len().div_ceil(guess);
+        let mut mini_hulls = vec![];
+
+        for (index, group) in points.chunks(guess).enumerate() {
+            let mut graham_scan = GrahamScan::new();
+            graham_scan.set_points(group.to_vec());
+            graham_scan.calculate();
+            mini_hulls.push(graham_scan.get_hull());
+
+            self.add_step(
+                mini_hull_drawables(&mini_hulls),
+                format!(
+                    "Guess m = {guess}: Graham scan builds mini-hull {} of {group_count}.",
+                    index + 1
+                ),
+            );
+        }
+        mini_hulls
+    }
+
+    /// Wraps around the mini-hulls for at most `guess` iterations. Returns the
+    /// hull on success and the unfinished chain if the guess was too small.
+    fn wrap(
+        &mut self,
+        mini_hulls: &[Vec<Point2<f64>>],
+        start: &Point2<f64>,
+        guess: usize,
+    ) -> Result<Vec<Point2<f64>>, Vec<Point2<f64>>> {
+        let mut current = mini_hulls
+            .iter()
+            .enumerate()
+            .find_map(|(hull_index, hull)| {
+                hull.iter()
+                    .position(|point| point == start)
+                    .map(|point_index| (hull_index, point_index))
+            })
+            .expect("The leftmost point has to be on a mini-hull.");
+        let mut chain = vec![*start];
+
+        for _ in 0..guess {
+            let point = mini_hulls[current.0][current.1];
+
+            // On its own mini-hull the next candidate is simply the successor.
+            let mut best = (current.0, (current.1 + 1) % mini_hulls[current.0].len());
+
+            for (hull_index, hull) in mini_hulls.iter().enumerate() {
+                if hull_index == current.0 {
+                    continue;
+                }
+                let best_point = mini_hulls[best.0][best.1];
+                let mut base = mini_hull_drawables(mini_hulls);
+                base.extend(chain_drawables(&chain, Color::White));
+                base.push(Drawable::line(&point, &best_point, Color::Yellow));
+
+                let tangent = self.tangent(hull, hull_index, &point, &base, guess);
+                let cross = cross_product(&point, &best_point, &hull[tangent]);
+                if cross < 0.0
+                    || (cross == 0.0
+                        && distance(&point, &hull[tangent]) > distance(&point, &best_point))
+                {
+                    best = (hull_index, tangent);
+                }
+            }
+
+            let next = mini_hulls[best.0][best.1];
+            if next == *start {
+                return Ok(chain);
+            }
+            chain.push(next);
+            current = best;
+
+            let mut step = mini_hull_drawables(mini_hulls);
+            step.extend(chain_drawables(&chain, Color::White));
+            self.add_step(
+                step,
+                format!(
+                    "Guess m = {guess}: wrapping step {} of at most {guess} adds the best \
+                     tangent point.",
+                    chain.len() - 1
+                ),
+            );
+        }
+        Err(chain)
+    }
+
+    /// Finds the tangent point from `point` to the counterclockwise mini-hull with
+    /// a binary search. All points of the mini-hull lie left of the returned tangent.
+    /// Every probe of the search is recorded as a step on top of `base`.
+    fn tangent(
+        &mut self,
+        hull: &[Point2<f64>],
+        hull_index: usize,
+        point: &Point2<f64>,
+        base: &[Drawable],
+        guess: usize,
+    ) -> usize {
+        let n = hull.len();
+        let turn_at = |a: usize, b: usize| turn(point, &hull[a % n], &hull[b % n]);
+
+        let mut left = 0;
+        let mut right = n;
+        let mut left_before = turn_at(0, n - 1);
+        let mut left_after = turn_at(0, 1);
+
+        while left < right {
+            let center = (left + right) / 2;
+
+            let mut step = base.to_vec();
+            step.push(Drawable::line(point, &hull[center], Color::Gray));
+            self.add_step(
+                step,
+                format!(
+                    "Guess m = {guess}: tangent search on mini-hull {} probes vertex {}.",
+                    hull_index + 1,
+                    center + 1
+                ),
+            );
+
+            let center_before = turn_at(center, center + n - 1);
+            let center_after = turn_at(center, center + 1);
+            let center_side = turn_at(left, center);
+
+            if center_before >= 0 && center_after >= 0 {
+                return center;
+            } else if (center_side > 0 && (left_after < 0 || left_before == left_after))
+                || (center_side < 0 && center_before < 0)
+            {
+                right = center;
+            } else {
+                left = center + 1;
+                left_before = -center_after;
+                left_after = turn_at(left, left + 1);
+            }
+        }
+        left % n
+    }
+}
+
+/// Returns 1 for a left turn, -1 for a right turn and 0 for collinear points.
+fn turn(origin: &Point2<f64>, a: &Point2<f64>, b: &Point2<f64>) -> i8 {
+    let cross = cross_product(origin, a, b);
+    if cross > 0.0 {
+        1
+    } else if cross < 0.0 {
+        -1
+    } else {
+        0
+    }
+}
+
+fn mini_hull_drawables(mini_hulls: &[Vec<Point2<f64>>]) -> Vec<Drawable> {
+    mini_hulls
+        .iter()
+        .zip(MINI_HULL_COLORS.iter().cycle())
+        .flat_map(|(hull, color)| {
+            hull.iter()
+                .circular_tuple_windows()
+                .map(|(from, to)| Drawable::line(from, to, *color))
+                .collect::<Vec<_>>()
+        })
+        .collect()
+}
+
+fn chain_drawables(chain: &[Point2<f64>], color: Color) -> Vec<Drawable> {
+    chain
+        .iter()
+        .tuple_windows()
+        .map(|(from, to)| Drawable::line(from, to, color))
+        .collect()
+}
+
+impl Algorithm for ChansAlgorithm {
+    fn get_title(&self) -> &str {
+        "Chan's algorithm"
+    }
+
+    fn get_points(&self) -> &Vec<Point2<f64>> {
+        &self.points
+    }
+
+    fn set_points(&mut self, points: Vec<Point2<f64>>) {
+        self.points = points;
+    }
+
+    fn get_steps(&self) -> Vec<Vec<Drawable>> {
+        self.steps.clone()
+    }
+
+    fn get_draw_method(&self) -> DrawMethod {
+        DrawMethod::Edge
+    }
+
+    fn get_step_description(&self, step: usize) -> Option<String> {
+        self.descriptions.get(step).cloned()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::algorithms::testing::{assert_same_points, brute_force_hull, random_points};
+
+    fn chans_algorithm(points: Vec<Point2<f64>>) -> ChansAlgorithm {
+        let mut chans_algorithm = ChansAlgorithm::new();
+        chans_algorithm.set_points(points);
+        chans_algorithm.calculate();
+        chans_algorithm
+    }
+
+    #[test]
+    fn fewer_than_three_points_are_their_own_hull() {
+        for amount in 0..3 {
+            let points = random_points(amount);
+            assert_same_points(&chans_algorithm(points.clone()).hull, &points);
+        }
+    }
+
+    #[test]
+    fn matches_brute_force_hull() {
+        for amount in 3..80 {
+            let points = random_points(amount);
+            let chans_algorithm = chans_algorithm(points.clone());
+            assert_same_points(&chans_algorithm.hull, &brute_force_hull(&points));
+            assert!(chans_algorithm
+                .hull
+                .iter()
+                .circular_tuple_windows()
+                .all(|(a, b, c)| cross_product(a, b, c) > 0.0));
+        }
+    }
+
+    #[test]
+    fn small_guesses_are_squared_until_the_hull_fits() {
+        // All points of a circle are on the hull, so the guesses 4 and 16 fail.
+        let circle = (0..20)
+            .map(|i| {
+                let angle = i as f64 / 20.0 * std::f64::consts::TAU;
+                Point2::new(50.0 + 40.0 * angle.cos(), 50.0 + 40.0 * angle.sin())
+            })
+            .collect::<Vec<_>>();
+        let chans_algorithm = chans_algorithm(circle.clone());
+        assert_same_points(&chans_algorithm.hull, &circle);
+        let abandoned = chans_algorithm
+            .descriptions
+            .iter()
+            .filter(|description| description.contains("abandoned"))
+            .count();
+        assert_eq!(abandoned, 2);
+    }
+}
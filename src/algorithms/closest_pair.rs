@@ -0,0 +1,318 @@
+use nalgebra::Point2;
+use tui::style::Color;
+
+use super::algorithm::{Algorithm, DrawMethod, Drawable};
+
+/// The closest pair of a subproblem as indices into the points and their distance.
+type Pair = (usize, usize, f64);
+
+pub struct ClosestPair {
+    // Later passed to app.
+    pub maximum_step_count: usize,
+    pub points: Vec<Point2<f64>>,
+    pub steps: Vec<Vec<Drawable>>,
+    pub descriptions: Vec<String>,
+    // The closest pair found, if there are at least two points.
+    pub pair: Option<Pair>,
+    y_bounds: [f64; 2],
+}
+
+impl ClosestPair {
+    pub fn new() -> Self {
+        ClosestPair {
+            maximum_step_count: 0,
+            points: vec![],
+            steps: vec![],
+            descriptions: vec![],
+            pair: None,
+            y_bounds: [0.0, 0.0],
+        }
+    }
+
+    fn add_step(&mut self, drawables: Vec<Drawable>, description: String) {
+        self.steps.push(drawables);
+        self.descriptions.push(description);
+        self.maximum_step_count += 1;
+    }
+
+    fn vertical_line(&self, x: f64, color: Color) -> Drawable {
+        Drawable::line(
+            &Point2::new(x, self.y_bounds[0]),
+            &Point2::new(x, self.y_bounds[1]),
+            color,
+        )
+    }
+
+    fn pair_line(&self, (from, to, _): Pair, color: Color) -> Drawable {
+        Drawable::line(&self.points[from], &self.points[to], color)
+    }
+
+    fn subset(&self, indices: &[usize], color: Color) -> Drawable {
+        Drawable::points(
+            &indices
+                .iter()
+                .map(|index| self.points[*index])
+                .collect::<Vec<_>>(),
+            color,
+        )
+    }
+
+    fn distance(&self, from: usize, to: usize) -> f64 {
+        (self.points[from] - self.points[to]).norm()
+    }
+
+    /// Solves the subproblem of points sorted by x. Returns its closest pair and
+    /// its points sorted by y.
+    fn solve(&mut self, by_x: &[usize]) -> (Pair, Vec<usize>) {
+        if by_x.len() <= 3 {
+            let mut best = (by_x[0], by_x[1], self.distance(by_x[0], by_x[1]));
+            for (position, from) in by_x.iter().enumerate() {
+                for to in &by_x[position + 1..] {
+                    let distance = self.distance(*from, *to);
+                    if distance < best.2 {
+                        best = (*from, *to, distance);
+                    }
+                }
+            }
+            let mut by_y = by_x.to_vec();
+            by_y.sort_by(|a, b| self.points[*a].y.partial_cmp(&self.points[*b].y).unwrap());
+
+            let drawables = vec![
+                self.subset(by_x, Color::White),
+                self.pair_line(best, Color::Green),
+            ];
+            self.add_step(
+                drawables,
+                format!(
+                    "Solve the {} points by brute force: δ = {:.2}.",
+                    by_x.len(),
+                    best.2
+                ),
+            );
+            return (best, by_y);
+        }
+
+        let middle = by_x.len() / 2;
+        let split = (self.points[by_x[middle - 1]].x + self.points[by_x[middle]].x) / 2.0;
+        let drawables = vec![
+            self.subset(by_x, Color::White),
+            self.vertical_line(split, Color::Yellow),
+        ];
+        self.add_step(
+            drawables,
+            format!(
+                "Split the {} points at x = {split:.2} into two halves of {} and {}.",
+                by_x.len(),
+                middle,
+                by_x.len() - middle
+            ),
+        );
+
+        let (left, left_by_y) = self.solve(&by_x[..middle]);
+        let (right, right_by_y) = self.solve(&by_x[middle..]);
+        let mut best = if left.2 <= right.2 { left } else { right };
+        let delta = best.2;
+
+        let strip_lines = [
+            self.vertical_line(split, Color::Yellow),
+            self.vertical_line(split - delta, Color::Cyan),
+            self.vertical_line(split + delta, Color::Cyan),
+        ];
+        let mut drawables = strip_lines.to_vec();
+        drawables.push(self.subset(by_x, Color::White));
+        drawables.push(self.pair_line(left, Color::Green));
+        drawables.push(self.pair_line(right, Color::Green));
+        self.add_step(
+            drawables,
+            format!(
+                "Combine at x = {split:.2}: δ = {:.2} on the left and {:.2} on the right. Only \
+                 points within δ = {delta:.2} of the split line can form a closer pair.",
+                left.2, right.2
+            ),
+        );
+
+        // Merging keeps the points sorted by y without sorting again.
+        let mut by_y = Vec::with_capacity(by_x.len());
+        let (mut left_position, mut right_position) = (0, 0);
+        while left_position < left_by_y.len() || right_position < right_by_y.len() {
+            let take_left = right_position == right_by_y.len()
+                || (left_position < left_by_y.len()
+                    && self.points[left_by_y[left_position]].y
+                        <= self.points[right_by_y[right_position]].y);
+            if take_left {
+                by_y.push(left_by_y[left_position]);
+                left_position += 1;
+            } else {
+                by_y.push(right_by_y[right_position]);
+                right_position += 1;
+            }
+        }
+
+        let strip = by_y
+            .iter()
+            .copied()
+            .filter(|index| (self.points[*index].x - split).abs() < delta)
+            .collect::<Vec<_>>();
+        for (position, from) in strip.iter().enumerate() {
+            // Only a constant number of strip points lie within δ above the current one.
+            let neighbours = strip[position + 1..]
+                .iter()
+                .copied()
+                .take_while(|to| self.points[*to].y - self.points[*from].y < best.2)
+                .collect::<Vec<_>>();
+            if neighbours.is_empty() {
+                continue;
+            }
+            let previous = best;
+            for to in neighbours.iter() {
+                let distance = self.distance(*from, *to);
+                if distance < best.2 {
+                    best = (*from, *to, distance);
+                }
+            }
+
+            let mut drawables = strip_lines.to_vec();
+            drawables.extend(
+                neighbours.iter().map(|to| {
+                    Drawable::line(&self.points[*from], &self.points[*to], Color::DarkGray)
+                }),
+            );
+            drawables.push(self.pair_line(best, Color::Green));
+            drawables.push(self.subset(&strip, Color::White));
+            drawables.push(self.subset(&[*from], Color::Yellow));
+            let description = if best.2 < previous.2 {
+                format!(
+                    "Check v{from} against {} of the strip points within δ above it. The pair \
+                     v{} - v{} is closer: δ = {:.2}.",
+                    neighbours.len(),
+                    best.0,
+                    best.1,
+                    best.2
+                )
+            } else {
+                format!(
+                    "Check v{from} against {} of the strip points within δ above it. None is \
+                     closer.",
+                    neighbours.len()
+                )
+            };
+            self.add_step(drawables, description);
+        }
+
+        (best, by_y)
+    }
+
+    pub fn calculate(&mut self) {
+        self.steps = vec![];
+        self.descriptions = vec![];
+        self.maximum_step_count = 0;
+        self.pair = None;
+
+        if self.points.len() < 2 {
+            self.add_step(
+                vec![],
+                String::from("The closest pair needs at least two points."),
+            );
+            return;
+        }
+
+        let mut by_x = (0..self.points.len()).collect::<Vec<_>>();
+        by_x.sort_by(|a, b| self.points[*a].x.partial_cmp(&self.points[*b].x).unwrap());
+        let (best, _) = self.solve(&by_x);
+        self.pair = Some(best);
+
+        let drawables = vec![
+            self.pair_line(best, Color::Yellow),
+            self.subset(&[best.0, best.1], Color::Yellow),
+        ];
+        self.add_step(
+            drawables,
+            format!(
+                "The closest pair is v{} - v{} with distance {:.2}.",
+                best.0, best.1, best.2
+            ),
+        );
+    }
+}
+
+impl Algorithm for ClosestPair {
+    fn get_title(&self) -> &str {
+        "Closest pair"
+    }
+
+    fn get_points(&self) -> &Vec<Point2<f64>> {
+        &self.points
+    }
+
+    fn set_points(&mut self, points: Vec<Point2<f64>>) {
+        self.points = points;
+    }
+
+    fn set_bounds(&mut self, _x_bounds: [f64; 2], y_bounds: [f64; 2]) {
+        self.y_bounds = y_bounds;
+    }
+
+    fn get_steps(&self) -> Vec<Vec<Drawable>> {
+        self.steps.clone()
+    }
+
+    fn get_draw_method(&self) -> DrawMethod {
+        DrawMethod::Edge
+    }
+
+    fn get_step_description(&self, step: usize) -> Option<String> {
+        self.descriptions.get(step).cloned()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::algorithms::testing::random_points;
+
+    fn closest_pair(points: Vec<Point2<f64>>) -> ClosestPair {
+        let mut closest_pair = ClosestPair::new();
+        closest_pair.set_bounds([0.0, 100.0], [0.0, 100.0]);
+        closest_pair.set_points(points);
+        closest_pair.calculate();
+        closest_pair
+    }
+
+    fn brute_force(points: &[Point2<f64>]) -> f64 {
+        let mut best = f64::MAX;
+        for (index, a) in points.iter().enumerate() {
+            for b in &points[index + 1..] {
+                best = best.min((a - b).norm());
+            }
+        }
+        best
+    }
+
+    #[test]
+    fn small_inputs_have_no_pair() {
+        for amount in 0..2 {
+            assert!(closest_pair(random_points(amount)).pair.is_none());
+        }
+    }
+
+    #[test]
+    fn matches_brute_force() {
+        for amount in 2..60 {
+            let points = random_points(amount);
+            let (a, b, distance) = closest_pair(points.clone()).pair.unwrap();
+            assert!((distance - brute_force(&points)).abs() < 1e-9);
+            assert!(((points[a] - points[b]).norm() - distance).abs() < 1e-9);
+        }
+    }
+
+    #[test]
+    fn handles_shared_coordinates() {
+        // Points on a grid share their x and y coordinates with many others.
+        let grid = (0..30)
+            .map(|index| Point2::new((index % 5) as f64 * 3.0, (index / 5) as f64 * 2.0))
+            .collect::<Vec<_>>();
+        assert_eq!(closest_pair(grid).pair.unwrap().2, 2.0);
+        let duplicates = vec![Point2::new(1.0, 1.0); 3];
+        assert_eq!(closest_pair(duplicates).pair.unwrap().2, 0.0);
+    }
+}
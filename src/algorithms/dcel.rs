@@ -0,0 +1,321 @@
+use nalgebra::{Point2, Vector2};
+
+use super::geometry::in_polygon;
+
+pub struct Vertex {
+    pub point: Point2<f64>,
+    // Some half-edge starting at the vertex.
+    pub incident: Option<usize>,
+}
+
+/// A directed edge with its face on the left.
+pub struct HalfEdge {
+    pub origin: usize,
+    pub twin: usize,
+    pub next: usize,
+    pub prev: usize,
+    pub face: usize,
+}
+
+pub struct Face {
+    // Some half-edge of the counterclockwise outer boundary, which the
+    // unbounded face does not have.
+    pub outer: Option<usize>,
+    // Some half-edge of every clockwise boundary of a hole.
+    pub inner: Vec<usize>,
+}
+
+/// # Doubly-connected edge list.
+/// Represents a planar subdivision by its vertices, half-edges and faces.
+/// The first face is always the unbounded one. Edges are added in twin pairs,
+/// so the twin of half-edge `2i` is `2i + 1`.
+pub struct Dcel {
+    pub vertices: Vec<Vertex>,
+    pub half_edges: Vec<HalfEdge>,
+    pub faces: Vec<Face>,
+}
+
+impl Dcel {
+    pub fn new() -> Self {
+        Dcel {
+            vertices: vec![],
+            half_edges: vec![],
+            faces: vec![Face {
+                outer: None,
+                inner: vec![],
+            }],
+        }
+    }
+
+    pub fn add_vertex(&mut self, point: Point2<f64>) -> usize {
+        self.vertices.push(Vertex {
+            point,
+            incident: None,
+        });
+        self.vertices.len() - 1
+    }
+
+    /// Adds an edge as a pair of twins and returns the half-edge starting at
+    /// `from`. Until `link` is called, both twins form a cycle on their own.
+    pub fn add_edge(&mut self, from: usize, to: usize) -> usize {
+        let index = self.half_edges.len();
+        for (half_edge, origin, twin) in [(index, from, index + 1), (index + 1, to, index)] {
+            self.half_edges.push(HalfEdge {
+                origin,
+                twin,
+                next: twin,
+                prev: twin,
+                face: 0,
+            });
+            self.vertices[origin].incident.get_or_insert(half_edge);
+        }
+        index
+    }
+
+    pub fn origin(&self, half_edge: usize) -> &Point2<f64> {
+        &self.vertices[self.half_edges[half_edge].origin].point
+    }
+
+    pub fn destination(&self, half_edge: usize) -> &Point2<f64> {
+        self.origin(self.half_edges[half_edge].twin)
+    }
+
+    /// Sets the next and previous pointers around every vertex. Ordering the
+    /// outgoing half-edges by angle, an incoming half-edge continues with the
+    /// outgoing one clockwise after its twin.
+    pub fn link(&mut self) {
+        let mut outgoing = vec![vec![]; self.vertices.len()];
+        for (index, half_edge) in self.half_edges.iter().enumerate() {
+            outgoing[half_edge.origin].push(index);
+        }
+        let angle = |half_edge: &usize| {
+            let direction = self.destination(*half_edge) - self.origin(*half_edge);
+            direction.y.atan2(direction.x)
+        };
+        for half_edges in outgoing.iter_mut() {
+            half_edges.sort_by(|a, b| angle(a).partial_cmp(&angle(b)).unwrap());
+        }
+        for half_edges in outgoing {
+            for (position, half_edge) in half_edges.iter().enumerate() {
+                let incoming = self.half_edges[*half_edge].twin;
+                let next = half_edges[(position + half_edges.len() - 1) % half_edges.len()];
+                self.half_edges[incoming].next = next;
+                self.half_edges[next].prev = incoming;
+            }
+        }
+    }
+
+    /// Returns the half-edges starting at the vertex in clockwise order.
+    pub fn outgoing(&self, vertex: usize) -> Vec<usize> {
+        let start = match self.vertices[vertex].incident {
+            Some(start) => start,
+            None => return vec![],
+        };
+        let mut outgoing = vec![start];
+        let mut half_edge = self.half_edges[self.half_edges[start].twin].next;
+        while half_edge != start {
+            outgoing.push(half_edge);
+            half_edge = self.half_edges[self.half_edges[half_edge].twin].next;
+        }
+        outgoing
+    }
+
+    /// Returns the half-edges of the boundary cycle the given one belongs to.
+    pub fn cycle(&self, start: usize) -> Vec<usize> {
+        let mut cycle = vec![start];
+        let mut half_edge = self.half_edges[start].next;
+        while half_edge != start {
+            cycle.push(half_edge);
+            half_edge = self.half_edges[half_edge].next;
+        }
+        cycle
+    }
+
+    /// Returns the origins of the boundary cycle the given half-edge belongs to.
+    pub fn polygon(&self, start: usize) -> Vec<Point2<f64>> {
+        self.cycle(start)
+            .into_iter()
+            .map(|half_edge| *self.origin(half_edge))
+            .collect()
+    }
+
+    /// Creates the faces from the boundary cycles of the linked half-edges.
+    /// Counterclockwise cycles bound a face from the outside, clockwise cycles
+    /// are holes in the smallest face around them.
+    pub fn build_faces(&mut self) {
+        self.faces.truncate(1);
+        self.faces[0].inner = vec![];
+
+        let mut visited = vec![false; self.half_edges.len()];
+        let mut holes = vec![];
+        for start in 0..self.half_edges.len() {
+            if visited[start] {
+                continue;
+            }
+            let cycle = self.cycle(start);
+            for half_edge in cycle.iter() {
+                visited[*half_edge] = true;
+            }
+            if area(&self.polygon(start)) > 0.0 {
+                self.faces.push(Face {
+                    outer: Some(start),
+                    inner: vec![],
+                });
+                for half_edge in cycle {
+                    self.half_edges[half_edge].face = self.faces.len() - 1;
+                }
+            } else {
+                holes.push(start);
+            }
+        }
+
+        for hole in holes {
+            // Slightly left of the leftmost vertex, the point lies outside of the
+            // faces of its own component, but inside every face around it.
+            let leftmost = self
+                .polygon(hole)
+                .into_iter()
+                .min_by(|a, b| a.x.partial_cmp(&b.x).unwrap())
+                .unwrap();
+            let point = Point2::new(leftmost.x - 1e-9 * (1.0 + leftmost.x.abs()), leftmost.y);
+            let face = (1..self.faces.len())
+                .filter(|face| in_polygon(&point, &self.polygon(self.faces[*face].outer.unwrap())))
+                .min_by(|a, b| {
+                    let area = |face: &usize| area(&self.polygon(self.faces[*face].outer.unwrap()));
+                    area(a).partial_cmp(&area(b)).unwrap()
+                })
+                .unwrap_or(0);
+            self.faces[face].inner.push(hole);
+            for half_edge in self.cycle(hole) {
+                self.half_edges[half_edge].face = face;
+            }
+        }
+    }
+
+    /// Finds the face containing the point by testing it against the outer
+    /// boundary and the holes of every bounded face.
+    pub fn locate(&self, point: &Point2<f64>) -> usize {
+        (1..self.faces.len())
+            .find(|face| {
+                let face = &self.faces[*face];
+                in_polygon(point, &self.polygon(face.outer.unwrap()))
+                    && !face
+                        .inner
+                        .iter()
+                        .any(|hole| in_polygon(point, &self.polygon(*hole)))
+            })
+            .unwrap_or(0)
+    }
+
+    /// Finds a point inside a bounded face to place its label. This is the
+    /// centroid of its outer boundary, if the face contains it, or else a point
+    /// next to the middle of its longest edge.
+    pub fn interior_point(&self, face: usize) -> Option<Point2<f64>> {
+        let outer = self.faces[face].outer?;
+        let polygon = self.polygon(outer);
+        let centroid = Point2::from(
+            polygon
+                .iter()
+                .fold(Vector2::zeros(), |sum, point| sum + point.coords)
+                / polygon.len() as f64,
+        );
+        if self.locate(&centroid) == face {
+            return Some(centroid);
+        }
+
+        let longest = self
+            .cycle(outer)
+            .into_iter()
+            .max_by(|a, b| {
+                let length = |half_edge: &usize| {
+                    (self.destination(*half_edge) - self.origin(*half_edge)).norm()
+                };
+                length(a).partial_cmp(&length(b)).unwrap()
+            })
+            .unwrap();
+        let (from, to) = (self.origin(longest), self.destination(longest));
+        let direction = to - from;
+        // The face lies left of its half-edges.
+        let normal = Vector2::new(-direction.y, direction.x) * 1e-6;
+        Some(from + direction / 2.0 + normal)
+    }
+}
+
+/// Calculates the signed area of the polygon, positive if it is counterclockwise.
+fn area(polygon: &[Point2<f64>]) -> f64 {
+    polygon
+        .iter()
+        .enumerate()
+        .map(|(index, from)| {
+            let to = &polygon[(index + 1) % polygon.len()];
+            from.x * to.y - to.x * from.y
+        })
+        .sum::<f64>()
+        / 2.0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Builds a square with a square hole and returns it together with the
+    /// counterclockwise half-edges along the bottom of both squares.
+    fn square_with_hole() -> (Dcel, [usize; 2]) {
+        let mut dcel = Dcel::new();
+        let bottoms = [(0.0, 10.0), (3.0, 6.0)].map(|(low, high)| {
+            let vertices = [(low, low), (high, low), (high, high), (low, high)]
+                .map(|(x, y)| dcel.add_vertex(Point2::new(x, y)));
+            (0..4)
+                .map(|index| dcel.add_edge(vertices[index], vertices[(index + 1) % 4]))
+                .collect::<Vec<_>>()[0]
+        });
+        dcel.link();
+        dcel.build_faces();
+        (dcel, bottoms)
+    }
+
+    #[test]
+    fn link_connects_the_half_edges() {
+        let (dcel, _) = square_with_hole();
+        for (index, half_edge) in dcel.half_edges.iter().enumerate() {
+            assert_eq!(dcel.half_edges[half_edge.twin].twin, index);
+            assert_eq!(dcel.half_edges[half_edge.next].prev, index);
+            assert_eq!(dcel.half_edges[half_edge.prev].next, index);
+            assert_eq!(dcel.origin(half_edge.next), dcel.destination(index));
+            assert_eq!(dcel.half_edges[half_edge.next].face, half_edge.face);
+        }
+        for vertex in 0..dcel.vertices.len() {
+            assert_eq!(dcel.outgoing(vertex).len(), 2);
+        }
+    }
+
+    #[test]
+    fn build_faces_puts_holes_into_the_face_around_them() {
+        let (dcel, [outer, inner]) = square_with_hole();
+        assert_eq!(dcel.faces.len(), 3);
+        let [ring, square] = [outer, inner].map(|half_edge| dcel.half_edges[half_edge].face);
+        assert_ne!(ring, square);
+        assert_eq!(dcel.faces[ring].outer, Some(outer));
+        assert_eq!(dcel.faces[square].outer, Some(inner));
+
+        // The twins run clockwise around the hole and the whole square.
+        for (face, half_edge) in [(ring, inner), (0, outer)] {
+            let twin = dcel.half_edges[half_edge].twin;
+            assert_eq!(dcel.half_edges[twin].face, face);
+            assert_eq!(dcel.faces[face].inner.len(), 1);
+            assert!(dcel.cycle(dcel.faces[face].inner[0]).contains(&twin));
+        }
+    }
+
+    #[test]
+    fn locate_skips_holes() {
+        let (dcel, [outer, inner]) = square_with_hole();
+        let [ring, square] = [outer, inner].map(|half_edge| dcel.half_edges[half_edge].face);
+        assert_eq!(dcel.locate(&Point2::new(1.0, 5.0)), ring);
+        assert_eq!(dcel.locate(&Point2::new(4.0, 5.0)), square);
+        assert_eq!(dcel.locate(&Point2::new(20.0, 5.0)), 0);
+        for face in [ring, square] {
+            assert_eq!(dcel.locate(&dcel.interior_point(face).unwrap()), face);
+        }
+    }
+}
@@ -0,0 +1,362 @@
+use itertools::Itertools;
+use nalgebra::Point2;
+use tui::style::Color;
+
+use super::{
+    algorithm::{Algorithm, DrawMethod, Drawable},
+    geometry::cross_product,
+};
+
+pub struct DivideAndConquerHull {
+    // Later passed to app.
+    pub maximum_step_count: usize,
+    pub points: Vec<Point2<f64>>,
+    pub steps: Vec<Vec<Drawable>>,
+    pub descriptions: Vec<String>,
+    // The vertices of the hull in counterclockwise order.
+    pub hull: Vec<Point2<f64>>,
+    // Finished hulls waiting for their right neighbour to be merged with.
+    pending: Vec<Vec<Point2<f64>>>,
+    y_range: [f64; 2],
+}
+
+/// Which bridge of the merge step is searched.
+enum Bridge {
+    Upper,
+    Lower,
+}
+
+impl DivideAndConquerHull {
+    pub fn new() -> Self {
+        DivideAndConquerHull {
+            maximum_step_count: 0,
+            points: vec![],
+            steps: vec![],
+            descriptions: vec![],
+            hull: vec![],
+            pending: vec![],
+            y_range: [0.0, 0.0],
+        }
+    }
+
+    /// Stores the given drawables together with all pending hulls.
+    fn add_step(&mut self, drawables: Vec<Drawable>, description: String) {
+        let mut step = self
+            .pending
+            .iter()
+            .flat_map(|hull| hull_drawables(hull, Color::DarkGray))
+            .collect::<Vec<_>>();
+        step.extend(drawables);
+
+        self.steps.push(step);
+        self.descriptions.push(description);
+        self.maximum_step_count += 1;
+    }
+
+    pub fn calculate(&mut self) {
+        self.steps = vec![];
+        self.descriptions = vec![];
+        self.maximum_step_count = 0;
+        self.hull = vec![];
+        self.pending = vec![];
+
+        let mut points = self.points.clone();
+
+        // Sort points lexicographically.
+        points.sort_by(|a, b| (a.x, a.y).partial_cmp(&(b.x, b.y)).unwrap());
+
+        if points.is_empty() {
+            self.add_step(vec![], String::from("There are no points."));
+            return;
+        }
+
+        self.y_range = points.iter().fold([f64::MAX, f64::MIN], |range, point| {
+            [range[0].min(point.y), range[1].max(point.y)]
+        });
+
+        let hull = self.hull(&points);
+        self.add_step(
+            hull_drawables(&hull, Color::Yellow),
+            format!("The convex hull has {} points.", hull.len()),
+        );
+        self.hull = hull;
+    }
+
+    /// Computes the counterclockwise hull of the lexicographically sorted points.
+    fn hull(&mut self, points: &[Point2<f64>]) -> Vec<Point2<f64>> {
+        if points.len() <= 3 {
+            let hull = base_hull(points);
+            self.add_step(
+                hull_drawables(&hull, Color::White),
+                format!("Base case: hull of {} points.", points.len()),
+            );
+            return hull;
+        }
+
+        let middle = points.len() / 2;
+        let split = (points[middle - 1].x + points[middle].x) / 2.0;
+        self.add_step(
+            vec![
+                Drawable::line(
+                    &Point2::new(split, self.y_range[0]),
+                    &Point2::new(split, self.y_range[1]),
+                    Color::Gray,
+                ),
+                Drawable::points(&points[..middle], Color::Cyan),
+                Drawable::points(&points[middle..], Color::Green),
+            ],
+            format!(
+                "Split {} points into {} left and {} right points.",
+                points.len(),
+                middle,
+                points.len() - middle
+            ),
+        );
+
+        let left = self.hull(&points[..middle]);
+        self.pending.push(left);
+        let right = self.hull(&points[middle..]);
+        let left = self.pending.pop().unwrap();
+
+        self.merge(&left, &right)
+    }
+
+    /// Merges two counterclockwise hulls separated by a vertical line.
+    fn merge(&mut self, left: &[Point2<f64>], right: &[Point2<f64>]) -> Vec<Point2<f64>> {
+        // Both walks start at the rightmost point of the left and the
+        // leftmost point of the right hull.
+        let rightmost = position_by(left, |a, b| (a.x, a.y) > (b.x, b.y));
+        let leftmost = position_by(right, |a, b| (a.x, a.y) < (b.x, b.y));
+
+        let (upper_left, upper_right) =
+            self.find_bridge(left, right, rightmost, leftmost, Bridge::Upper);
+        let (lower_left, lower_right) =
+            self.find_bridge(left, right, rightmost, leftmost, Bridge::Lower);
+
+        // Walk counterclockwise over the left hull from the upper to the lower
+        // bridge and continue on the right hull from the lower to the upper bridge.
+        let mut hull = vec![];
+        let mut index = upper_left;
+        loop {
+            hull.push(left[index]);
+            if index == lower_left {
+                break;
+            }
+            index = (index + 1) % left.len();
+        }
+        let mut index = lower_right;
+        loop {
+            hull.push(right[index]);
+            if index == upper_right {
+                break;
+            }
+            index = (index + 1) % right.len();
+        }
+
+        let mut step = hull_drawables(&hull, Color::White);
+        step.push(Drawable::line(
+            &left[upper_left],
+            &right[upper_right],
+            Color::Blue,
+        ));
+        step.push(Drawable::line(
+            &left[lower_left],
+            &right[lower_right],
+            Color::Blue,
+        ));
+        self.add_step(
+            step,
+            format!(
+                "Merged both hulls into a hull of {} points along the bridges.",
+                hull.len()
+            ),
+        );
+        hull
+    }
+
+    /// Walks the candidate bridge outwards until no neighbouring hull point lies
+    /// beyond it. Every move of the walk is recorded as a step.
+    fn find_bridge(
+        &mut self,
+        left: &[Point2<f64>],
+        right: &[Point2<f64>],
+        mut left_index: usize,
+        mut right_index: usize,
+        bridge: Bridge,
+    ) -> (usize, usize) {
+        let (name, color, sign) = match bridge {
+            Bridge::Upper => ("upper", Color::Yellow, 1.0),
+            Bridge::Lower => ("lower", Color::Magenta, -1.0),
+        };
+
+        // The upper bridge moves counterclockwise on the left and clockwise on the
+        // right hull. The lower bridge moves the other way around.
+        let next = |index: usize, length: usize, forward: bool| {
+            if forward {
+                (index + 1) % length
+            } else {
+                (index + length - 1) % length
+            }
+        };
+        let forward = matches!(bridge, Bridge::Upper);
+
+        let walk = |this: &mut Self, left_index: usize, right_index: usize, moved: &str| {
+            let mut step = hull_drawables(left, Color::Cyan);
+            step.extend(hull_drawables(right, Color::Green));
+            step.push(Drawable::line(
+                &left[left_index],
+                &right[right_index],
+                color,
+            ));
+            this.add_step(step, format!("Searching the {name} bridge: {moved}"));
+        };
+        walk(
+            self,
+            left_index,
+            right_index,
+            "start at the inner extreme points of both hulls.",
+        );
+
+        loop {
+            let mut moved = false;
+
+            loop {
+                let candidate = next(left_index, left.len(), forward);
+                if sign * cross_product(&left[left_index], &right[right_index], &left[candidate])
+                    <= 0.0
+                {
+                    break;
+                }
+                left_index = candidate;
+                moved = true;
+                walk(
+                    self,
+                    left_index,
+                    right_index,
+                    "the left end moves because its neighbour lies beyond the bridge.",
+                );
+            }
+
+            loop {
+                let candidate = next(right_index, right.len(), !forward);
+                if sign * cross_product(&left[left_index], &right[right_index], &right[candidate])
+                    <= 0.0
+                {
+                    break;
+                }
+                right_index = candidate;
+                moved = true;
+                walk(
+                    self,
+                    left_index,
+                    right_index,
+                    "the right end moves because its neighbour lies beyond the bridge.",
+                );
+            }
+
+            if !moved {
+                break;
+            }
+        }
+        (left_index, right_index)
+    }
+}
+
+/// Computes the counterclockwise hull of at most three sorted points.
+fn base_hull(points: &[Point2<f64>]) -> Vec<Point2<f64>> {
+    match points {
+        [first, second, third] => {
+            let cross = cross_product(first, second, third);
+            if cross > 0.0 {
+                vec![*first, *second, *third]
+            } else if cross < 0.0 {
+                vec![*first, *third, *second]
+            } else {
+                vec![*first, *third]
+            }
+        }
+        _ => points.to_vec(),
+    }
+}
+
+fn position_by<F>(points: &[Point2<f64>], better: F) -> usize
+where
+    F: Fn(&Point2<f64>, &Point2<f64>) -> bool,
+{
+    (0..points.len())
+        .reduce(|best, index| {
+            if better(&points[index], &points[best]) {
+                index
+            } else {
+                best
+            }
+        })
+        .unwrap_or(0)
+}
+
+fn hull_drawables(hull: &[Point2<f64>], color: Color) -> Vec<Drawable> {
+    hull.iter()
+        .circular_tuple_windows()
+        .map(|(from, to)| Drawable::line(from, to, color))
+        .collect()
+}
+
+impl Algorithm for DivideAndConquerHull {
+    fn get_title(&self) -> &str {
+        "Divide and conquer hull"
+    }
+
+    fn get_points(&self) -> &Vec<Point2<f64>> {
+        &self.points
+    }
+
+    fn set_points(&mut self, points: Vec<Point2<f64>>) {
+        self.points = points;
+    }
+
+    fn get_steps(&self) -> Vec<Vec<Drawable>> {
+        self.steps.clone()
+    }
+
+    fn get_draw_method(&self) -> DrawMethod {
+        DrawMethod::Edge
+    }
+
+    fn get_step_description(&self, step: usize) -> Option<String> {
+        self.descriptions.get(step).cloned()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::algorithms::testing::{assert_same_points, brute_force_hull, random_points};
+
+    fn divide_and_conquer_hull(points: Vec<Point2<f64>>) -> DivideAndConquerHull {
+        let mut divide_and_conquer_hull = DivideAndConquerHull::new();
+        divide_and_conquer_hull.set_points(points);
+        divide_and_conquer_hull.calculate();
+        divide_and_conquer_hull
+    }
+
+    #[test]
+    fn fewer_than_three_points_are_their_own_hull() {
+        for amount in 0..3 {
+            let points = random_points(amount);
+            assert_same_points(&divide_and_conquer_hull(points.clone()).hull, &points);
+        }
+    }
+
+    #[test]
+    fn matches_brute_force_hull() {
+        for amount in 3..80 {
+            let points = random_points(amount);
+            let hull = divide_and_conquer_hull(points.clone()).hull;
+            assert_same_points(&hull, &brute_force_hull(&points));
+            assert!(hull
+                .iter()
+                .circular_tuple_windows()
+                .all(|(a, b, c)| cross_product(a, b, c) > 0.0));
+        }
+    }
+}
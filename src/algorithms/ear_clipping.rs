@@ -0,0 +1,290 @@
+use itertools::Itertools;
+use nalgebra::Point2;
+use tui::style::Color;
+
+use super::{
+    algorithm::{Algorithm, DrawMethod, Drawable, InputKind},
+    geometry::{cross_product, in_triangle},
+};
+
+#[derive(PartialEq)]
+enum VertexClass {
+    Convex,
+    Reflex,
+    Ear,
+}
+
+pub struct EarClipping {
+    // Later passed to app.
+    pub maximum_step_count: usize,
+    pub points: Vec<Point2<f64>>,
+    pub steps: Vec<Vec<Drawable>>,
+    pub descriptions: Vec<String>,
+    // Clipped triangles as indices into the counterclockwise polygon.
+    pub triangles: Vec<[usize; 3]>,
+    // Indices of the polygon vertices that are not clipped yet.
+    remaining: Vec<usize>,
+    diagonals: Vec<(usize, usize)>,
+}
+
+impl EarClipping {
+    pub fn new() -> Self {
+        EarClipping {
+            maximum_step_count: 0,
+            points: vec![],
+            steps: vec![],
+            descriptions: vec![],
+            triangles: vec![],
+            remaining: vec![],
+            diagonals: vec![],
+        }
+    }
+
+    /// Stores the original polygon, the diagonals added so far and the remaining
+    /// polygon with its classified vertices.
+    fn add_step(&mut self, classes: &[VertexClass], drawables: Vec<Drawable>, description: String) {
+        let mut step = self
+            .points
+            .iter()
+            .circular_tuple_windows()
+            .map(|(from, to)| Drawable::line(from, to, Color::DarkGray))
+            .collect::<Vec<_>>();
+        step.extend(
+            self.diagonals.iter().map(|(from, to)| {
+                Drawable::line(&self.points[*from], &self.points[*to], Color::Blue)
+            }),
+        );
+        step.extend(
+            self.remaining
+                .iter()
+                .circular_tuple_windows()
+                .map(|(from, to)| {
+                    Drawable::line(&self.points[*from], &self.points[*to], Color::White)
+                }),
+        );
+        step.extend(drawables);
+
+        for (class, color) in [
+            (VertexClass::Convex, Color::Green),
+            (VertexClass::Reflex, Color::LightRed),
+            (VertexClass::Ear, Color::Yellow),
+        ] {
+            let points = self
+                .remaining
+                .iter()
+                .zip(classes)
+                .filter(|(_, other)| **other == class)
+                .map(|(index, _)| self.points[*index])
+                .collect::<Vec<_>>();
+            step.push(Drawable::points(&points, color));
+        }
+
+        self.steps.push(step);
+        self.descriptions.push(description);
+        self.maximum_step_count += 1;
+    }
+
+    /// Classifies every remaining vertex as convex, reflex or ear.
+    fn classify(&self) -> Vec<VertexClass> {
+        let length = self.remaining.len();
+        let is_reflex = |position: usize| {
+            let (previous, vertex, next) = self.neighbourhood(position);
+            cross_product(&previous, &vertex, &next) <= 0.0
+        };
+
+        (0..length)
+            .map(|position| {
+                if is_reflex(position) {
+                    return VertexClass::Reflex;
+                }
+
+                // A convex vertex is an ear if no reflex vertex lies inside its triangle.
+                let (previous, vertex, next) = self.neighbourhood(position);
+                let blocked = (0..length).any(|other| {
+                    let distance = (other + length - position) % length;
+                    distance > 1
+                        && distance < length - 1
+                        && is_reflex(other)
+                        && in_triangle(
+                            &self.points[self.remaining[other]],
+                            &previous,
+                            &vertex,
+                            &next,
+                        )
+                });
+                if blocked {
+                    VertexClass::Convex
+                } else {
+                    VertexClass::Ear
+                }
+            })
+            .collect()
+    }
+
+    fn neighbourhood(&self, position: usize) -> (Point2<f64>, Point2<f64>, Point2<f64>) {
+        let length = self.remaining.len();
+        (
+            self.points[self.remaining[(position + length - 1) % length]],
+            self.points[self.remaining[position]],
+            self.points[self.remaining[(position + 1) % length]],
+        )
+    }
+
+    pub fn calculate(&mut self) {
+        self.steps = vec![];
+        self.descriptions = vec![];
+        self.maximum_step_count = 0;
+        self.triangles = vec![];
+        self.remaining = (0..self.points.len()).collect();
+        self.diagonals = vec![];
+
+        if self.points.len() < 3 {
+            self.add_step(
+                &[],
+                vec![],
+                String::from("A polygon needs at least three vertices."),
+            );
+            return;
+        }
+
+        while self.remaining.len() > 3 {
+            let classes = self.classify();
+            let ear = match classes.iter().position(|class| *class == VertexClass::Ear) {
+                Some(ear) => ear,
+                None => {
+                    self.add_step(
+                        &classes,
+                        vec![],
+                        String::from("No ear found, the polygon is degenerate."),
+                    );
+                    return;
+                }
+            };
+
+            let (previous, vertex, next) = self.neighbourhood(ear);
+            let count =
+                |class: VertexClass| classes.iter().filter(|other| **other == class).count();
+            let ear_count = count(VertexClass::Ear);
+            let reflex_count = count(VertexClass::Reflex);
+            self.add_step(
+                &classes,
+                vec![
+                    Drawable::line(&previous, &vertex, Color::Magenta),
+                    Drawable::line(&vertex, &next, Color::Magenta),
+                    Drawable::line(&next, &previous, Color::Magenta),
+                ],
+                format!(
+                    "{} vertices remain with {ear_count} ears and {reflex_count} reflex \
+                     vertices. Clip the ear at v{}.",
+                    self.remaining.len(),
+                    self.remaining[ear]
+                ),
+            );
+
+            let length = self.remaining.len();
+            let triangle = [
+                self.remaining[(ear + length - 1) % length],
+                self.remaining[ear],
+                self.remaining[(ear + 1) % length],
+            ];
+            self.triangles.push(triangle);
+            self.diagonals.push((triangle[0], triangle[2]));
+            self.remaining.remove(ear);
+
+            let classes = self.classify();
+            self.add_step(
+                &classes,
+                vec![],
+                format!("Add the diagonal v{} - v{}.", triangle[0], triangle[2]),
+            );
+        }
+
+        self.triangles
+            .push([self.remaining[0], self.remaining[1], self.remaining[2]]);
+        self.remaining = vec![];
+        self.add_step(
+            &[],
+            vec![],
+            format!(
+                "The last triangle remains. The polygon is split into {} triangles.",
+                self.triangles.len()
+            ),
+        );
+    }
+}
+
+impl Algorithm for EarClipping {
+    fn get_title(&self) -> &str {
+        "Ear clipping"
+    }
+
+    fn get_points(&self) -> &Vec<Point2<f64>> {
+        &self.points
+    }
+
+    fn set_points(&mut self, points: Vec<Point2<f64>>) {
+        self.points = points;
+    }
+
+    fn get_input_kind(&self) -> InputKind {
+        InputKind::SimplePolygon
+    }
+
+    fn get_steps(&self) -> Vec<Vec<Drawable>> {
+        self.steps.clone()
+    }
+
+    fn get_draw_method(&self) -> DrawMethod {
+        DrawMethod::Edge
+    }
+
+    fn get_step_description(&self, step: usize) -> Option<String> {
+        self.descriptions.get(step).cloned()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::algorithms::testing::{random_points, random_star_polygon};
+
+    fn ear_clipping(points: Vec<Point2<f64>>) -> EarClipping {
+        let mut ear_clipping = EarClipping::new();
+        ear_clipping.set_points(points);
+        ear_clipping.calculate();
+        ear_clipping
+    }
+
+    #[test]
+    fn small_polygons_have_no_triangles() {
+        for amount in 0..3 {
+            let ear_clipping = ear_clipping(random_points(amount));
+            assert!(ear_clipping.triangles.is_empty());
+        }
+    }
+
+    #[test]
+    fn triangulates_star_polygons() {
+        for amount in 3..40 {
+            let points = random_star_polygon(amount, Point2::new(50.0, 50.0), 50.0);
+            let ear_clipping = ear_clipping(points.clone());
+            assert_eq!(ear_clipping.triangles.len(), amount - 2);
+
+            let area =
+                |a: &Point2<f64>, b: &Point2<f64>, c: &Point2<f64>| cross_product(a, b, c) / 2.0;
+            let polygon_area = (1..amount - 1)
+                .map(|index| area(&points[0], &points[index], &points[index + 1]))
+                .sum::<f64>();
+            let mut triangle_area = 0.0;
+            for [a, b, c] in &ear_clipping.triangles {
+                let (a, b, c) = (&points[*a], &points[*b], &points[*c]);
+                assert!(area(a, b, c) > 0.0);
+                triangle_area += area(a, b, c);
+                assert!(points
+                    .iter()
+                    .all(|point| [a, b, c].contains(&point) || !in_triangle(point, a, b, c)));
+            }
+            assert!((polygon_area - triangle_area).abs() < 1e-6);
+        }
+    }
+}
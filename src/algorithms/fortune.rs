@@ -0,0 +1,510 @@
+use itertools::Itertools;
+use nalgebra::{Point2, Vector2};
+use tui::style::Color;
+
+use super::{
+    algorithm::{Algorithm, DrawMethod, Drawable},
+    geometry::{circumcircle, cross_product},
+};
+
+// Number of samples used to draw the beach line across the x range.
+const SAMPLES: usize = 400;
+
+enum EventKind {
+    Site(usize),
+    Circle(usize),
+}
+
+struct Event {
+    point: Point2<f64>,
+    kind: EventKind,
+}
+
+/// An arc of the beach line together with the circle event that would
+/// remove it.
+struct Arc {
+    site: usize,
+    circle: Option<usize>,
+}
+
+/// A Voronoi edge traced by a breakpoint. Edges without an end are still
+/// growing with their breakpoint.
+struct Edge {
+    start: Point2<f64>,
+    end: Option<Point2<f64>>,
+}
+
+pub struct Fortune {
+    // Later passed to app.
+    pub maximum_step_count: usize,
+    pub points: Vec<Point2<f64>>,
+    pub steps: Vec<Vec<Drawable>>,
+    pub descriptions: Vec<String>,
+    // Events ordered from bottom to top, the next event is the last one.
+    queue: Vec<Event>,
+    beach_line: Vec<Arc>,
+    // The edge traced by the breakpoint between arc `i` and `i + 1`.
+    breakpoints: Vec<usize>,
+    edges: Vec<Edge>,
+    circles: Vec<(Point2<f64>, f64)>,
+    vertices: Vec<Point2<f64>>,
+    x_range: [f64; 2],
+}
+
+impl Fortune {
+    pub fn new() -> Self {
+        Fortune {
+            maximum_step_count: 0,
+            points: vec![],
+            steps: vec![],
+            descriptions: vec![],
+            queue: vec![],
+            beach_line: vec![],
+            breakpoints: vec![],
+            edges: vec![],
+            circles: vec![],
+            vertices: vec![],
+            x_range: [0.0, 0.0],
+        }
+    }
+
+    /// Stores the sweep line, the beach line, the traced Voronoi edges and the
+    /// queued circle events together with the given drawables.
+    fn add_step(&mut self, sweep: f64, drawables: Vec<Drawable>, description: String) {
+        let mut step = vec![Drawable::line(
+            &Point2::new(self.x_range[0], sweep),
+            &Point2::new(self.x_range[1], sweep),
+            Color::Yellow,
+        )];
+
+        for (index, edge) in self.edges.iter().enumerate() {
+            let end = match edge.end {
+                Some(end) => end,
+                None => match self.breakpoints.iter().position(|other| *other == index) {
+                    Some(breakpoint) => self.breakpoint(breakpoint, sweep),
+                    None => continue,
+                },
+            };
+            step.push(Drawable::line(&edge.start, &end, Color::Blue));
+        }
+
+        for event in self.queue.iter() {
+            if let EventKind::Circle(circle) = event.kind {
+                let (center, radius) = self.circles[circle];
+                step.push(Drawable::circle(&center, radius, Color::DarkGray));
+            }
+        }
+        step.extend(self.beach_line_drawables(sweep));
+        step.push(Drawable::points(&self.vertices, Color::Green));
+        step.extend(drawables);
+
+        let beach_line = self
+            .beach_line
+            .iter()
+            .map(|arc| format!("v{}", arc.site))
+            .join(", ");
+        self.steps.push(step);
+        self.descriptions.push(format!(
+            "{description} Beach line (left to right): [{beach_line}]."
+        ));
+        self.maximum_step_count += 1;
+    }
+
+    /// Samples every arc of the beach line between its breakpoints.
+    fn beach_line_drawables(&self, sweep: f64) -> Vec<Drawable> {
+        let width = (self.x_range[1] - self.x_range[0]) / SAMPLES as f64;
+        let mut drawables = vec![];
+        for (index, arc) in self.beach_line.iter().enumerate() {
+            let site = self.points[arc.site];
+            if site.y <= sweep {
+                continue;
+            }
+            let left = if index == 0 {
+                self.x_range[0]
+            } else {
+                self.breakpoint(index - 1, sweep).x.max(self.x_range[0])
+            };
+            let right = if index == self.beach_line.len() - 1 {
+                self.x_range[1]
+            } else {
+                self.breakpoint(index, sweep).x.min(self.x_range[1])
+            };
+            if left >= right {
+                continue;
+            }
+
+            let samples = ((right - left) / width).ceil() as usize;
+            let points = (0..=samples)
+                .map(|sample| {
+                    let x = left + (right - left) * sample as f64 / samples as f64;
+                    Point2::new(x, parabola(&site, sweep, x))
+                })
+                .collect::<Vec<_>>();
+            drawables.extend(
+                points
+                    .iter()
+                    .tuple_windows()
+                    .map(|(from, to)| Drawable::line(from, to, Color::Cyan)),
+            );
+        }
+        drawables
+    }
+
+    /// Calculates the position of the breakpoint right of the given arc.
+    fn breakpoint(&self, index: usize, sweep: f64) -> Point2<f64> {
+        let left = self.points[self.beach_line[index].site];
+        let right = self.points[self.beach_line[index + 1].site];
+        let x = breakpoint_x(&left, &right, sweep);
+
+        // Take the height of a parabola that is not degenerated to a vertical line.
+        let site = if left.y > sweep { left } else { right };
+        Point2::new(x, parabola(&site, sweep, x))
+    }
+
+    /// Inserts the event while keeping the queue ordered from bottom to top.
+    fn push_event(&mut self, event: Event) {
+        let index = self.queue.partition_point(|queued| {
+            (queued.point.y, -queued.point.x) < (event.point.y, -event.point.x)
+        });
+        self.queue.insert(index, event);
+    }
+
+    /// Removes the circle event of the arc from the queue.
+    fn remove_circle_event(&mut self, arc: usize) -> bool {
+        match self.beach_line[arc].circle.take() {
+            Some(circle) => {
+                self.queue.retain(
+                    |event| !matches!(event.kind, EventKind::Circle(other) if other == circle),
+                );
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Schedules a circle event for the arc, if the breakpoints around it converge.
+    fn check_circle_event(&mut self, arc: usize, sweep: f64) -> bool {
+        if arc == 0 || arc + 1 >= self.beach_line.len() {
+            return false;
+        }
+        let [left, middle, right] =
+            [arc - 1, arc, arc + 1].map(|index| self.points[self.beach_line[index].site]);
+        if self.beach_line[arc - 1].site == self.beach_line[arc + 1].site
+            || cross_product(&left, &middle, &right) >= 0.0
+        {
+            return false;
+        }
+        let (center, radius) = match circumcircle(&left, &middle, &right) {
+            Some(circle) => circle,
+            None => return false,
+        };
+        let bottom = Point2::new(center.x, center.y - radius);
+        if bottom.y > sweep {
+            return false;
+        }
+
+        let circle = self.circles.len();
+        self.circles.push((center, radius));
+        self.beach_line[arc].circle = Some(circle);
+        self.push_event(Event {
+            point: bottom,
+            kind: EventKind::Circle(circle),
+        });
+        true
+    }
+
+    fn handle_site_event(&mut self, site: usize) -> String {
+        let point = self.points[site];
+        if self.beach_line.is_empty() {
+            self.beach_line.push(Arc { site, circle: None });
+            return format!("Site event of v{site}: it forms the first arc.");
+        }
+
+        let arc = (0..self.beach_line.len() - 1)
+            .find(|index| point.x < self.breakpoint(*index, point.y).x)
+            .unwrap_or(self.beach_line.len() - 1);
+        let split = self.beach_line[arc].site;
+        let false_alarm = self.remove_circle_event(arc);
+
+        // The new arc splits the arc above it into two parts.
+        let start = Point2::new(point.x, parabola(&self.points[split], point.y, point.x));
+        self.beach_line.insert(arc + 1, Arc { site, circle: None });
+        self.beach_line.insert(
+            arc + 2,
+            Arc {
+                site: split,
+                circle: None,
+            },
+        );
+        let edge = self.edges.len();
+        self.edges.push(Edge { start, end: None });
+        self.edges.push(Edge { start, end: None });
+        self.breakpoints.insert(arc, edge);
+        self.breakpoints.insert(arc + 1, edge + 1);
+
+        let created = [arc, arc + 2]
+            .into_iter()
+            .filter(|arc| self.check_circle_event(*arc, point.y))
+            .count();
+
+        let mut description = format!("Site event of v{site}: split the arc of v{split}.");
+        if false_alarm {
+            description += " Its circle event was a false alarm and is removed.";
+        }
+        description + &format!(" {created} new circle events.")
+    }
+
+    fn handle_circle_event(&mut self, circle: usize, sweep: f64) -> String {
+        let (center, _) = self.circles[circle];
+        let arc = self
+            .beach_line
+            .iter()
+            .position(|arc| arc.circle == Some(circle))
+            .unwrap();
+        let site = self.beach_line[arc].site;
+
+        // Both breakpoints of the vanishing arc meet at the new Voronoi vertex.
+        self.vertices.push(center);
+        self.edges[self.breakpoints[arc - 1]].end = Some(center);
+        self.edges[self.breakpoints[arc]].end = Some(center);
+        self.beach_line.remove(arc);
+        self.breakpoints.remove(arc);
+        self.breakpoints[arc - 1] = self.edges.len();
+        self.edges.push(Edge {
+            start: center,
+            end: None,
+        });
+
+        let removed = [arc - 1, arc]
+            .into_iter()
+            .filter(|arc| self.remove_circle_event(*arc))
+            .count();
+        let created = [arc - 1, arc]
+            .into_iter()
+            .filter(|arc| self.check_circle_event(*arc, sweep))
+            .count();
+        format!(
+            "Circle event: the arc of v{site} vanishes at a new Voronoi vertex. {removed} false \
+             alarms are removed, {created} new circle events."
+        )
+    }
+
+    pub fn calculate(&mut self) {
+        self.steps = vec![];
+        self.descriptions = vec![];
+        self.maximum_step_count = 0;
+        self.queue = vec![];
+        self.beach_line = vec![];
+        self.breakpoints = vec![];
+        self.edges = vec![];
+        self.circles = vec![];
+        self.vertices = vec![];
+
+        if self.points.is_empty() {
+            self.add_step(0.0, vec![], String::from("There are no sites."));
+            return;
+        }
+
+        let (min, max) = self
+            .points
+            .iter()
+            .fold((f64::MAX, f64::MIN), |(min, max), point| {
+                (min.min(point.x), max.max(point.x))
+            });
+        let margin = (max - min).max(1.0);
+        self.x_range = [min - margin, max + margin];
+
+        for site in 0..self.points.len() {
+            self.push_event(Event {
+                point: self.points[site],
+                kind: EventKind::Site(site),
+            });
+        }
+        let top = self.queue.last().unwrap().point.y;
+        self.add_step(
+            top,
+            vec![],
+            format!(
+                "The event queue holds the {} sites. The sweep line moves downwards.",
+                self.points.len()
+            ),
+        );
+
+        while let Some(event) = self.queue.pop() {
+            let sweep = event.point.y;
+            let (description, drawables) = match event.kind {
+                EventKind::Site(site) => (
+                    self.handle_site_event(site),
+                    vec![Drawable::points(&[self.points[site]], Color::Yellow)],
+                ),
+                EventKind::Circle(circle) => {
+                    let (center, radius) = self.circles[circle];
+                    (
+                        self.handle_circle_event(circle, sweep),
+                        vec![
+                            Drawable::circle(&center, radius, Color::Magenta),
+                            Drawable::points(&[event.point], Color::Magenta),
+                        ],
+                    )
+                }
+            };
+            self.add_step(sweep, drawables, description);
+        }
+
+        // The remaining breakpoints move along the bisector of their sites forever,
+        // with the left site on their right. End their unbounded edges past every
+        // site and vertex.
+        for breakpoint in 0..self.breakpoints.len() {
+            let [left, right] =
+                [breakpoint, breakpoint + 1].map(|arc| self.points[self.beach_line[arc].site]);
+            let direction = Vector2::new(right.y - left.y, left.x - right.x).normalize();
+            let start = self.edges[self.breakpoints[breakpoint]].start;
+            let length = self
+                .points
+                .iter()
+                .chain(self.vertices.iter())
+                .map(|point| (point - start).norm())
+                .fold(0.0, f64::max)
+                + margin;
+            self.edges[self.breakpoints[breakpoint]].end = Some(start + direction * length);
+        }
+        let far = self
+            .edges
+            .iter()
+            .filter_map(|edge| edge.end)
+            .fold(top, |lowest, point| lowest.min(point.y));
+        self.add_step(
+            far,
+            vec![],
+            format!(
+                "The queue is empty. The Voronoi diagram has {} vertices and {} unbounded edges.",
+                self.vertices.len(),
+                self.breakpoints.len()
+            ),
+        );
+    }
+}
+
+/// Calculates the height of the parabola of all points that are as far away
+/// from the site as from the sweep line.
+fn parabola(site: &Point2<f64>, sweep: f64, x: f64) -> f64 {
+    ((x - site.x).powi(2) + site.y * site.y - sweep * sweep) / (2.0 * (site.y - sweep))
+}
+
+/// Calculates the x coordinate where the parabola of the left site meets the
+/// parabola of the right site from above.
+fn breakpoint_x(left: &Point2<f64>, right: &Point2<f64>, sweep: f64) -> f64 {
+    let left_distance = 2.0 * (left.y - sweep);
+    let right_distance = 2.0 * (right.y - sweep);
+    if left_distance == 0.0 {
+        return left.x;
+    }
+    if right_distance == 0.0 {
+        return right.x;
+    }
+
+    // The difference of both parabolas is a quadratic function.
+    let a = 1.0 / left_distance - 1.0 / right_distance;
+    let b = -2.0 * (left.x / left_distance - right.x / right_distance);
+    let c = (left.x * left.x + left.y * left.y - sweep * sweep) / left_distance
+        - (right.x * right.x + right.y * right.y - sweep * sweep) / right_distance;
+    if a == 0.0 {
+        return -c / b;
+    }
+
+    // Left of the breakpoint the left parabola is lower, so the difference increases.
+    (-b + (b * b - 4.0 * a * c).max(0.0).sqrt()) / (2.0 * a)
+}
+
+impl Algorithm for Fortune {
+    fn get_title(&self) -> &str {
+        "Fortune"
+    }
+
+    fn get_points(&self) -> &Vec<Point2<f64>> {
+        &self.points
+    }
+
+    fn set_points(&mut self, points: Vec<Point2<f64>>) {
+        self.points = points;
+    }
+
+    fn get_steps(&self) -> Vec<Vec<Drawable>> {
+        self.steps.clone()
+    }
+
+    fn get_draw_method(&self) -> DrawMethod {
+        DrawMethod::Edge
+    }
+
+    fn get_step_description(&self, step: usize) -> Option<String> {
+        self.descriptions.get(step).cloned()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::algorithms::{
+        bowyer_watson::BowyerWatson,
+        testing::{assert_same_points, random_points},
+    };
+
+    fn fortune(points: Vec<Point2<f64>>) -> Fortune {
+        let mut fortune = Fortune::new();
+        fortune.set_points(points);
+        fortune.calculate();
+        fortune
+    }
+
+    /// Returns the distances to the two nearest sites.
+    fn nearest_two(points: &[Point2<f64>], point: &Point2<f64>) -> (f64, f64) {
+        let mut distances = points
+            .iter()
+            .map(|site| (site - point).norm())
+            .collect::<Vec<_>>();
+        distances.sort_by(|a, b| a.partial_cmp(b).unwrap());
+        (distances[0], distances[1])
+    }
+
+    #[test]
+    fn small_inputs_have_no_vertices() {
+        for amount in 0..3 {
+            let fortune = fortune(random_points(amount));
+            assert!(fortune.vertices.is_empty());
+            assert_eq!(fortune.edges.len(), amount.saturating_sub(1) * 2);
+        }
+    }
+
+    #[test]
+    fn vertices_are_delaunay_circumcenters() {
+        for amount in 3..40 {
+            let points = random_points(amount);
+            let mut bowyer_watson = BowyerWatson::new();
+            bowyer_watson.set_points(points.clone());
+            bowyer_watson.calculate();
+            let centers = bowyer_watson
+                .triangles
+                .iter()
+                .map(|[a, b, c]| {
+                    circumcircle(&points[*a], &points[*b], &points[*c])
+                        .unwrap()
+                        .0
+                })
+                .collect::<Vec<_>>();
+            assert_same_points(&fortune(points).vertices, &centers);
+        }
+    }
+
+    #[test]
+    fn edges_are_equidistant_to_their_nearest_sites() {
+        for amount in 2..40 {
+            let points = random_points(amount);
+            for edge in fortune(points.clone()).edges {
+                let middle = nalgebra::center(&edge.start, &edge.end.unwrap());
+                let (first, second) = nearest_two(&points, &middle);
+                assert!((first - second).abs() < 1e-6 * (1.0 + first));
+            }
+        }
+    }
+}
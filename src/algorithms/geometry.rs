@@ -0,0 +1,170 @@
+use nalgebra::Point2;
+
+/// Calculates the cross product of the vectors `origin -> a` and `origin -> b`.
+/// A positive value means that `b` lies left of the directed line
+/// `origin -> a`, a negative value means it lies right of it.
+pub fn cross_product(origin: &Point2<f64>, a: &Point2<f64>, b: &Point2<f64>) -> f64 {
+    (a.x - origin.x) * (b.y - origin.y) - (a.y - origin.y) * (b.x - origin.x)
+}
+
+/// A line segment between two points.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Segment {
+    pub from: Point2<f64>,
+    pub to: Point2<f64>,
+}
+
+impl Segment {
+    pub fn new(from: Point2<f64>, to: Point2<f64>) -> Self {
+        Segment { from, to }
+    }
+
+    /// Calculates the proper intersection point with another segment. Touching
+    /// endpoints and overlapping collinear segments are not reported.
+    pub fn intersection(&self, other: &Segment) -> Option<Point2<f64>> {
+        let direction = self.to - self.from;
+        let other_direction = other.to - other.from;
+        let denominator = direction.x * other_direction.y - direction.y * other_direction.x;
+        if denominator == 0.0 {
+            return None;
+        }
+
+        let offset = other.from - self.from;
+        let t = (offset.x * other_direction.y - offset.y * other_direction.x) / denominator;
+        let u = (offset.x * direction.y - offset.y * direction.x) / denominator;
+        if t <= 0.0 || t >= 1.0 || u <= 0.0 || u >= 1.0 {
+            return None;
+        }
+        Some(self.from + direction * t)
+    }
+}
+
+/// An axis-parallel rectangle, including its boundary.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Rectangle {
+    pub min: Point2<f64>,
+    pub max: Point2<f64>,
+}
+
+impl Rectangle {
+    /// Creates the rectangle spanned by two opposite corners.
+    pub fn new(a: Point2<f64>, b: Point2<f64>) -> Self {
+        Rectangle {
+            min: Point2::new(a.x.min(b.x), a.y.min(b.y)),
+            max: Point2::new(a.x.max(b.x), a.y.max(b.y)),
+        }
+    }
+
+    pub fn contains_point(&self, point: &Point2<f64>) -> bool {
+        (self.min.x..=self.max.x).contains(&point.x) && (self.min.y..=self.max.y).contains(&point.y)
+    }
+
+    pub fn contains(&self, other: &Rectangle) -> bool {
+        self.contains_point(&other.min) && self.contains_point(&other.max)
+    }
+
+    pub fn intersects(&self, other: &Rectangle) -> bool {
+        self.min.x <= other.max.x
+            && other.min.x <= self.max.x
+            && self.min.y <= other.max.y
+            && other.min.y <= self.max.y
+    }
+
+    /// Returns the corners in counterclockwise order, starting at the lower left.
+    pub fn corners(&self) -> [Point2<f64>; 4] {
+        [
+            self.min,
+            Point2::new(self.max.x, self.min.y),
+            self.max,
+            Point2::new(self.min.x, self.max.y),
+        ]
+    }
+}
+
+/// Checks whether the point lies inside or on the boundary of the
+/// counterclockwise triangle `a, b, c`.
+pub fn in_triangle(point: &Point2<f64>, a: &Point2<f64>, b: &Point2<f64>, c: &Point2<f64>) -> bool {
+    cross_product(a, b, point) >= 0.0
+        && cross_product(b, c, point) >= 0.0
+        && cross_product(c, a, point) >= 0.0
+}
+
+/// Checks whether the point lies inside the polygon by casting a ray to the
+/// right and counting the crossed edges. Points on the boundary may go either way.
+pub fn in_polygon(point: &Point2<f64>, polygon: &[Point2<f64>]) -> bool {
+    let mut inside = false;
+    for (index, from) in polygon.iter().enumerate() {
+        let to = &polygon[(index + 1) % polygon.len()];
+        if (from.y > point.y) != (to.y > point.y)
+            && point.x < from.x + (point.y - from.y) / (to.y - from.y) * (to.x - from.x)
+        {
+            inside = !inside;
+        }
+    }
+    inside
+}
+
+/// Checks whether `d` lies strictly inside the circumcircle of the
+/// counterclockwise triangle `a, b, c`.
+pub fn in_circle(a: &Point2<f64>, b: &Point2<f64>, c: &Point2<f64>, d: &Point2<f64>) -> bool {
+    let (a, b, c) = (a - d, b - d, c - d);
+    let determinant = (a.x * a.x + a.y * a.y) * (b.x * c.y - c.x * b.y)
+        - (b.x * b.x + b.y * b.y) * (a.x * c.y - c.x * a.y)
+        + (c.x * c.x + c.y * c.y) * (a.x * b.y - b.x * a.y);
+    determinant > 0.0
+}
+
+/// Calculates the center and radius of the circle through three points.
+/// Collinear points have no such circle.
+pub fn circumcircle(
+    a: &Point2<f64>,
+    b: &Point2<f64>,
+    c: &Point2<f64>,
+) -> Option<(Point2<f64>, f64)> {
+    let (b, c) = (b - a, c - a);
+    let denominator = 2.0 * (b.x * c.y - b.y * c.x);
+    if denominator == 0.0 {
+        return None;
+    }
+    let b_squared = b.x * b.x + b.y * b.y;
+    let c_squared = c.x * c.x + c.y * c.y;
+    let offset = nalgebra::Vector2::new(
+        (c.y * b_squared - b.y * c_squared) / denominator,
+        (b.x * c_squared - c.x * b_squared) / denominator,
+    );
+    Some((a + offset, offset.norm()))
+}
+
+/// Clips the segment to the given bounds with the Liang-Barsky algorithm.
+pub fn clip_segment(
+    from: &Point2<f64>,
+    to: &Point2<f64>,
+    x_bounds: [f64; 2],
+    y_bounds: [f64; 2],
+) -> Option<(Point2<f64>, Point2<f64>)> {
+    let direction = to - from;
+    let (mut start, mut end) = (0.0, 1.0);
+    for (delta, distance) in [
+        (-direction.x, from.x - x_bounds[0]),
+        (direction.x, x_bounds[1] - from.x),
+        (-direction.y, from.y - y_bounds[0]),
+        (direction.y, y_bounds[1] - from.y),
+    ] {
+        if delta == 0.0 {
+            if distance < 0.0 {
+                return None;
+            }
+            continue;
+        }
+        let t = distance / delta;
+        if delta < 0.0 {
+            start = f64::max(start, t);
+        } else {
+            end = f64::min(end, t);
+        }
+    }
+    if start > end {
+        return None;
+    }
+    Some((from + direction * start, from + direction * end))
+}
@@ -1,8 +1,8 @@
 use itertools::Itertools;
 use nalgebra::{Point2, Vector2};
-use tui::{style::Color, widgets::canvas::Line};
+use tui::style::Color;
 
-use super::algorithm::{Algorithm, DrawMethod};
+use super::algorithm::{Algorithm, DrawMethod, Drawable};
 
 pub struct GrahamScan {
     pub step_count: usize,
@@ -115,20 +115,12 @@ impl GrahamScan {
         // Sort points lexicographically.
         points.sort_by(|a, b| (a.x, a.y).partial_cmp(&(b.x, b.y)).unwrap());
 
-        // Handle base cases.
+        // Handle base cases. Fewer than three points are their own hull.
         if points.len() < 3 {
-            match points.len() {
-                1 => {
-                    self.add_step(Step::Addition, Some(points.remove(0)), Orientation::Upper);
-                    return;
-                }
-                2 => {
-                    self.add_step(Step::Addition, Some(points.remove(0)), Orientation::Upper);
-                    self.add_step(Step::Addition, Some(points.remove(0)), Orientation::Upper);
-                    return;
-                }
-                _ => {}
+            for point in points {
+                self.add_step(Step::Addition, Some(point), Orientation::Upper);
             }
+            return;
         }
 
         // Insert the first two points.
@@ -164,6 +156,27 @@ impl GrahamScan {
             }
         }
     }
+
+    /// Assembles the convex hull in counterclockwise order out of the
+    /// last upper and lower steps. Expects `calculate` to be called before.
+    pub fn get_hull(&self) -> Vec<Point2<f64>> {
+        let upper = self.upper_steps.last().cloned().unwrap_or_default();
+        let mut hull = match self.lower_steps.last() {
+            Some(lower) => lower.clone(),
+            // Base cases only produce upper steps.
+            None => return upper,
+        };
+
+        // The leftmost and rightmost point are part of both chains.
+        hull.extend(
+            upper
+                .iter()
+                .rev()
+                .skip(1)
+                .take(upper.len().saturating_sub(2)),
+        );
+        hull
+    }
 }
 
 impl Algorithm for GrahamScan {
@@ -179,7 +192,7 @@ impl Algorithm for GrahamScan {
         self.points = points;
     }
 
-    fn get_steps(&self) -> Vec<Vec<Line>> {
+    fn get_steps(&self) -> Vec<Vec<Drawable>> {
         let upper_lines = self
             .upper_steps
             .iter()
@@ -187,13 +200,7 @@ impl Algorithm for GrahamScan {
                 upper_step
                     .iter()
                     .tuple_windows()
-                    .map(|(from, to)| Line {
-                        x1: from.x,
-                        x2: to.x,
-                        y1: from.y,
-                        y2: to.y,
-                        color: Color::Blue,
-                    })
+                    .map(|(from, to)| Drawable::line(from, to, Color::Blue))
                     .collect::<Vec<_>>()
             })
             .collect::<Vec<_>>();
@@ -202,13 +209,7 @@ impl Algorithm for GrahamScan {
             lower_step
                 .iter()
                 .tuple_windows()
-                .map(|(from, to)| Line {
-                    x1: from.x,
-                    x2: to.x,
-                    y1: from.y,
-                    y2: to.y,
-                    color: Color::Green,
-                })
+                .map(|(from, to)| Drawable::line(from, to, Color::Green))
                 .collect::<Vec<_>>()
         });
 
@@ -226,3 +227,31 @@ impl Algorithm for GrahamScan {
         DrawMethod::Edge
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::algorithms::testing::{assert_same_points, brute_force_hull, random_points};
+
+    #[test]
+    fn fewer_than_three_points_are_their_own_hull() {
+        for amount in 0..3 {
+            let points = random_points(amount);
+            let mut graham_scan = GrahamScan::new();
+            graham_scan.set_points(points.clone());
+            graham_scan.calculate();
+            assert_same_points(&graham_scan.get_hull(), &points);
+        }
+    }
+
+    #[test]
+    fn matches_brute_force_hull() {
+        for amount in 3..40 {
+            let points = random_points(amount);
+            let mut graham_scan = GrahamScan::new();
+            graham_scan.set_points(points.clone());
+            graham_scan.calculate();
+            assert_same_points(&graham_scan.get_hull(), &brute_force_hull(&points));
+        }
+    }
+}
@@ -0,0 +1,491 @@
+use itertools::Itertools;
+use nalgebra::Point2;
+use tui::style::Color;
+
+use super::{
+    algorithm::{Algorithm, DrawMethod, Drawable, InputKind},
+    geometry::{in_polygon, Segment},
+};
+
+const NAMES: [&str; 2] = ["P", "Q"];
+const COLORS: [Color; 2] = [Color::Blue, Color::Cyan];
+
+#[derive(Clone, Copy, PartialEq)]
+pub enum Operation {
+    Intersection,
+    Union,
+    Difference,
+}
+
+/// A vertex of one of both polygons after inserting the crossings.
+struct Node {
+    point: Point2<f64>,
+    crossing: Option<usize>,
+}
+
+/// An intersection of both boundaries with its position in both vertex lists.
+struct Crossing {
+    point: Point2<f64>,
+    positions: [usize; 2],
+    // Whether walking forward along each polygon enters the other one here,
+    // flipped depending on the operation.
+    entry: [bool; 2],
+    visited: bool,
+}
+
+pub struct GreinerHormann {
+    // Later passed to app.
+    pub maximum_step_count: usize,
+    pub points: Vec<Point2<f64>>,
+    pub steps: Vec<Vec<Drawable>>,
+    pub descriptions: Vec<String>,
+    // The contours of the result.
+    pub contours: Vec<Vec<Point2<f64>>>,
+    pub operation: Operation,
+    polygons: Vec<Vec<Point2<f64>>>,
+    lists: [Vec<Node>; 2],
+    crossings: Vec<Crossing>,
+    marked: bool,
+}
+
+impl GreinerHormann {
+    pub fn new() -> Self {
+        GreinerHormann {
+            maximum_step_count: 0,
+            points: vec![],
+            steps: vec![],
+            descriptions: vec![],
+            contours: vec![],
+            operation: Operation::Intersection,
+            polygons: vec![],
+            lists: [vec![], vec![]],
+            crossings: vec![],
+            marked: false,
+        }
+    }
+
+    /// Stores both polygons, the crossings and the finished contours together
+    /// with the given drawables.
+    fn add_step(&mut self, drawables: Vec<Drawable>, description: String) {
+        let mut step = self
+            .polygons
+            .iter()
+            .zip(COLORS)
+            .flat_map(|(polygon, color)| polygon_lines(polygon, color))
+            .collect::<Vec<_>>();
+        step.extend(
+            self.contours
+                .iter()
+                .flat_map(|contour| polygon_lines(contour, Color::Green)),
+        );
+        step.extend(drawables);
+        if self.marked {
+            // Entries and exits refer to walking forward along P.
+            for (entry, color) in [(true, Color::Green), (false, Color::LightRed)] {
+                step.push(Drawable::points(
+                    &self
+                        .crossings
+                        .iter()
+                        .filter(|crossing| crossing.entry[0] == entry)
+                        .map(|crossing| crossing.point)
+                        .collect::<Vec<_>>(),
+                    color,
+                ));
+            }
+        } else {
+            step.push(Drawable::points(
+                &self
+                    .crossings
+                    .iter()
+                    .map(|crossing| crossing.point)
+                    .collect::<Vec<_>>(),
+                Color::Magenta,
+            ));
+        }
+
+        self.steps.push(step);
+        self.descriptions.push(description);
+        self.maximum_step_count += 1;
+    }
+
+    fn name(&self) -> &str {
+        match self.operation {
+            Operation::Intersection => "intersection P ∩ Q",
+            Operation::Union => "union P ∪ Q",
+            Operation::Difference => "difference P − Q",
+        }
+    }
+
+    /// Finds all crossings of both boundaries and inserts them into the vertex
+    /// lists, sorted along every edge.
+    fn find_crossings(&mut self) {
+        // The crossings on every edge with their position along it.
+        let mut on_edges = [
+            vec![vec![]; self.polygons[0].len()],
+            vec![vec![]; self.polygons[1].len()],
+        ];
+        let edges = self.polygons[0]
+            .iter()
+            .circular_tuple_windows()
+            .map(|(from, to)| Segment::new(*from, *to))
+            .collect::<Vec<_>>();
+        for (i, segment) in edges.into_iter().enumerate() {
+            let mut found = vec![];
+            for (j, (other_from, other_to)) in
+                self.polygons[1].iter().circular_tuple_windows().enumerate()
+            {
+                let other = Segment::new(*other_from, *other_to);
+                if let Some(point) = segment.intersection(&other) {
+                    let crossing = self.crossings.len() + found.len();
+                    on_edges[0][i].push((alpha(&segment, &point), crossing));
+                    on_edges[1][j].push((alpha(&other, &point), crossing));
+                    found.push(point);
+                }
+            }
+            if found.is_empty() {
+                continue;
+            }
+
+            self.crossings.extend(found.iter().map(|point| Crossing {
+                point: *point,
+                positions: [0, 0],
+                entry: [false, false],
+                visited: false,
+            }));
+            self.add_step(
+                vec![Drawable::line(&segment.from, &segment.to, Color::Yellow)],
+                format!(
+                    "Edge {i} of P crosses the boundary of Q {} times.",
+                    found.len()
+                ),
+            );
+        }
+
+        for (polygon, edges) in on_edges.iter_mut().enumerate() {
+            for (vertex, crossings) in edges.iter_mut().enumerate() {
+                self.lists[polygon].push(Node {
+                    point: self.polygons[polygon][vertex],
+                    crossing: None,
+                });
+                crossings.sort_by(|a, b| a.0.partial_cmp(&b.0).unwrap());
+                for (_, crossing) in crossings.iter() {
+                    self.crossings[*crossing].positions[polygon] = self.lists[polygon].len();
+                    self.lists[polygon].push(Node {
+                        point: self.crossings[*crossing].point,
+                        crossing: Some(*crossing),
+                    });
+                }
+            }
+        }
+    }
+
+    /// Walks along both polygons and marks every crossing as an entry into or an
+    /// exit out of the other polygon.
+    fn mark_crossings(&mut self) {
+        for polygon in 0..2 {
+            let other = &self.polygons[1 - polygon];
+            let mut inside = in_polygon(&self.polygons[polygon][0], other);
+            let starts_inside = inside;
+            // Union keeps the parts outside of the other polygon, difference the
+            // parts of P outside of Q and the parts of Q inside of P.
+            let flip = match self.operation {
+                Operation::Intersection => false,
+                Operation::Union => true,
+                Operation::Difference => polygon == 0,
+            };
+            for node in self.lists[polygon].iter() {
+                if let Some(crossing) = node.crossing {
+                    // Coming from outside makes an entry, unless the marks are flipped.
+                    self.crossings[crossing].entry[polygon] = inside == flip;
+                    inside = !inside;
+                }
+            }
+            self.marked = true;
+            self.add_step(
+                polygon_lines(&self.polygons[polygon], Color::Yellow),
+                format!(
+                    "The first vertex of {} lies {} {}. Walking along {}, the crossings \
+                     alternate between entries and exits.{}",
+                    NAMES[polygon],
+                    if starts_inside { "inside" } else { "outside" },
+                    NAMES[1 - polygon],
+                    NAMES[polygon],
+                    if flip {
+                        " For the operation, flip the marks to keep the parts outside."
+                    } else {
+                        ""
+                    }
+                ),
+            );
+        }
+    }
+
+    /// Traces the contour starting at the crossing. Entries continue forward
+    /// along the current polygon, exits backward, until the next crossing
+    /// switches to the other polygon.
+    fn trace(&mut self, start: usize) {
+        let mut contour = vec![self.crossings[start].point];
+        let (mut crossing, mut polygon) = (start, 0);
+        loop {
+            self.crossings[crossing].visited = true;
+            let forward = self.crossings[crossing].entry[polygon];
+            let list = &self.lists[polygon];
+            let mut position = self.crossings[crossing].positions[polygon];
+            let mut run = vec![list[position].point];
+            loop {
+                position = if forward {
+                    (position + 1) % list.len()
+                } else {
+                    (position + list.len() - 1) % list.len()
+                };
+                run.push(list[position].point);
+                if let Some(next) = list[position].crossing {
+                    crossing = next;
+                    break;
+                }
+            }
+            contour.extend(run[1..].iter().copied());
+
+            let mut drawables = contour
+                .iter()
+                .tuple_windows()
+                .map(|(from, to)| Drawable::line(from, to, Color::Green))
+                .collect::<Vec<_>>();
+            drawables.extend(
+                run.iter()
+                    .tuple_windows()
+                    .map(|(from, to)| Drawable::line(from, to, Color::Yellow)),
+            );
+            self.add_step(
+                drawables,
+                format!(
+                    "The crossing is {} for {}, so walk {} along it to the next crossing and \
+                     switch to {}.",
+                    if forward { "an entry" } else { "an exit" },
+                    NAMES[polygon],
+                    if forward { "forward" } else { "backward" },
+                    NAMES[1 - polygon]
+                ),
+            );
+
+            polygon = 1 - polygon;
+            if crossing == start {
+                break;
+            }
+        }
+        // The contour returned to its first point.
+        contour.pop();
+        self.contours.push(contour);
+    }
+
+    /// Handles polygons whose boundaries do not cross, so one of them lies
+    /// inside of the other or they are disjoint.
+    fn without_crossings(&mut self) -> String {
+        let [first, second] = [&self.polygons[0], &self.polygons[1]];
+        let first_inside = in_polygon(&first[0], second);
+        let second_inside = in_polygon(&second[0], first);
+        let (contours, case) = match (self.operation, first_inside, second_inside) {
+            (Operation::Intersection, true, _) => (vec![first.clone()], "P lies inside of Q"),
+            (Operation::Intersection, _, true) => (vec![second.clone()], "Q lies inside of P"),
+            (Operation::Intersection, _, _) => (vec![], "both polygons are disjoint"),
+            (Operation::Union, true, _) => (vec![second.clone()], "P lies inside of Q"),
+            (Operation::Union, _, true) => (vec![first.clone()], "Q lies inside of P"),
+            (Operation::Union, _, _) => (
+                vec![first.clone(), second.clone()],
+                "both polygons are disjoint",
+            ),
+            (Operation::Difference, true, _) => (vec![], "P lies inside of Q"),
+            (Operation::Difference, _, true) => (
+                vec![first.clone(), second.iter().rev().copied().collect()],
+                "Q is a hole in P",
+            ),
+            (Operation::Difference, _, _) => (vec![first.clone()], "both polygons are disjoint"),
+        };
+        self.contours = contours;
+        format!("The boundaries do not cross and {case}.")
+    }
+
+    pub fn calculate(&mut self) {
+        self.steps = vec![];
+        self.descriptions = vec![];
+        self.maximum_step_count = 0;
+        self.contours = vec![];
+        self.lists = [vec![], vec![]];
+        self.crossings = vec![];
+        self.marked = false;
+
+        if self.polygons.len() != 2 || self.polygons.iter().any(|polygon| polygon.len() < 3) {
+            self.add_step(
+                vec![],
+                String::from("Boolean operations need two polygons with at least three vertices."),
+            );
+            return;
+        }
+        self.add_step(
+            vec![],
+            format!(
+                "Compute the {} of two simple polygons with {} and {} vertices. Press m to \
+                 switch the operation.",
+                self.name(),
+                self.polygons[0].len(),
+                self.polygons[1].len()
+            ),
+        );
+
+        self.find_crossings();
+        if self.crossings.is_empty() {
+            let description = self.without_crossings();
+            self.add_step(vec![], description);
+            return;
+        }
+        self.add_step(
+            vec![],
+            format!(
+                "Insert the {} crossings into the vertex lists of both polygons and link \
+                 each with its twin in the other list.",
+                self.crossings.len()
+            ),
+        );
+        self.mark_crossings();
+
+        while let Some(start) = self.crossings.iter().position(|crossing| !crossing.visited) {
+            self.trace(start);
+            self.add_step(
+                vec![],
+                format!(
+                    "Back at the start, contour {} has {} vertices. Continue at an unvisited \
+                     crossing.",
+                    self.contours.len(),
+                    self.contours.last().unwrap().len()
+                ),
+            );
+        }
+
+        self.add_step(
+            vec![],
+            format!(
+                "The {} consists of {} contours. Every crossing was visited once, so tracing \
+                 takes linear time in the size of the vertex lists.",
+                self.name(),
+                self.contours.len()
+            ),
+        );
+    }
+}
+
+/// Returns the position of a point on the segment between 0 at its start and 1
+/// at its end.
+fn alpha(segment: &Segment, point: &Point2<f64>) -> f64 {
+    let direction = segment.to - segment.from;
+    (point - segment.from).dot(&direction) / direction.norm_squared()
+}
+
+fn polygon_lines(polygon: &[Point2<f64>], color: Color) -> Vec<Drawable> {
+    polygon
+        .iter()
+        .circular_tuple_windows()
+        .map(|(from, to)| Drawable::line(from, to, color))
+        .collect()
+}
+
+impl Algorithm for GreinerHormann {
+    fn get_title(&self) -> &str {
+        "Greiner-Hormann clipping"
+    }
+
+    fn get_points(&self) -> &Vec<Point2<f64>> {
+        &self.points
+    }
+
+    fn set_points(&mut self, points: Vec<Point2<f64>>) {
+        self.points = points;
+    }
+
+    fn set_polygons(&mut self, polygons: Vec<Vec<Point2<f64>>>) {
+        self.points = polygons.concat();
+        self.polygons = polygons;
+    }
+
+    fn get_input_kind(&self) -> InputKind {
+        InputKind::SimplePolygonPair
+    }
+
+    fn switch_mode(&mut self) -> bool {
+        self.operation = match self.operation {
+            Operation::Intersection => Operation::Union,
+            Operation::Union => Operation::Difference,
+            Operation::Difference => Operation::Intersection,
+        };
+        true
+    }
+
+    fn get_steps(&self) -> Vec<Vec<Drawable>> {
+        self.steps.clone()
+    }
+
+    fn get_draw_method(&self) -> DrawMethod {
+        DrawMethod::Edge
+    }
+
+    fn get_step_description(&self, step: usize) -> Option<String> {
+        self.descriptions.get(step).cloned()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::algorithms::testing::{random_points, random_star_polygon};
+
+    fn clip(polygons: Vec<Vec<Point2<f64>>>, operation: Operation) -> GreinerHormann {
+        let mut greiner_hormann = GreinerHormann::new();
+        greiner_hormann.set_polygons(polygons);
+        greiner_hormann.operation = operation;
+        greiner_hormann.calculate();
+        greiner_hormann
+    }
+
+    #[test]
+    fn small_polygons_have_no_contours() {
+        for amount in 0..3 {
+            let polygons = vec![random_points(amount), random_points(3)];
+            let greiner_hormann = clip(polygons, Operation::Intersection);
+            assert!(greiner_hormann.contours.is_empty());
+        }
+        assert!(clip(vec![], Operation::Union).contours.is_empty());
+    }
+
+    #[test]
+    fn matches_point_membership() {
+        for _ in 0..100 {
+            let polygons = vec![
+                random_star_polygon(8, Point2::new(40.0, 45.0), 40.0),
+                random_star_polygon(8, Point2::new(60.0, 55.0), 40.0),
+            ];
+            for operation in [
+                Operation::Intersection,
+                Operation::Union,
+                Operation::Difference,
+            ] {
+                let greiner_hormann = clip(polygons.clone(), operation);
+                // Random samples avoid the boundaries with probability one.
+                for sample in random_points(200) {
+                    let inside = [0, 1].map(|index| in_polygon(&sample, &polygons[index]));
+                    let expected = match operation {
+                        Operation::Intersection => inside[0] && inside[1],
+                        Operation::Union => inside[0] || inside[1],
+                        Operation::Difference => inside[0] && !inside[1],
+                    };
+                    let actual = greiner_hormann
+                        .contours
+                        .iter()
+                        .filter(|contour| in_polygon(&sample, contour))
+                        .count()
+                        % 2
+                        == 1;
+                    assert_eq!(actual, expected);
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,180 @@
+use itertools::Itertools;
+use nalgebra::{distance, Point2};
+use tui::style::Color;
+
+use super::{
+    algorithm::{Algorithm, DrawMethod, Drawable},
+    geometry::cross_product,
+};
+
+pub struct JarvisMarch {
+    // Later passed to app.
+    pub maximum_step_count: usize,
+    pub points: Vec<Point2<f64>>,
+    pub steps: Vec<Vec<Drawable>>,
+    // The vertices of the hull in counterclockwise order.
+    pub hull: Vec<Point2<f64>>,
+}
+
+impl JarvisMarch {
+    pub fn new() -> Self {
+        JarvisMarch {
+            maximum_step_count: 0,
+            points: vec![],
+            steps: vec![],
+            hull: vec![],
+        }
+    }
+
+    /// Stores the current hull chain together with the candidate edge that
+    /// is tested and the best candidate edge found so far.
+    fn add_step(
+        &mut self,
+        hull: &[Point2<f64>],
+        candidate: Option<&Point2<f64>>,
+        best: Option<&Point2<f64>>,
+    ) {
+        let mut lines = hull
+            .iter()
+            .tuple_windows()
+            .map(|(from, to)| Drawable::line(from, to, Color::Blue))
+            .collect::<Vec<_>>();
+
+        if let Some(current) = hull.last() {
+            if let Some(best) = best {
+                lines.push(Drawable::line(current, best, Color::Green));
+            }
+            if let Some(candidate) = candidate {
+                lines.push(Drawable::line(current, candidate, Color::Yellow));
+            }
+        }
+        self.steps.push(lines);
+        self.maximum_step_count += 1;
+    }
+
+    pub fn calculate(&mut self) {
+        self.steps = vec![];
+        self.maximum_step_count = 0;
+        self.hull = vec![];
+
+        let points = self.points.clone();
+
+        // The leftmost point is guaranteed to be on the hull.
+        let start = match points
+            .iter()
+            .min_by(|a, b| (a.x, a.y).partial_cmp(&(b.x, b.y)).unwrap())
+        {
+            Some(start) => *start,
+            None => {
+                self.add_step(&[], None, None);
+                return;
+            }
+        };
+
+        let mut hull = vec![start];
+        self.add_step(&hull, None, None);
+
+        // Every wrapping step adds one hull point, so we never need more than
+        // n iterations. The bound protects us from looping on duplicate points.
+        for _ in 0..points.len() {
+            let current = hull[hull.len() - 1];
+            let mut best: Option<Point2<f64>> = None;
+
+            for candidate in points.iter().filter(|point| **point != current) {
+                self.add_step(&hull, Some(candidate), best.as_ref());
+
+                best = match best {
+                    None => Some(*candidate),
+                    Some(best) => {
+                        let cross = cross_product(&current, &best, candidate);
+                        // Prefer candidates right of the current best edge. On
+                        // collinear candidates prefer the one further away.
+                        if cross < 0.0
+                            || (cross == 0.0
+                                && distance(&current, candidate) > distance(&current, &best))
+                        {
+                            Some(*candidate)
+                        } else {
+                            Some(best)
+                        }
+                    }
+                };
+            }
+
+            match best {
+                Some(best) if best != start => hull.push(best),
+                _ => break,
+            }
+        }
+
+        self.hull = hull.clone();
+        // Close the hull in the last step.
+        hull.push(start);
+        self.add_step(&hull, None, None);
+    }
+}
+
+impl Algorithm for JarvisMarch {
+    fn get_title(&self) -> &str {
+        "Jarvis march"
+    }
+
+    fn get_points(&self) -> &Vec<Point2<f64>> {
+        &self.points
+    }
+
+    fn set_points(&mut self, points: Vec<Point2<f64>>) {
+        self.points = points;
+    }
+
+    fn get_steps(&self) -> Vec<Vec<Drawable>> {
+        self.steps.clone()
+    }
+
+    fn get_draw_method(&self) -> DrawMethod {
+        DrawMethod::Edge
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::algorithms::testing::{assert_same_points, brute_force_hull, random_points};
+
+    fn jarvis_march(points: Vec<Point2<f64>>) -> JarvisMarch {
+        let mut jarvis_march = JarvisMarch::new();
+        jarvis_march.set_points(points);
+        jarvis_march.calculate();
+        jarvis_march
+    }
+
+    #[test]
+    fn fewer_than_three_points_are_their_own_hull() {
+        for amount in 0..3 {
+            let points = random_points(amount);
+            assert_same_points(&jarvis_march(points.clone()).hull, &points);
+        }
+    }
+
+    #[test]
+    fn collinear_points_keep_their_extremes() {
+        let line = (0..5).map(|i| Point2::new(10.0 * i as f64, 5.0)).collect();
+        assert_same_points(
+            &jarvis_march(line).hull,
+            &[Point2::new(0.0, 5.0), Point2::new(40.0, 5.0)],
+        );
+    }
+
+    #[test]
+    fn matches_brute_force_hull() {
+        for amount in 3..40 {
+            let points = random_points(amount);
+            let hull = jarvis_march(points.clone()).hull;
+            assert!(hull
+                .iter()
+                .circular_tuple_windows()
+                .all(|(a, b, c)| cross_product(a, b, c) > 0.0));
+            assert_same_points(&hull, &brute_force_hull(&points));
+        }
+    }
+}
@@ -0,0 +1,427 @@
+use itertools::Itertools;
+use nalgebra::Point2;
+use tui::style::Color;
+
+use super::{
+    algorithm::{Algorithm, DrawMethod, Drawable, QueryKind},
+    geometry::Rectangle,
+};
+
+enum NodeKind {
+    Leaf(usize),
+    // Vertical lines split by x, horizontal ones by y. The left child holds the
+    // points on or left of, respectively below, the line.
+    Split {
+        vertical: bool,
+        value: f64,
+        children: [usize; 2],
+    },
+}
+
+struct Node {
+    // The region of the plane the subtree is responsible for.
+    cell: Rectangle,
+    kind: NodeKind,
+}
+
+pub struct KdTree {
+    // Later passed to app.
+    pub maximum_step_count: usize,
+    pub points: Vec<Point2<f64>>,
+    pub steps: Vec<Vec<Drawable>>,
+    pub descriptions: Vec<String>,
+    // Indices of the points inside the query rectangle.
+    pub reported: Vec<usize>,
+    // Number of nodes whose cell intersects the query without lying inside it.
+    pub visited: usize,
+    nodes: Vec<Node>,
+    splits: Vec<(Point2<f64>, Point2<f64>)>,
+    pruned: Vec<Rectangle>,
+    reported_cells: Vec<Rectangle>,
+    // The query entered by the user and the one searched for in this run.
+    query: Option<Rectangle>,
+    search: Option<Rectangle>,
+    x_bounds: [f64; 2],
+    y_bounds: [f64; 2],
+}
+
+impl KdTree {
+    pub fn new() -> Self {
+        KdTree {
+            maximum_step_count: 0,
+            points: vec![],
+            steps: vec![],
+            descriptions: vec![],
+            reported: vec![],
+            visited: 0,
+            nodes: vec![],
+            splits: vec![],
+            pruned: vec![],
+            reported_cells: vec![],
+            query: None,
+            search: None,
+            x_bounds: [0.0, 0.0],
+            y_bounds: [0.0, 0.0],
+        }
+    }
+
+    /// Stores the splitting lines and the state of the search together with the
+    /// given drawables.
+    fn add_step(&mut self, drawables: Vec<Drawable>, description: String) {
+        let mut step = self
+            .splits
+            .iter()
+            .map(|(from, to)| Drawable::line(from, to, Color::Blue))
+            .collect::<Vec<_>>();
+        step.extend(
+            self.pruned
+                .iter()
+                .flat_map(|cell| rectangle_lines(cell, Color::DarkGray)),
+        );
+        step.extend(
+            self.reported_cells
+                .iter()
+                .flat_map(|cell| rectangle_lines(cell, Color::Green)),
+        );
+        if let Some(search) = &self.search {
+            step.extend(rectangle_lines(search, Color::Magenta));
+        }
+        step.extend(drawables);
+        step.push(Drawable::points(
+            &self
+                .reported
+                .iter()
+                .map(|point| self.points[*point])
+                .collect::<Vec<_>>(),
+            Color::Green,
+        ));
+
+        self.steps.push(step);
+        self.descriptions.push(description);
+        self.maximum_step_count += 1;
+    }
+
+    /// Builds the subtree of the given points inside the cell and returns its root.
+    fn build(&mut self, mut points: Vec<usize>, depth: usize, cell: Rectangle) -> usize {
+        let index = self.nodes.len();
+        if points.len() == 1 {
+            self.nodes.push(Node {
+                cell,
+                kind: NodeKind::Leaf(points[0]),
+            });
+            return index;
+        }
+
+        let vertical = depth.is_multiple_of(2);
+        let coordinate = |point: &Point2<f64>| if vertical { point.x } else { point.y };
+        points.sort_by(|a, b| {
+            coordinate(&self.points[*a])
+                .partial_cmp(&coordinate(&self.points[*b]))
+                .unwrap()
+        });
+        let median = (points.len() - 1) / 2;
+        let value = coordinate(&self.points[points[median]]);
+        let (mut left_cell, mut right_cell) = (cell, cell);
+        let line = if vertical {
+            left_cell.max.x = value;
+            right_cell.min.x = value;
+            (
+                Point2::new(value, cell.min.y),
+                Point2::new(value, cell.max.y),
+            )
+        } else {
+            left_cell.max.y = value;
+            right_cell.min.y = value;
+            (
+                Point2::new(cell.min.x, value),
+                Point2::new(cell.max.x, value),
+            )
+        };
+
+        self.nodes.push(Node {
+            cell,
+            kind: NodeKind::Leaf(points[median]),
+        });
+        let mut drawables = rectangle_lines(&cell, Color::Yellow);
+        drawables.push(Drawable::line(&line.0, &line.1, Color::Green));
+        self.add_step(
+            drawables,
+            format!(
+                "Depth {depth}: split the {} points of the cell at the median {} = {value:.2}.",
+                points.len(),
+                if vertical { "x" } else { "y" },
+            ),
+        );
+        self.splits.push(line);
+
+        let right = points.split_off(median + 1);
+        let children = [
+            self.build(points, depth + 1, left_cell),
+            self.build(right, depth + 1, right_cell),
+        ];
+        self.nodes[index].kind = NodeKind::Split {
+            vertical,
+            value,
+            children,
+        };
+        index
+    }
+
+    fn leaves(&self, node: usize) -> Vec<usize> {
+        match self.nodes[node].kind {
+            NodeKind::Leaf(point) => vec![point],
+            NodeKind::Split { children, .. } => children
+                .iter()
+                .flat_map(|child| self.leaves(*child))
+                .collect(),
+        }
+    }
+
+    /// Reports the subtree if its cell lies inside the query, searches it if the
+    /// cell intersects the query and prunes it otherwise.
+    fn classify(&mut self, node: usize, query: &Rectangle) {
+        let cell = self.nodes[node].cell;
+        if query.contains(&cell) {
+            let leaves = self.leaves(node);
+            self.reported.extend(leaves.iter().copied());
+            self.reported_cells.push(cell);
+            self.add_step(
+                rectangle_lines(&cell, Color::Yellow),
+                format!(
+                    "The cell lies inside the query. Report all {} points of the subtree \
+                     without visiting it.",
+                    leaves.len()
+                ),
+            );
+        } else if query.intersects(&cell) {
+            self.search(node, query);
+        } else {
+            self.pruned.push(cell);
+            self.add_step(
+                rectangle_lines(&cell, Color::LightRed),
+                String::from("The cell misses the query. Prune the subtree."),
+            );
+        }
+    }
+
+    fn search(&mut self, node: usize, query: &Rectangle) {
+        self.visited += 1;
+        let cell = self.nodes[node].cell;
+        match self.nodes[node].kind {
+            NodeKind::Leaf(point) => {
+                let inside = query.contains_point(&self.points[point]);
+                if inside {
+                    self.reported.push(point);
+                }
+                let drawables = vec![Drawable::points(&[self.points[point]], Color::Yellow)];
+                self.add_step(
+                    drawables,
+                    format!(
+                        "Visit the leaf of v{point}. It lies {} the query.",
+                        if inside { "inside" } else { "outside" }
+                    ),
+                );
+            }
+            NodeKind::Split {
+                vertical,
+                value,
+                children,
+            } => {
+                self.add_step(
+                    rectangle_lines(&cell, Color::Yellow),
+                    format!(
+                        "Visit the node splitting at {} = {value:.2}. Its cell intersects the \
+                         query, so check both children.",
+                        if vertical { "x" } else { "y" },
+                    ),
+                );
+                for child in children {
+                    self.classify(child, query);
+                }
+            }
+        }
+    }
+
+    pub fn calculate(&mut self) {
+        self.steps = vec![];
+        self.descriptions = vec![];
+        self.maximum_step_count = 0;
+        self.reported = vec![];
+        self.visited = 0;
+        self.nodes = vec![];
+        self.splits = vec![];
+        self.pruned = vec![];
+        self.reported_cells = vec![];
+        self.search = None;
+
+        if self.points.is_empty() {
+            self.add_step(vec![], String::from("There are no points to store."));
+            return;
+        }
+
+        // The root cell has to contain all points, even if they leave the bounds.
+        let bounds = Rectangle::new(
+            Point2::new(self.x_bounds[0], self.y_bounds[0]),
+            Point2::new(self.x_bounds[1], self.y_bounds[1]),
+        );
+        let cell = self.points.iter().fold(bounds, |cell, point| {
+            Rectangle::new(
+                Point2::new(cell.min.x.min(point.x), cell.min.y.min(point.y)),
+                Point2::new(cell.max.x.max(point.x), cell.max.y.max(point.y)),
+            )
+        });
+        let root = self.build((0..self.points.len()).collect(), 0, cell);
+        self.add_step(
+            vec![],
+            format!(
+                "The k-d tree stores {} points in {} nodes.",
+                self.points.len(),
+                self.nodes.len()
+            ),
+        );
+
+        // Without a query of the user, search the center quarter of the bounds.
+        let query = self.query.unwrap_or_else(|| {
+            let center = bounds.min + (bounds.max - bounds.min) / 2.0;
+            let offset = (bounds.max - bounds.min) / 4.0;
+            Rectangle::new(center - offset, center + offset)
+        });
+        self.search = Some(query);
+        self.add_step(
+            vec![],
+            format!(
+                "Query the rectangle from ({:.2}, {:.2}) to ({:.2}, {:.2}). Press r to enter \
+                 another one.",
+                query.min.x, query.min.y, query.max.x, query.max.y
+            ),
+        );
+        self.classify(root, &query);
+
+        self.add_step(
+            vec![],
+            format!(
+                "Found {} points. The search visited {} of {} nodes, pruned {} subtrees and \
+                 reported {} subtrees as a whole. Only the O(√n) cells crossing the query \
+                 boundary need a visit, with √n ≈ {:.0}.",
+                self.reported.len(),
+                self.visited,
+                self.nodes.len(),
+                self.pruned.len(),
+                self.reported_cells.len(),
+                (self.points.len() as f64).sqrt()
+            ),
+        );
+    }
+}
+
+fn rectangle_lines(rectangle: &Rectangle, color: Color) -> Vec<Drawable> {
+    rectangle
+        .corners()
+        .iter()
+        .circular_tuple_windows()
+        .map(|(from, to)| Drawable::line(from, to, color))
+        .collect()
+}
+
+impl Algorithm for KdTree {
+    fn get_title(&self) -> &str {
+        "k-d tree"
+    }
+
+    fn get_points(&self) -> &Vec<Point2<f64>> {
+        &self.points
+    }
+
+    fn set_points(&mut self, points: Vec<Point2<f64>>) {
+        self.points = points;
+    }
+
+    fn set_bounds(&mut self, x_bounds: [f64; 2], y_bounds: [f64; 2]) {
+        self.x_bounds = x_bounds;
+        self.y_bounds = y_bounds;
+    }
+
+    fn get_query_kind(&self) -> Option<QueryKind> {
+        Some(QueryKind::Rectangle)
+    }
+
+    fn set_query(&mut self, query: Vec<f64>) -> bool {
+        match query[..] {
+            [x1, y1, x2, y2] => {
+                self.query = Some(Rectangle::new(Point2::new(x1, y1), Point2::new(x2, y2)));
+                true
+            }
+            _ => false,
+        }
+    }
+
+    fn get_steps(&self) -> Vec<Vec<Drawable>> {
+        self.steps.clone()
+    }
+
+    fn get_draw_method(&self) -> DrawMethod {
+        DrawMethod::Edge
+    }
+
+    fn get_step_description(&self, step: usize) -> Option<String> {
+        self.descriptions.get(step).cloned()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::algorithms::testing::{assert_range_queries, random_points};
+
+    fn new() -> KdTree {
+        let mut kd_tree = KdTree::new();
+        kd_tree.set_bounds([0.0, 100.0], [0.0, 100.0]);
+        kd_tree
+    }
+
+    fn query(kd_tree: &mut KdTree, points: &[Point2<f64>], rectangle: [f64; 4]) -> Vec<usize> {
+        kd_tree.set_points(points.to_vec());
+        kd_tree.set_query(rectangle.to_vec());
+        kd_tree.calculate();
+        let mut reported = kd_tree.reported.clone();
+        reported.sort();
+        reported
+    }
+
+    #[test]
+    fn empty_input_reports_nothing() {
+        let mut kd_tree = new();
+        kd_tree.calculate();
+        assert!(kd_tree.reported.is_empty());
+    }
+
+    #[test]
+    fn matches_brute_force() {
+        let mut kd_tree = new();
+        assert_range_queries(|points, rectangle| query(&mut kd_tree, points, rectangle));
+    }
+
+    #[test]
+    fn leaves_lie_in_their_cells() {
+        let mut kd_tree = new();
+        query(&mut kd_tree, &random_points(200), [0.0, 0.0, 1.0, 1.0]);
+        for node in kd_tree.nodes.iter() {
+            if let NodeKind::Leaf(point) = node.kind {
+                assert!(node.cell.contains_point(&kd_tree.points[point]));
+            }
+        }
+    }
+
+    #[test]
+    fn a_vertical_line_visits_few_cells() {
+        // Every cell the line crosses is visited, which are O(√n) of them.
+        let mut kd_tree = new();
+        let amount = 1024;
+        query(
+            &mut kd_tree,
+            &random_points(amount),
+            [50.0, 0.0, 50.0, 100.0],
+        );
+        assert!(kd_tree.visited <= 8 * (amount as f64).sqrt() as usize);
+    }
+}
@@ -0,0 +1,500 @@
+use itertools::Itertools;
+use nalgebra::{Point2, Vector2};
+use rand::{seq::SliceRandom, Rng};
+use tui::style::Color;
+
+use super::{
+    algorithm::{Algorithm, DrawMethod, Drawable, QueryKind},
+    geometry::clip_segment,
+};
+
+const EPSILON: f64 = 1e-9;
+
+/// The half-plane of all points `x` with `(x - point) · normal >= 0`.
+#[derive(Clone, Copy)]
+struct HalfPlane {
+    point: Point2<f64>,
+    normal: Vector2<f64>,
+}
+
+impl HalfPlane {
+    fn contains(&self, point: &Point2<f64>) -> bool {
+        (point - self.point).dot(&self.normal) >= -EPSILON * self.normal.norm()
+    }
+
+    /// The unit direction of the boundary line, with the half-plane on its left.
+    fn direction(&self) -> Vector2<f64> {
+        Vector2::new(-self.normal.y, self.normal.x).normalize()
+    }
+}
+
+pub struct LinearProgramming {
+    // Later passed to app.
+    pub maximum_step_count: usize,
+    pub points: Vec<Point2<f64>>,
+    pub steps: Vec<Vec<Drawable>>,
+    pub descriptions: Vec<String>,
+    // The vertex maximizing the objective, if the program is feasible.
+    pub optimum: Option<Point2<f64>>,
+    // Number of added constraints the optimum violated.
+    pub violations: usize,
+    // The inward normal of the half-plane bounded at every point.
+    normals: Vec<Vector2<f64>>,
+    // The four sides of the bounding box come first.
+    constraints: Vec<HalfPlane>,
+    added: Vec<usize>,
+    // The feasible region of the added constraints.
+    region: Vec<Point2<f64>>,
+    objective: Vector2<f64>,
+    x_bounds: [f64; 2],
+    y_bounds: [f64; 2],
+}
+
+impl LinearProgramming {
+    pub fn new() -> Self {
+        LinearProgramming {
+            maximum_step_count: 0,
+            points: vec![],
+            steps: vec![],
+            descriptions: vec![],
+            optimum: None,
+            violations: 0,
+            normals: vec![],
+            constraints: vec![],
+            added: vec![],
+            region: vec![],
+            objective: Vector2::new(0.0, 1.0),
+            x_bounds: [0.0, 0.0],
+            y_bounds: [0.0, 0.0],
+        }
+    }
+
+    /// Stores the added constraints, the feasible region and the optimum together
+    /// with the given drawables.
+    fn add_step(&mut self, drawables: Vec<Drawable>, description: String) {
+        let mut step = self
+            .added
+            .iter()
+            .flat_map(|constraint| self.boundary(*constraint, Color::DarkGray))
+            .collect::<Vec<_>>();
+        step.extend(
+            self.region
+                .iter()
+                .circular_tuple_windows()
+                .map(|(from, to)| Drawable::line(from, to, Color::Green)),
+        );
+        step.extend(drawables);
+        if let Some(optimum) = self.optimum {
+            let length = (self.x_bounds[1] - self.x_bounds[0]) / 20.0;
+            step.push(Drawable::line(
+                &optimum,
+                &(optimum + self.objective.normalize() * length),
+                Color::Magenta,
+            ));
+            step.push(Drawable::points(&[optimum], Color::Magenta));
+        }
+
+        self.steps.push(step);
+        self.descriptions.push(description);
+        self.maximum_step_count += 1;
+    }
+
+    /// Returns the boundary line of the constraint clipped to the bounds.
+    fn boundary(&self, constraint: usize, color: Color) -> Option<Drawable> {
+        let half_plane = &self.constraints[constraint];
+        let length = (self.x_bounds[1] - self.x_bounds[0]) + (self.y_bounds[1] - self.y_bounds[0]);
+        let direction = half_plane.direction() * 2.0 * length;
+        clip_segment(
+            &(half_plane.point - direction),
+            &(half_plane.point + direction),
+            self.x_bounds,
+            self.y_bounds,
+        )
+        .map(|(from, to)| Drawable::line(&from, &to, color))
+    }
+
+    fn name(&self, constraint: usize) -> String {
+        match constraint {
+            0..=3 => String::from("the bounding box"),
+            _ => format!("h{}", constraint - 4),
+        }
+    }
+
+    /// Cuts the feasible region with the half-plane.
+    fn clip_region(&mut self, half_plane: &HalfPlane) {
+        let mut region = vec![];
+        for (from, to) in self.region.iter().circular_tuple_windows() {
+            let (a, b) = (
+                (from - half_plane.point).dot(&half_plane.normal),
+                (to - half_plane.point).dot(&half_plane.normal),
+            );
+            if a >= 0.0 {
+                region.push(*from);
+            }
+            if (a >= 0.0) != (b >= 0.0) {
+                region.push(from + (to - from) * (a / (a - b)));
+            }
+        }
+        self.region = region;
+    }
+
+    /// Solves the one-dimensional program on the boundary line of the violated
+    /// constraint, restricted by all constraints added before it.
+    fn solve_on_line(&mut self, constraint: usize) -> Option<Point2<f64>> {
+        let half_plane = self.constraints[constraint];
+        let direction = half_plane.direction();
+        let (mut lower, mut upper) = (f64::NEG_INFINITY, f64::INFINITY);
+        let line = self.boundary(constraint, Color::Yellow);
+
+        for previous in self.added.clone() {
+            let other = self.constraints[previous];
+            // The points p + t·d of the line inside the other half-plane satisfy
+            // t·(d · n) >= (q - p) · n.
+            let factor = direction.dot(&other.normal);
+            let offset = (other.point - half_plane.point).dot(&other.normal);
+            let bound = if factor.abs() < EPSILON * direction.norm() * other.normal.norm() {
+                if offset > EPSILON * other.normal.norm() {
+                    lower = f64::INFINITY;
+                    "is parallel to the line and excludes it"
+                } else {
+                    "is parallel to the line"
+                }
+            } else if factor > 0.0 {
+                lower = lower.max(offset / factor);
+                "bounds the interval from below"
+            } else {
+                upper = upper.min(offset / factor);
+                "bounds the interval from above"
+            };
+            // The sides of the bounding box share a single step.
+            if previous < 3 {
+                continue;
+            }
+
+            let mut drawables = line.iter().cloned().collect::<Vec<_>>();
+            if previous > 3 {
+                drawables.extend(self.boundary(previous, Color::Magenta));
+            }
+            if lower <= upper {
+                let [from, to] = [lower, upper].map(|t| half_plane.point + direction * t);
+                drawables.extend(
+                    clip_segment(&from, &to, self.x_bounds, self.y_bounds)
+                        .map(|(from, to)| Drawable::line(&from, &to, Color::Cyan)),
+                );
+            }
+            let interval = if lower <= upper {
+                format!("t ∈ [{lower:.2}, {upper:.2}]")
+            } else {
+                String::from("empty")
+            };
+            let description = if previous == 3 {
+                format!(
+                    "1D LP on the line of {}: the bounding box limits it to {interval}.",
+                    self.name(constraint)
+                )
+            } else {
+                format!(
+                    "1D LP on the line of {}: {} {bound}. The feasible interval is {interval}.",
+                    self.name(constraint),
+                    self.name(previous)
+                )
+            };
+            self.add_step(drawables, description);
+        }
+
+        if lower > upper {
+            return None;
+        }
+        // The objective increases along the line in the direction of c · d.
+        let t = if self.objective.dot(&direction) > 0.0 {
+            upper
+        } else {
+            lower
+        };
+        Some(half_plane.point + direction * t)
+    }
+
+    pub fn calculate(&mut self) {
+        self.steps = vec![];
+        self.descriptions = vec![];
+        self.maximum_step_count = 0;
+        self.optimum = None;
+        self.violations = 0;
+        self.added = vec![];
+
+        let (lower_left, upper_right) = (
+            Point2::new(self.x_bounds[0], self.y_bounds[0]),
+            Point2::new(self.x_bounds[1], self.y_bounds[1]),
+        );
+        self.constraints = vec![
+            HalfPlane {
+                point: lower_left,
+                normal: Vector2::new(1.0, 0.0),
+            },
+            HalfPlane {
+                point: lower_left,
+                normal: Vector2::new(0.0, 1.0),
+            },
+            HalfPlane {
+                point: upper_right,
+                normal: Vector2::new(-1.0, 0.0),
+            },
+            HalfPlane {
+                point: upper_right,
+                normal: Vector2::new(0.0, -1.0),
+            },
+        ];
+        self.constraints.extend(
+            self.points
+                .iter()
+                .zip(&self.normals)
+                .map(|(point, normal)| HalfPlane {
+                    point: *point,
+                    normal: *normal,
+                }),
+        );
+        self.added = (0..4).collect();
+        self.region = vec![
+            lower_left,
+            Point2::new(upper_right.x, lower_left.y),
+            upper_right,
+            Point2::new(lower_left.x, upper_right.y),
+        ];
+        // Ties between corners are broken towards the first one in the direction
+        // of the objective rotated counterclockwise.
+        self.optimum = self.region.iter().copied().max_by(|a, b| {
+            let key = |point: &Point2<f64>| {
+                (
+                    point.coords.dot(&self.objective),
+                    point
+                        .coords
+                        .dot(&Vector2::new(-self.objective.y, self.objective.x)),
+                )
+            };
+            key(a).partial_cmp(&key(b)).unwrap()
+        });
+        self.add_step(
+            vec![],
+            format!(
+                "Maximize c · x for c = ({:.2}, {:.2}), press r to enter another direction. \
+                 The bounding box keeps the program bounded, its best corner is the first \
+                 optimum.",
+                self.objective.x, self.objective.y
+            ),
+        );
+
+        let mut order = (4..self.constraints.len())
+            .filter(|constraint| self.constraints[*constraint].normal.norm() > EPSILON)
+            .collect::<Vec<_>>();
+        order.shuffle(&mut rand::thread_rng());
+        let count = order.len();
+        for (insertion, constraint) in order.into_iter().enumerate() {
+            let half_plane = self.constraints[constraint];
+            let optimum = self.optimum.unwrap();
+            let mut drawables = self
+                .boundary(constraint, Color::Yellow)
+                .into_iter()
+                .collect::<Vec<_>>();
+            drawables.push(Drawable::points(&[half_plane.point], Color::Yellow));
+            if half_plane.contains(&optimum) {
+                self.add_step(
+                    drawables,
+                    format!(
+                        "Add {} as constraint {} of {count}. The optimum satisfies it and \
+                         stays.",
+                        self.name(constraint),
+                        insertion + 1
+                    ),
+                );
+            } else {
+                self.violations += 1;
+                self.add_step(
+                    drawables,
+                    format!(
+                        "Add {} as constraint {} of {count}. It cuts off the optimum, so the \
+                         new optimum lies on its boundary line.",
+                        self.name(constraint),
+                        insertion + 1
+                    ),
+                );
+                self.optimum = self.solve_on_line(constraint);
+                if self.optimum.is_none() {
+                    self.region = vec![];
+                    self.add_step(
+                        vec![],
+                        format!(
+                            "The interval is empty, so the program is infeasible after adding \
+                             {}.",
+                            self.name(constraint)
+                        ),
+                    );
+                    return;
+                }
+            }
+            self.clip_region(&half_plane);
+            self.added.push(constraint);
+        }
+
+        let optimum = self.optimum.unwrap();
+        self.add_step(
+            vec![],
+            format!(
+                "The optimum is ({:.2}, {:.2}) with value {:.2}. {} of {count} constraints \
+                 needed a 1D LP. By backward analysis, the i-th one does so with probability \
+                 at most 2/i, so about {:.1} are expected.",
+                optimum.x,
+                optimum.y,
+                optimum.coords.dot(&self.objective),
+                self.violations,
+                (1..=count).map(|i| 2.0 / i as f64).sum::<f64>()
+            ),
+        );
+    }
+}
+
+impl Algorithm for LinearProgramming {
+    fn get_title(&self) -> &str {
+        "Seidel's linear programming"
+    }
+
+    fn get_points(&self) -> &Vec<Point2<f64>> {
+        &self.points
+    }
+
+    fn set_points(&mut self, points: Vec<Point2<f64>>) {
+        // Every point bounds a half-plane facing a random direction, so the
+        // program may as well be infeasible.
+        let mut rng = rand::thread_rng();
+        self.normals = points
+            .iter()
+            .map(|_| {
+                let angle = rng.gen_range(0.0..std::f64::consts::TAU);
+                Vector2::new(angle.cos(), angle.sin())
+            })
+            .collect();
+        self.points = points;
+    }
+
+    fn set_bounds(&mut self, x_bounds: [f64; 2], y_bounds: [f64; 2]) {
+        self.x_bounds = x_bounds;
+        self.y_bounds = y_bounds;
+    }
+
+    fn get_query_kind(&self) -> Option<QueryKind> {
+        Some(QueryKind::Objective)
+    }
+
+    fn set_query(&mut self, query: Vec<f64>) -> bool {
+        match query[..] {
+            [x, y] if x != 0.0 || y != 0.0 => {
+                self.objective = Vector2::new(x, y);
+                true
+            }
+            _ => false,
+        }
+    }
+
+    fn get_steps(&self) -> Vec<Vec<Drawable>> {
+        self.steps.clone()
+    }
+
+    fn get_draw_method(&self) -> DrawMethod {
+        DrawMethod::Edge
+    }
+
+    fn get_step_description(&self, step: usize) -> Option<String> {
+        self.descriptions.get(step).cloned()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::algorithms::testing::random_points;
+
+    fn linear_programming(points: Vec<Point2<f64>>, objective: Vector2<f64>) -> LinearProgramming {
+        let mut linear_programming = LinearProgramming::new();
+        linear_programming.set_bounds([-10.0, 110.0], [-10.0, 110.0]);
+        linear_programming.set_points(points);
+        linear_programming.set_query(vec![objective.x, objective.y]);
+        linear_programming.calculate();
+        linear_programming
+    }
+
+    /// Finds the best value among all feasible intersections of two boundary
+    /// lines, if there are any.
+    fn brute_force_value(linear_programming: &LinearProgramming) -> Option<f64> {
+        let constraints = &linear_programming.constraints;
+        constraints
+            .iter()
+            .tuple_combinations()
+            .filter_map(|(a, b)| {
+                let (direction, other) = (a.direction(), b.direction());
+                let denominator = direction.x * other.y - direction.y * other.x;
+                if denominator.abs() < EPSILON {
+                    return None;
+                }
+                let offset = b.point - a.point;
+                let t = (offset.x * other.y - offset.y * other.x) / denominator;
+                Some(a.point + direction * t)
+            })
+            .filter(|point| {
+                constraints
+                    .iter()
+                    .all(|constraint| constraint.contains(point))
+            })
+            .map(|point| point.coords.dot(&linear_programming.objective))
+            .reduce(f64::max)
+    }
+
+    #[test]
+    fn without_points_a_corner_is_optimal() {
+        let linear_programming = linear_programming(vec![], Vector2::new(1.0, 1.0));
+        assert_eq!(linear_programming.optimum, Some(Point2::new(110.0, 110.0)));
+    }
+
+    #[test]
+    fn opposing_half_planes_are_infeasible() {
+        let mut linear_programming = LinearProgramming::new();
+        linear_programming.set_bounds([-10.0, 110.0], [-10.0, 110.0]);
+        linear_programming.set_points(vec![Point2::new(20.0, 50.0), Point2::new(80.0, 50.0)]);
+        // x <= 20 and x >= 80.
+        linear_programming.normals = vec![Vector2::new(-1.0, 0.0), Vector2::new(1.0, 0.0)];
+        linear_programming.calculate();
+        assert_eq!(linear_programming.optimum, None);
+        assert!(linear_programming.region.is_empty());
+        assert!(linear_programming
+            .descriptions
+            .last()
+            .unwrap()
+            .contains("infeasible"));
+    }
+
+    #[test]
+    fn matches_brute_force_optimum() {
+        let mut infeasible = 0;
+        for _ in 0..5 {
+            for amount in 1..40 {
+                let objective = random_points(1)[0] - Point2::new(50.0, 50.0);
+                let linear_programming = linear_programming(random_points(amount), objective);
+                let expected = brute_force_value(&linear_programming);
+                let optimum = match linear_programming.optimum {
+                    Some(optimum) => optimum,
+                    None => {
+                        assert_eq!(expected, None);
+                        infeasible += 1;
+                        continue;
+                    }
+                };
+                assert!(linear_programming
+                    .constraints
+                    .iter()
+                    .all(|constraint| constraint.contains(&optimum)));
+                let value = optimum.coords.dot(&objective);
+                assert!((value - expected.unwrap()).abs() < 1e-6 * objective.norm());
+            }
+        }
+        // Random half-planes exclude each other most of the time.
+        assert!(infeasible > 0);
+    }
+}
@@ -0,0 +1,654 @@
+use std::collections::HashSet;
+
+use itertools::Itertools;
+use nalgebra::Point2;
+use tui::style::Color;
+
+use super::{
+    algorithm::{Algorithm, DrawMethod, Drawable},
+    bowyer_watson::BowyerWatson,
+    dcel::Dcel,
+    geometry::Segment,
+};
+
+const NAMES: [&str; 2] = ["A", "B"];
+const COLORS: [Color; 2] = [Color::Blue, Color::Cyan];
+
+enum EventKind {
+    Vertex(usize, usize),
+    Crossing(usize, usize),
+}
+
+struct Event {
+    point: Point2<f64>,
+    kind: EventKind,
+}
+
+/// An edge of one of both subdivisions, oriented from left to right.
+struct Edge {
+    subdivision: usize,
+    segment: Segment,
+    vertices: [usize; 2],
+    // The half-edge of the subdivision running from left to right.
+    half_edge: usize,
+    // The vertex of the overlay the sweep passed last on this edge.
+    last: usize,
+}
+
+pub struct MapOverlay {
+    // Later passed to app.
+    pub maximum_step_count: usize,
+    pub points: Vec<Point2<f64>>,
+    pub steps: Vec<Vec<Drawable>>,
+    pub descriptions: Vec<String>,
+    // The overlay of both subdivisions and the labels of its faces.
+    pub overlay: Dcel,
+    pub labels: Vec<String>,
+    subdivisions: Vec<Dcel>,
+    edges: Vec<Edge>,
+    // The edge every edge of the overlay is part of.
+    sources: Vec<usize>,
+    // For every vertex of the overlay, the nearest edge of the other subdivision
+    // below it when the sweep passed it.
+    below: Vec<Option<usize>>,
+    // Events ordered from left to right.
+    queue: Vec<Event>,
+    // Edges intersecting the sweep line ordered from bottom to top.
+    status: Vec<usize>,
+    scheduled: HashSet<(usize, usize)>,
+    drawn_labels: Vec<Drawable>,
+    y_bounds: [f64; 2],
+}
+
+impl MapOverlay {
+    pub fn new() -> Self {
+        MapOverlay {
+            maximum_step_count: 0,
+            points: vec![],
+            steps: vec![],
+            descriptions: vec![],
+            overlay: Dcel::new(),
+            labels: vec![],
+            subdivisions: vec![],
+            edges: vec![],
+            sources: vec![],
+            below: vec![],
+            queue: vec![],
+            status: vec![],
+            scheduled: HashSet::new(),
+            drawn_labels: vec![],
+            y_bounds: [0.0, 0.0],
+        }
+    }
+
+    /// Stores both subdivisions, the edges of the overlay created so far and the
+    /// face labels together with the given drawables.
+    fn add_step(&mut self, drawables: Vec<Drawable>, description: String) {
+        let mut step = self
+            .edges
+            .iter()
+            .map(|edge| {
+                Drawable::line(
+                    &edge.segment.from,
+                    &edge.segment.to,
+                    COLORS[edge.subdivision],
+                )
+            })
+            .collect::<Vec<_>>();
+        step.extend(
+            (0..self.overlay.half_edges.len())
+                .step_by(2)
+                .map(|half_edge| {
+                    Drawable::line(
+                        self.overlay.origin(half_edge),
+                        self.overlay.destination(half_edge),
+                        Color::Green,
+                    )
+                }),
+        );
+        step.extend(drawables);
+        step.extend(self.drawn_labels.iter().cloned());
+
+        self.steps.push(step);
+        self.descriptions.push(description);
+        self.maximum_step_count += 1;
+    }
+
+    fn face_name(subdivision: usize, face: usize) -> String {
+        if face == 0 {
+            format!("{}∞", NAMES[subdivision])
+        } else {
+            format!("{}{face}", NAMES[subdivision])
+        }
+    }
+
+    fn face_lines(dcel: &Dcel, face: usize, color: Color) -> Vec<Drawable> {
+        dcel.faces[face]
+            .outer
+            .map(|outer| dcel.polygon(outer))
+            .unwrap_or_default()
+            .iter()
+            .circular_tuple_windows()
+            .map(|(from, to)| Drawable::line(from, to, color))
+            .collect()
+    }
+
+    /// Builds the subdivision given by the Delaunay triangulation of the points.
+    fn triangulate(points: Vec<Point2<f64>>) -> Dcel {
+        let mut triangulation = BowyerWatson::new();
+        triangulation.set_points(points.clone());
+        triangulation.calculate();
+
+        let mut dcel = Dcel::new();
+        for point in points {
+            dcel.add_vertex(point);
+        }
+        let edges = triangulation
+            .triangles
+            .iter()
+            .flat_map(|triangle| {
+                triangle
+                    .iter()
+                    .circular_tuple_windows()
+                    .map(|(from, to)| (*from.min(to), *from.max(to)))
+                    .collect::<Vec<_>>()
+            })
+            .unique()
+            .collect::<Vec<_>>();
+        for (from, to) in edges {
+            dcel.add_edge(from, to);
+        }
+        dcel.link();
+        dcel.build_faces();
+        dcel
+    }
+
+    fn push_event(&mut self, event: Event) {
+        let key = |point: &Point2<f64>| (point.x, point.y);
+        let position = self
+            .queue
+            .partition_point(|other| key(&other.point) < key(&event.point));
+        self.queue.insert(position, event);
+    }
+
+    /// Schedules the crossing of the edges at the given status positions, if
+    /// they belong to different subdivisions and cross right of the sweep line.
+    /// Returns whether a new crossing was found.
+    fn check_neighbours(
+        &mut self,
+        below: Option<usize>,
+        above: usize,
+        sweep: &Point2<f64>,
+    ) -> bool {
+        let (below, above) = match (below, self.status.get(above)) {
+            (Some(below), Some(&above)) => (self.status[below], above),
+            _ => return false,
+        };
+        let pair = (below.min(above), below.max(above));
+        if self.edges[below].subdivision == self.edges[above].subdivision
+            || self.scheduled.contains(&pair)
+        {
+            return false;
+        }
+        match self.edges[below]
+            .segment
+            .intersection(&self.edges[above].segment)
+        {
+            Some(point) if (point.x, point.y) > (sweep.x, sweep.y) => {
+                self.scheduled.insert(pair);
+                self.push_event(Event {
+                    point,
+                    kind: EventKind::Crossing(below, above),
+                });
+                true
+            }
+            _ => false,
+        }
+    }
+
+    fn y_at(&self, edge: usize, x: f64) -> f64 {
+        let segment = &self.edges[edge].segment;
+        if segment.from.x == segment.to.x {
+            return segment.from.y;
+        }
+        let t = (x - segment.from.x) / (segment.to.x - segment.from.x);
+        segment.from.y + t * (segment.to.y - segment.from.y)
+    }
+
+    fn slope(&self, edge: usize) -> f64 {
+        let segment = &self.edges[edge].segment;
+        if segment.from.x == segment.to.x {
+            return f64::INFINITY;
+        }
+        (segment.to.y - segment.from.y) / (segment.to.x - segment.from.x)
+    }
+
+    /// Adds the part of the edge between the last passed vertex and the given one
+    /// to the overlay.
+    fn extend(&mut self, edge: usize, vertex: usize) {
+        self.overlay.add_edge(self.edges[edge].last, vertex);
+        self.sources.push(edge);
+        self.edges[edge].last = vertex;
+    }
+
+    /// Finds the face of the subdivision containing the bounded face of the
+    /// overlay. An edge of the subdivision on its outer boundary has the face on
+    /// the same side, otherwise the face lies above the edge of the subdivision
+    /// the sweep found below its leftmost vertex.
+    fn containing(&self, face: usize, subdivision: usize) -> usize {
+        let dcel = &self.subdivisions[subdivision];
+        let cycle = self.overlay.cycle(self.overlay.faces[face].outer.unwrap());
+        if let Some(half_edge) = cycle
+            .iter()
+            .find(|half_edge| self.edges[self.sources[*half_edge / 2]].subdivision == subdivision)
+        {
+            // Overlay edges are added from left to right, like the half-edge
+            // stored with their edge.
+            let original = self.edges[self.sources[half_edge / 2]].half_edge;
+            let original = if half_edge % 2 == 0 {
+                original
+            } else {
+                dcel.half_edges[original].twin
+            };
+            return dcel.half_edges[original].face;
+        }
+
+        let leftmost = cycle
+            .into_iter()
+            .map(|half_edge| self.overlay.half_edges[half_edge].origin)
+            .min_by(|a, b| {
+                let key = |vertex: &usize| {
+                    let point = &self.overlay.vertices[*vertex].point;
+                    (point.x, point.y)
+                };
+                key(a).partial_cmp(&key(b)).unwrap()
+            })
+            .unwrap();
+        self.below[leftmost]
+            .map(|edge| dcel.half_edges[self.edges[edge].half_edge].face)
+            .unwrap_or(0)
+    }
+
+    pub fn calculate(&mut self) {
+        self.steps = vec![];
+        self.descriptions = vec![];
+        self.maximum_step_count = 0;
+        self.overlay = Dcel::new();
+        self.labels = vec![];
+        self.subdivisions = vec![];
+        self.edges = vec![];
+        self.sources = vec![];
+        self.below = vec![];
+        self.queue = vec![];
+        self.status = vec![];
+        self.scheduled = HashSet::new();
+        self.drawn_labels = vec![];
+
+        // Every other point belongs to the second subdivision.
+        let (first, second): (Vec<_>, Vec<_>) = self
+            .points
+            .iter()
+            .enumerate()
+            .partition(|(index, _)| index % 2 == 0);
+        for points in [first, second] {
+            let points = points.into_iter().map(|(_, point)| *point).collect();
+            self.subdivisions.push(Self::triangulate(points));
+        }
+        if self.subdivisions.iter().any(|dcel| dcel.faces.len() < 2) {
+            self.add_step(
+                vec![],
+                String::from(
+                    "Both subdivisions triangulate every other point, so they need at least \
+                     six points in general position.",
+                ),
+            );
+            return;
+        }
+
+        for (subdivision, dcel) in self.subdivisions.iter().enumerate() {
+            for half_edge in (0..dcel.half_edges.len()).step_by(2) {
+                let vertices =
+                    [half_edge, half_edge + 1].map(|half_edge| dcel.half_edges[half_edge].origin);
+                let [from, to] = vertices.map(|vertex| dcel.vertices[vertex].point);
+                let (segment, vertices, half_edge) = if (from.x, from.y) < (to.x, to.y) {
+                    (Segment::new(from, to), vertices, half_edge)
+                } else {
+                    (
+                        Segment::new(to, from),
+                        [vertices[1], vertices[0]],
+                        half_edge + 1,
+                    )
+                };
+                self.edges.push(Edge {
+                    subdivision,
+                    segment,
+                    vertices,
+                    half_edge,
+                    last: 0,
+                });
+            }
+        }
+        for subdivision in 0..2 {
+            let dcel = &self.subdivisions[subdivision];
+            let mut labels = vec![];
+            let mut faces = vec![];
+            for face in 1..dcel.faces.len() {
+                if let Some(point) = dcel.interior_point(face) {
+                    labels.push(Drawable::label(
+                        &point,
+                        Self::face_name(subdivision, face),
+                        COLORS[subdivision],
+                    ));
+                }
+                faces.extend(Self::face_lines(dcel, face, COLORS[subdivision]));
+            }
+            let description = format!(
+                "Subdivision {} triangulates {} points into {} faces plus the unbounded face \
+                 {}. Its DCEL has {} half-edges.",
+                NAMES[subdivision],
+                dcel.vertices.len(),
+                dcel.faces.len() - 1,
+                Self::face_name(subdivision, 0),
+                dcel.half_edges.len()
+            );
+            faces.extend(labels);
+            self.add_step(faces, description);
+        }
+
+        for subdivision in 0..2 {
+            for vertex in 0..self.subdivisions[subdivision].vertices.len() {
+                self.push_event(Event {
+                    point: self.subdivisions[subdivision].vertices[vertex].point,
+                    kind: EventKind::Vertex(subdivision, vertex),
+                });
+            }
+        }
+        self.add_step(
+            vec![],
+            format!(
+                "Sweep from left to right over the {} vertices of both subdivisions. Crossings \
+                 of edges from A and B become new vertices of the overlay.",
+                self.queue.len()
+            ),
+        );
+
+        while !self.queue.is_empty() {
+            let event = self.queue.remove(0);
+            let vertex = self.overlay.add_vertex(event.point);
+            let sweep = event.point;
+            let description = match event.kind {
+                EventKind::Vertex(subdivision, original) => {
+                    // The edges ending here are neighbours in the status, the
+                    // starting ones take their place ordered by their slope.
+                    let ending = self
+                        .status
+                        .iter()
+                        .copied()
+                        .filter(|edge| {
+                            self.edges[*edge].subdivision == subdivision
+                                && self.edges[*edge].vertices[1] == original
+                        })
+                        .collect::<Vec<_>>();
+                    let position = match ending.first() {
+                        Some(first) => self.status.iter().position(|edge| edge == first).unwrap(),
+                        None => self
+                            .status
+                            .partition_point(|edge| self.y_at(*edge, sweep.x) < sweep.y),
+                    };
+                    for edge in ending.iter() {
+                        self.extend(*edge, vertex);
+                    }
+                    self.status.retain(|edge| !ending.contains(edge));
+                    self.below.push(
+                        self.status[..position]
+                            .iter()
+                            .rev()
+                            .copied()
+                            .find(|edge| self.edges[*edge].subdivision != subdivision),
+                    );
+
+                    let mut starting = (0..self.edges.len())
+                        .filter(|edge| {
+                            self.edges[*edge].subdivision == subdivision
+                                && self.edges[*edge].vertices[0] == original
+                        })
+                        .collect::<Vec<_>>();
+                    starting.sort_by(|a, b| self.slope(*a).partial_cmp(&self.slope(*b)).unwrap());
+                    for (offset, edge) in starting.iter().enumerate() {
+                        self.edges[*edge].last = vertex;
+                        self.status.insert(position + offset, *edge);
+                    }
+                    let top = position + starting.len();
+                    let crossings = [
+                        self.check_neighbours(position.checked_sub(1), position, &sweep),
+                        !starting.is_empty() && self.check_neighbours(Some(top - 1), top, &sweep),
+                    ]
+                    .into_iter()
+                    .filter(|found| *found)
+                    .count();
+                    format!(
+                        "Vertex of {}: {} edges end and {} edges start here. Testing the edges \
+                         next to them in the status finds {crossings} new crossings.",
+                        NAMES[subdivision],
+                        ending.len(),
+                        starting.len()
+                    )
+                }
+                EventKind::Crossing(edge, other) => {
+                    self.extend(edge, vertex);
+                    self.extend(other, vertex);
+                    self.below.push(None);
+
+                    // Both edges change their order behind the crossing.
+                    let [lower, upper] = [edge, other]
+                        .map(|edge| self.status.iter().position(|other| *other == edge).unwrap());
+                    self.status.swap(lower, upper);
+                    let (lower, upper) = (lower.min(upper), lower.max(upper));
+                    let crossings = [
+                        self.check_neighbours(lower.checked_sub(1), lower, &sweep),
+                        self.check_neighbours(Some(upper), upper + 1, &sweep),
+                    ]
+                    .into_iter()
+                    .filter(|found| *found)
+                    .count();
+                    format!(
+                        "Crossing of an edge of A and an edge of B: split both at a new vertex \
+                         of the overlay and swap them in the status. Their new neighbours add \
+                         {crossings} crossings."
+                    )
+                }
+            };
+
+            let mut drawables = self
+                .status
+                .iter()
+                .map(|edge| {
+                    let segment = &self.edges[*edge].segment;
+                    Drawable::line(&segment.from, &segment.to, Color::Yellow)
+                })
+                .collect::<Vec<_>>();
+            drawables.push(Drawable::line(
+                &Point2::new(event.point.x, self.y_bounds[0]),
+                &Point2::new(event.point.x, self.y_bounds[1]),
+                Color::Yellow,
+            ));
+            drawables.push(Drawable::points(&[event.point], Color::Yellow));
+            self.add_step(drawables, description);
+        }
+
+        self.overlay.link();
+        self.overlay.build_faces();
+        let crossings = (0..self.overlay.vertices.len())
+            .filter(|vertex| {
+                !self
+                    .subdivisions
+                    .iter()
+                    .flat_map(|dcel| dcel.vertices.iter())
+                    .any(|original| original.point == self.overlay.vertices[*vertex].point)
+            })
+            .collect::<Vec<_>>();
+        let degrees = crossings
+            .iter()
+            .map(|vertex| self.overlay.outgoing(*vertex).len())
+            .unique()
+            .join(", ");
+        self.add_step(
+            vec![Drawable::points(
+                &crossings
+                    .iter()
+                    .map(|vertex| self.overlay.vertices[*vertex].point)
+                    .collect::<Vec<_>>(),
+                Color::Yellow,
+            )],
+            format!(
+                "Link the half-edges around every vertex by their angle. The {} crossings have \
+                 {degrees} outgoing half-edges. Walking the next pointers yields {} faces.",
+                crossings.len(),
+                self.overlay.faces.len()
+            ),
+        );
+
+        // Every face of the overlay lies inside one face of each subdivision.
+        self.labels = vec![format!(
+            "{}∩{}",
+            Self::face_name(0, 0),
+            Self::face_name(1, 0)
+        )];
+        for face in 1..self.overlay.faces.len() {
+            let [first, second] = [0, 1].map(|subdivision| {
+                Self::face_name(subdivision, self.containing(face, subdivision))
+            });
+            let label = format!("{first}∩{second}");
+            self.labels.push(label.clone());
+            if let Some(point) = self.overlay.interior_point(face) {
+                self.drawn_labels
+                    .push(Drawable::label(&point, label.clone(), Color::White));
+            }
+            self.add_step(
+                Self::face_lines(&self.overlay, face, Color::Yellow),
+                format!(
+                    "Face {face} of the overlay lies in {first} and {second}, seen from the \
+                     edges of its boundary or the edges below its leftmost vertex: label it \
+                     {label}."
+                ),
+            );
+        }
+
+        self.add_step(
+            vec![],
+            format!(
+                "The overlay has {} vertices, {} half-edges and {} faces. The unbounded face \
+                 is {}.",
+                self.overlay.vertices.len(),
+                self.overlay.half_edges.len(),
+                self.overlay.faces.len(),
+                self.labels[0]
+            ),
+        );
+    }
+}
+
+impl Algorithm for MapOverlay {
+    fn get_title(&self) -> &str {
+        "Map overlay"
+    }
+
+    fn get_points(&self) -> &Vec<Point2<f64>> {
+        &self.points
+    }
+
+    fn set_points(&mut self, points: Vec<Point2<f64>>) {
+        self.points = points;
+    }
+
+    fn set_bounds(&mut self, _x_bounds: [f64; 2], y_bounds: [f64; 2]) {
+        self.y_bounds = y_bounds;
+    }
+
+    fn get_steps(&self) -> Vec<Vec<Drawable>> {
+        self.steps.clone()
+    }
+
+    fn get_draw_method(&self) -> DrawMethod {
+        DrawMethod::Edge
+    }
+
+    fn get_step_description(&self, step: usize) -> Option<String> {
+        self.descriptions.get(step).cloned()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::algorithms::testing::{assert_same_points, random_points};
+
+    fn map_overlay(points: Vec<Point2<f64>>) -> MapOverlay {
+        let mut map_overlay = MapOverlay::new();
+        map_overlay.set_bounds([0.0, 100.0], [0.0, 100.0]);
+        map_overlay.set_points(points);
+        map_overlay.calculate();
+        map_overlay
+    }
+
+    #[test]
+    fn small_and_collinear_inputs_have_no_overlay() {
+        let line = (0..8).map(|i| Point2::new(10.0 * i as f64, 5.0)).collect();
+        for map_overlay in (0..6)
+            .map(|amount| map_overlay(random_points(amount)))
+            .chain([map_overlay(line)])
+        {
+            assert!(map_overlay.overlay.half_edges.is_empty());
+            assert!(map_overlay.labels.is_empty());
+        }
+    }
+
+    #[test]
+    fn crossings_match_brute_force() {
+        for amount in 6..40 {
+            let map_overlay = map_overlay(random_points(amount));
+            let [first, second] = [0, 1].map(|subdivision| {
+                map_overlay
+                    .edges
+                    .iter()
+                    .filter(|edge| edge.subdivision == subdivision)
+                    .map(|edge| edge.segment)
+                    .collect::<Vec<_>>()
+            });
+            let expected = first
+                .iter()
+                .flat_map(|a| second.iter().filter_map(|b| a.intersection(b)))
+                .collect::<Vec<_>>();
+            let crossings = map_overlay
+                .overlay
+                .vertices
+                .iter()
+                .map(|vertex| vertex.point)
+                .filter(|point| !map_overlay.points.contains(point))
+                .collect::<Vec<_>>();
+            assert_same_points(&crossings, &expected);
+            assert_eq!(
+                map_overlay.overlay.vertices.len(),
+                map_overlay.points.len() + expected.len()
+            );
+        }
+    }
+
+    #[test]
+    fn labels_match_point_location() {
+        for amount in 6..40 {
+            let map_overlay = map_overlay(random_points(amount));
+            assert_eq!(map_overlay.labels.len(), map_overlay.overlay.faces.len());
+            for face in 1..map_overlay.overlay.faces.len() {
+                let point = map_overlay.overlay.interior_point(face).unwrap();
+                let [first, second] = [0, 1].map(|subdivision| {
+                    MapOverlay::face_name(
+                        subdivision,
+                        map_overlay.subdivisions[subdivision].locate(&point),
+                    )
+                });
+                assert_eq!(map_overlay.labels[face], format!("{first}∩{second}"));
+            }
+        }
+    }
+}
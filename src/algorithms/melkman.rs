@@ -0,0 +1,210 @@
+use std::collections::VecDeque;
+
+use itertools::Itertools;
+use nalgebra::Point2;
+use tui::style::Color;
+
+use super::{
+    algorithm::{Algorithm, DrawMethod, Drawable, InputKind},
+    geometry::cross_product,
+};
+
+pub struct Melkman {
+    // Later passed to app.
+    pub maximum_step_count: usize,
+    pub points: Vec<Point2<f64>>,
+    pub steps: Vec<Vec<Drawable>>,
+    pub descriptions: Vec<String>,
+    // Indices of the polyline vertices. The front is the bottom and the back
+    // the top of the deque. Both always hold the last inserted vertex.
+    deque: VecDeque<usize>,
+}
+
+impl Melkman {
+    pub fn new() -> Self {
+        Melkman {
+            maximum_step_count: 0,
+            points: vec![],
+            steps: vec![],
+            descriptions: vec![],
+            deque: VecDeque::new(),
+        }
+    }
+
+    /// Stores the polyline up to the given vertex, the hull represented by the
+    /// deque and its bottom and top vertex.
+    fn add_step(&mut self, vertex: usize, description: &str) {
+        let mut step = self.points[..=vertex]
+            .iter()
+            .tuple_windows()
+            .map(|(from, to)| Drawable::line(from, to, Color::DarkGray))
+            .collect::<Vec<_>>();
+        step.extend(
+            self.deque.iter().tuple_windows().map(|(from, to)| {
+                Drawable::line(&self.points[*from], &self.points[*to], Color::Blue)
+            }),
+        );
+        if let (Some(bottom), Some(top)) = (self.deque.front(), self.deque.back()) {
+            step.push(Drawable::points(&[self.points[*bottom]], Color::Green));
+            step.push(Drawable::points(&[self.points[*top]], Color::Magenta));
+        }
+        step.push(Drawable::points(&[self.points[vertex]], Color::Yellow));
+
+        let deque = self
+            .deque
+            .iter()
+            .map(|index| format!("v{index}"))
+            .join(", ");
+        self.steps.push(step);
+        self.descriptions
+            .push(format!("{description} Deque (bottom to top): [{deque}]"));
+        self.maximum_step_count += 1;
+    }
+
+    fn is_left(&self, from: usize, to: usize, vertex: usize) -> bool {
+        cross_product(&self.points[from], &self.points[to], &self.points[vertex]) > 0.0
+    }
+
+    pub fn calculate(&mut self) {
+        self.steps = vec![];
+        self.descriptions = vec![];
+        self.maximum_step_count = 0;
+        self.deque = VecDeque::new();
+
+        if self.points.len() < 3 {
+            self.deque = (0..self.points.len()).collect();
+            let polyline = self
+                .points
+                .iter()
+                .tuple_windows()
+                .map(|(from, to)| Drawable::line(from, to, Color::DarkGray))
+                .collect::<Vec<_>>();
+            self.steps.push(polyline);
+            self.descriptions
+                .push(String::from("A polyline needs at least three vertices."));
+            self.maximum_step_count += 1;
+            return;
+        }
+
+        // Orient the first triangle counterclockwise.
+        self.deque = if self.is_left(0, 1, 2) {
+            VecDeque::from([2, 0, 1, 2])
+        } else {
+            VecDeque::from([2, 1, 0, 2])
+        };
+        self.add_step(2, "The first three vertices form a triangle.");
+
+        for vertex in 3..self.points.len() {
+            let bottom = self.deque[0];
+            let above_bottom = self.deque[1];
+            let top = self.deque[self.deque.len() - 1];
+            let below_top = self.deque[self.deque.len() - 2];
+
+            // A vertex left of both the bottom and top edge lies inside the hull.
+            if self.is_left(bottom, above_bottom, vertex) && self.is_left(below_top, top, vertex) {
+                self.add_step(
+                    vertex,
+                    &format!("v{vertex} lies inside the hull and is skipped."),
+                );
+                continue;
+            }
+            self.add_step(vertex, &format!("v{vertex} lies outside the hull."));
+
+            while !self.is_left(self.deque[0], self.deque[1], vertex) {
+                let popped = self.deque.pop_front().unwrap();
+                self.add_step(vertex, &format!("Pop v{popped} from the bottom."));
+            }
+            self.deque.push_front(vertex);
+            self.add_step(vertex, &format!("Push v{vertex} to the bottom."));
+
+            while !self.is_left(
+                self.deque[self.deque.len() - 2],
+                self.deque[self.deque.len() - 1],
+                vertex,
+            ) {
+                let popped = self.deque.pop_back().unwrap();
+                self.add_step(vertex, &format!("Pop v{popped} from the top."));
+            }
+            self.deque.push_back(vertex);
+            self.add_step(vertex, &format!("Push v{vertex} to the top."));
+        }
+
+        self.add_step(
+            self.points.len() - 1,
+            &format!("The convex hull has {} vertices.", self.deque.len() - 1),
+        );
+    }
+}
+
+impl Algorithm for Melkman {
+    fn get_title(&self) -> &str {
+        "Melkman"
+    }
+
+    fn get_points(&self) -> &Vec<Point2<f64>> {
+        &self.points
+    }
+
+    fn set_points(&mut self, points: Vec<Point2<f64>>) {
+        self.points = points;
+    }
+
+    fn get_input_kind(&self) -> InputKind {
+        InputKind::SimplePolyline
+    }
+
+    fn get_steps(&self) -> Vec<Vec<Drawable>> {
+        self.steps.clone()
+    }
+
+    fn get_draw_method(&self) -> DrawMethod {
+        DrawMethod::Edge
+    }
+
+    fn get_step_description(&self, step: usize) -> Option<String> {
+        self.descriptions.get(step).cloned()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::algorithms::testing::{
+        assert_same_points, brute_force_hull, random_points, random_star_polygon, sorted,
+    };
+
+    fn melkman(points: Vec<Point2<f64>>) -> Melkman {
+        let mut melkman = Melkman::new();
+        melkman.set_points(points);
+        melkman.calculate();
+        melkman
+    }
+
+    #[test]
+    fn short_polylines_are_their_own_hull() {
+        for amount in 0..3 {
+            let melkman = melkman(random_points(amount));
+            assert!(melkman.deque.iter().copied().eq(0..amount));
+        }
+    }
+
+    #[test]
+    fn matches_brute_force_hull() {
+        for amount in 3..40 {
+            // Both monotone and star-shaped polylines are simple.
+            for points in [
+                sorted(random_points(amount)),
+                random_star_polygon(amount, Point2::new(50.0, 50.0), 50.0),
+            ] {
+                let melkman = melkman(points.clone());
+                let hull = melkman
+                    .deque
+                    .iter()
+                    .skip(1)
+                    .map(|index| points[*index])
+                    .collect::<Vec<_>>();
+                assert_same_points(&hull, &brute_force_hull(&points));
+            }
+        }
+    }
+}
@@ -0,0 +1,309 @@
+use nalgebra::Point2;
+use rand::seq::SliceRandom;
+use tui::style::Color;
+
+use super::{
+    algorithm::{Algorithm, DrawMethod, Drawable},
+    geometry::circumcircle,
+};
+
+type Disk = (Point2<f64>, f64);
+
+pub struct MinimumEnclosingDisk {
+    // Later passed to app.
+    pub maximum_step_count: usize,
+    pub points: Vec<Point2<f64>>,
+    pub steps: Vec<Vec<Drawable>>,
+    pub descriptions: Vec<String>,
+    // The smallest disk containing all points.
+    pub disk: Option<Disk>,
+    // Number of points whose containment was tested on each level of recursion.
+    pub tests: [usize; 3],
+    // The points in random order and the points fixed on the boundary.
+    order: Vec<usize>,
+    fixed: Vec<usize>,
+}
+
+impl MinimumEnclosingDisk {
+    pub fn new() -> Self {
+        MinimumEnclosingDisk {
+            maximum_step_count: 0,
+            points: vec![],
+            steps: vec![],
+            descriptions: vec![],
+            disk: None,
+            tests: [0; 3],
+            order: vec![],
+            fixed: vec![],
+        }
+    }
+
+    /// Stores the current disk, the points of the current subproblem and the
+    /// fixed boundary points together with the given drawables.
+    fn add_step(&mut self, prefix: usize, drawables: Vec<Drawable>, description: String) {
+        let mut step = vec![Drawable::points(
+            &self.order[..prefix]
+                .iter()
+                .map(|point| self.points[*point])
+                .collect::<Vec<_>>(),
+            Color::Blue,
+        )];
+        if let Some((center, radius)) = self.disk {
+            step.push(Drawable::circle(&center, radius, Color::Magenta));
+            step.push(Drawable::points(&[center], Color::Magenta));
+        }
+        step.extend(drawables);
+        step.push(Drawable::points(
+            &self
+                .fixed
+                .iter()
+                .map(|point| self.points[*point])
+                .collect::<Vec<_>>(),
+            Color::Yellow,
+        ));
+
+        self.steps.push(step);
+        self.descriptions.push(description);
+        self.maximum_step_count += 1;
+    }
+
+    fn contains(&self, point: usize) -> bool {
+        let (center, radius) = self.disk.unwrap();
+        (self.points[point] - center).norm() <= radius * (1.0 + 1e-9) + 1e-9
+    }
+
+    /// Returns the smallest disk with both points on its boundary.
+    fn diametral_disk(&self, a: usize, b: usize) -> Disk {
+        let (a, b) = (self.points[a], self.points[b]);
+        (a + (b - a) / 2.0, (b - a).norm() / 2.0)
+    }
+
+    /// Returns the disk through the fixed points and the given one.
+    fn boundary_disk(&self, point: usize) -> Disk {
+        match self.fixed[..] {
+            [fixed] => self.diametral_disk(fixed, point),
+            [a, b] => circumcircle(&self.points[a], &self.points[b], &self.points[point])
+                .unwrap_or_else(|| {
+                    // Collinear points are only enclosed by the disk of the outer two.
+                    [(a, b), (a, point), (b, point)]
+                        .into_iter()
+                        .map(|(a, b)| self.diametral_disk(a, b))
+                        .max_by(|a, b| a.1.partial_cmp(&b.1).unwrap())
+                        .unwrap()
+                }),
+            _ => unreachable!(),
+        }
+    }
+
+    /// Computes the smallest disk containing the first points of the order with
+    /// the fixed points on its boundary. The caller has set up the disk of the
+    /// first points that are needed for it.
+    fn enclose(&mut self, start: usize, end: usize) {
+        let level = self.fixed.len();
+        let context = ["", "With one point fixed: ", "With two points fixed: "][level];
+        for position in start..end {
+            let point = self.order[position];
+            self.tests[level] += 1;
+            if self.contains(point) {
+                self.add_step(
+                    position + 1,
+                    vec![Drawable::points(&[self.points[point]], Color::Green)],
+                    format!("{context}v{point} lies inside the disk. Keep it."),
+                );
+                continue;
+            }
+
+            let outside = vec![Drawable::points(&[self.points[point]], Color::LightRed)];
+            if level == 2 {
+                self.disk = Some(self.boundary_disk(point));
+                self.add_step(
+                    position + 1,
+                    outside,
+                    format!(
+                        "{context}v{point} lies outside the disk. The circle through both \
+                         fixed points and v{point} is the new disk."
+                    ),
+                );
+                continue;
+            }
+
+            self.add_step(
+                position,
+                outside,
+                format!(
+                    "{context}v{point} lies outside the disk, so it lies on the boundary of \
+                     the smallest disk of the first {} points. Fix it and recurse.",
+                    position + 1
+                ),
+            );
+            self.fixed.push(point);
+            // With two fixed points, the subproblem starts from their diametral disk
+            // and has to test all earlier points.
+            let start = if level == 0 {
+                let first = self.order[0];
+                self.disk = Some(self.boundary_disk(first));
+                self.add_step(
+                    1,
+                    vec![],
+                    format!("Start with the smallest disk of v{first} and the fixed point."),
+                );
+                1
+            } else {
+                self.disk = Some(self.diametral_disk(self.fixed[0], self.fixed[1]));
+                self.add_step(
+                    0,
+                    vec![],
+                    String::from("Start with the smallest disk of both fixed points."),
+                );
+                0
+            };
+            self.enclose(start, position);
+            self.fixed.pop();
+            self.add_step(
+                position + 1,
+                vec![],
+                format!(
+                    "Back from the recursion, the disk encloses the first {} points.",
+                    position + 1
+                ),
+            );
+        }
+    }
+
+    pub fn calculate(&mut self) {
+        self.steps = vec![];
+        self.descriptions = vec![];
+        self.maximum_step_count = 0;
+        self.disk = None;
+        self.tests = [0; 3];
+        self.fixed = vec![];
+
+        self.order = (0..self.points.len()).collect();
+        self.order.shuffle(&mut rand::thread_rng());
+        if self.points.len() < 2 {
+            self.disk = self.points.first().map(|point| (*point, 0.0));
+            let description = match self.disk {
+                Some(_) => "A single point is its own enclosing disk of radius zero.",
+                None => "There are no points to enclose.",
+            };
+            self.add_step(self.points.len(), vec![], String::from(description));
+            return;
+        }
+
+        self.disk = Some(self.diametral_disk(self.order[0], self.order[1]));
+        self.add_step(
+            2,
+            vec![],
+            format!(
+                "Shuffle the {} points. The smallest disk of the first two has them as a \
+                 diameter.",
+                self.points.len()
+            ),
+        );
+        self.enclose(2, self.points.len());
+
+        let (center, radius) = self.disk.unwrap();
+        self.add_step(
+            self.points.len(),
+            vec![],
+            format!(
+                "The minimum enclosing disk has center ({:.2}, {:.2}) and radius {radius:.2}. \
+                 The levels tested {}, {} and {} points, linear in expectation since a point \
+                 lies outside with probability at most 3/i.",
+                center.x, center.y, self.tests[0], self.tests[1], self.tests[2]
+            ),
+        );
+    }
+}
+
+impl Algorithm for MinimumEnclosingDisk {
+    fn get_title(&self) -> &str {
+        "Welzl's minimum enclosing disk"
+    }
+
+    fn get_points(&self) -> &Vec<Point2<f64>> {
+        &self.points
+    }
+
+    fn set_points(&mut self, points: Vec<Point2<f64>>) {
+        self.points = points;
+    }
+
+    fn get_steps(&self) -> Vec<Vec<Drawable>> {
+        self.steps.clone()
+    }
+
+    fn get_draw_method(&self) -> DrawMethod {
+        DrawMethod::Edge
+    }
+
+    fn get_step_description(&self, step: usize) -> Option<String> {
+        self.descriptions.get(step).cloned()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::algorithms::testing::random_points;
+
+    fn enclose(points: Vec<Point2<f64>>) -> MinimumEnclosingDisk {
+        let mut minimum_enclosing_disk = MinimumEnclosingDisk::new();
+        minimum_enclosing_disk.set_points(points);
+        minimum_enclosing_disk.calculate();
+        minimum_enclosing_disk
+    }
+
+    /// Returns the radius of the smallest disk through two or three of the
+    /// points that contains all of them.
+    fn brute_force(points: &[Point2<f64>]) -> f64 {
+        let mut candidates = vec![];
+        for (i, a) in points.iter().enumerate() {
+            for (j, b) in points.iter().enumerate().skip(i + 1) {
+                candidates.push((a + (b - a) / 2.0, (b - a).norm() / 2.0));
+                for c in &points[j + 1..] {
+                    candidates.extend(circumcircle(a, b, c));
+                }
+            }
+        }
+        candidates
+            .into_iter()
+            .filter(|(center, radius)| {
+                points
+                    .iter()
+                    .all(|point| (point - center).norm() <= radius + 1e-9)
+            })
+            .map(|(_, radius)| radius)
+            .fold(f64::MAX, f64::min)
+    }
+
+    #[test]
+    fn small_inputs_have_a_point_disk() {
+        for amount in 0..2 {
+            let points = random_points(amount);
+            let expected = points.first().map(|point| (*point, 0.0));
+            assert_eq!(enclose(points).disk, expected);
+        }
+    }
+
+    #[test]
+    fn matches_brute_force() {
+        for amount in 2..30 {
+            let points = random_points(amount);
+            let (center, radius) = enclose(points.clone()).disk.unwrap();
+            assert!((radius - brute_force(&points)).abs() < 1e-6);
+            assert!(points
+                .iter()
+                .all(|point| (point - center).norm() <= radius + 1e-6));
+        }
+    }
+
+    #[test]
+    fn handles_collinear_points() {
+        let line = (0..8)
+            .map(|i| Point2::new(i as f64, 2.0 * i as f64))
+            .collect::<Vec<_>>();
+        let (_, radius) = enclose(line).disk.unwrap();
+        assert!((radius - (49.0f64 + 196.0).sqrt() / 2.0).abs() < 1e-9);
+    }
+}
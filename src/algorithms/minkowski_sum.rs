@@ -0,0 +1,387 @@
+use std::f64::consts::TAU;
+
+use itertools::Itertools;
+use nalgebra::{Point2, Vector2};
+use tui::style::Color;
+
+use super::{
+    algorithm::{Algorithm, DrawMethod, Drawable},
+    geometry::cross_product,
+    graham_scan::GrahamScan,
+};
+
+pub struct MinkowskiSum {
+    // Later passed to app.
+    pub maximum_step_count: usize,
+    pub points: Vec<Point2<f64>>,
+    pub steps: Vec<Vec<Drawable>>,
+    pub descriptions: Vec<String>,
+    // The vertices of the sum in counterclockwise order.
+    pub sum: Vec<Point2<f64>>,
+    // Whether to compute the configuration space obstacle P ⊕ (−R) instead of P ⊕ R.
+    pub configuration_space: bool,
+    obstacle: Vec<Point2<f64>>,
+    robot: Vec<Point2<f64>>,
+    // The robot with its reference point moved to the origin, mirrored at it in
+    // the configuration space.
+    offsets: Vec<Point2<f64>>,
+}
+
+impl MinkowskiSum {
+    pub fn new() -> Self {
+        MinkowskiSum {
+            maximum_step_count: 0,
+            points: vec![],
+            steps: vec![],
+            descriptions: vec![],
+            sum: vec![],
+            configuration_space: false,
+            obstacle: vec![],
+            robot: vec![],
+            offsets: vec![],
+        }
+    }
+
+    /// Stores both polygons and the edges of the sum so far together with the
+    /// given drawables.
+    fn add_step(&mut self, drawables: Vec<Drawable>, description: String) {
+        let mut step = polygon_lines(&self.obstacle, Color::Blue);
+        step.extend(polygon_lines(&self.robot, Color::Cyan));
+        step.extend(
+            self.sum
+                .iter()
+                .tuple_windows()
+                .map(|(from, to)| Drawable::line(from, to, Color::Green)),
+        );
+        step.extend(drawables);
+
+        self.steps.push(step);
+        self.descriptions.push(description);
+        self.maximum_step_count += 1;
+    }
+
+    /// Returns the robot placed with its reference point at the given point.
+    fn placed_robot(&self, reference: &Point2<f64>) -> Vec<Drawable> {
+        polygon_lines(
+            &self
+                .offsets
+                .iter()
+                .map(|offset| reference + offset.coords)
+                .collect::<Vec<_>>(),
+            Color::DarkGray,
+        )
+    }
+
+    pub fn calculate(&mut self) {
+        self.steps = vec![];
+        self.descriptions = vec![];
+        self.maximum_step_count = 0;
+        self.sum = vec![];
+        self.obstacle = vec![];
+        self.robot = vec![];
+
+        // The left half of the points forms the obstacle P, the right half the robot R.
+        let mut points = self.points.clone();
+        points.sort_by(|a, b| (a.x, a.y).partial_cmp(&(b.x, b.y)).unwrap());
+        let robot = points.split_off(points.len() / 2);
+        let halves = vec![
+            Drawable::points(&points, Color::Blue),
+            Drawable::points(&robot, Color::Cyan),
+        ];
+        if points.len() < 3 || robot.len() < 3 {
+            self.add_step(
+                halves,
+                String::from(
+                    "Split the points by x into a left half for P and a right half for R. Both \
+                     halves need at least three points to span a polygon.",
+                ),
+            );
+            return;
+        }
+        self.obstacle = convex_polygon(points);
+        self.robot = convex_polygon(robot);
+        if self.obstacle.len() < 3 || self.robot.len() < 3 {
+            self.obstacle = vec![];
+            self.robot = vec![];
+            self.add_step(
+                halves,
+                String::from(
+                    "Split the points by x into a left half for P and a right half for R. The \
+                     hulls of both halves need at least three vertices, but one half is \
+                     collinear.",
+                ),
+            );
+            return;
+        }
+
+        let reference = Point2::from(
+            self.robot
+                .iter()
+                .fold(Vector2::zeros(), |sum, point| sum + point.coords)
+                / self.robot.len() as f64,
+        );
+        let sign = if self.configuration_space { -1.0 } else { 1.0 };
+        self.offsets = self
+            .robot
+            .iter()
+            .map(|point| Point2::from((point - reference) * sign))
+            .collect();
+        // Negating the robot rotates it by 180°, so its lowest vertex changes.
+        let lowest = [lowest_vertex(&self.obstacle), lowest_vertex(&self.offsets)];
+        self.obstacle.rotate_left(lowest[0]);
+        self.offsets.rotate_left(lowest[1]);
+        let name = if self.configuration_space {
+            "P ⊕ (−R)"
+        } else {
+            "P ⊕ R"
+        };
+        self.add_step(
+            vec![Drawable::points(&[reference], Color::Magenta)],
+            format!(
+                "P is the hull of the left half of the points with {} vertices, the robot R the \
+                 hull of the right half with {} vertices and its centroid as reference point. \
+                 Press m to switch between P ⊕ R and the configuration space obstacle P ⊕ (−R).",
+                self.obstacle.len(),
+                self.robot.len()
+            ),
+        );
+
+        let (length, robot_length) = (self.obstacle.len(), self.offsets.len());
+        let edge = |polygon: &[Point2<f64>], index: usize| {
+            polygon[(index + 1) % polygon.len()] - polygon[index % polygon.len()]
+        };
+        let (mut i, mut j) = (0, 0);
+        self.sum.push(self.obstacle[0] + self.offsets[0].coords);
+        let mut drawables = self.placed_robot(&self.obstacle[0]);
+        drawables.push(Drawable::points(&self.sum, Color::Yellow));
+        self.add_step(
+            drawables,
+            format!(
+                "{name} starts at the sum of the lowest vertices of both polygons. Merge their \
+                 edges by angle, like two sorted lists."
+            ),
+        );
+
+        while i < length || j < robot_length {
+            let obstacle_edge = edge(&self.obstacle, i);
+            let robot_edge = edge(&self.offsets, j);
+            // Exhausted polygons sort last.
+            let angle = |edge: Vector2<f64>, index: usize, length: usize| {
+                if index < length {
+                    edge.y.atan2(edge.x).rem_euclid(TAU)
+                } else {
+                    f64::INFINITY
+                }
+            };
+            let (obstacle_angle, robot_angle) = (
+                angle(obstacle_edge, i, length),
+                angle(robot_edge, j, robot_length),
+            );
+
+            let (vector, highlighted, description) = if (obstacle_angle - robot_angle).abs() < 1e-12
+            {
+                let highlighted = vec![
+                    Drawable::line(
+                        &self.obstacle[i],
+                        &(self.obstacle[i] + obstacle_edge),
+                        Color::Yellow,
+                    ),
+                    Drawable::line(
+                        &(self.obstacle[i] + self.offsets[j].coords),
+                        &(self.obstacle[i] + self.offsets[j].coords + robot_edge),
+                        Color::Yellow,
+                    ),
+                ];
+                i += 1;
+                j += 1;
+                (
+                    obstacle_edge + robot_edge,
+                    highlighted,
+                    String::from("Both polygons have an edge of the same angle. Add them as one."),
+                )
+            } else if obstacle_angle < robot_angle {
+                let highlighted = vec![Drawable::line(
+                    &self.obstacle[i],
+                    &(self.obstacle[i] + obstacle_edge),
+                    Color::Yellow,
+                )];
+                i += 1;
+                (
+                    obstacle_edge,
+                    highlighted,
+                    format!(
+                        "P supplies the next edge at {:.0}°.",
+                        obstacle_angle.to_degrees()
+                    ),
+                )
+            } else {
+                let reference = self.obstacle[i % length];
+                let highlighted = vec![Drawable::line(
+                    &(reference + self.offsets[j].coords),
+                    &(reference + self.offsets[j].coords + robot_edge),
+                    Color::Yellow,
+                )];
+                j += 1;
+                (
+                    robot_edge,
+                    highlighted,
+                    format!(
+                        "{} supplies the next edge at {:.0}°.",
+                        if self.configuration_space {
+                            "−R"
+                        } else {
+                            "R"
+                        },
+                        robot_angle.to_degrees()
+                    ),
+                )
+            };
+
+            let last = *self.sum.last().unwrap();
+            self.sum.push(last + vector);
+            let mut drawables = self.placed_robot(&self.obstacle[i % length]);
+            drawables.extend(highlighted);
+            drawables.push(Drawable::points(&[last + vector], Color::Yellow));
+            self.add_step(drawables, description);
+        }
+        // The last edge closes the polygon at its first vertex.
+        self.sum.pop();
+
+        let mut drawables = polygon_lines(&self.sum, Color::Green);
+        drawables.extend(self.placed_robot(&self.sum[0]));
+        let description = if self.configuration_space {
+            format!(
+                "The configuration space obstacle has {} vertices. R collides with P exactly \
+                 when its reference point lies inside.",
+                self.sum.len()
+            )
+        } else {
+            format!(
+                "The Minkowski sum has {} ≤ {} + {} vertices and took linear time.",
+                self.sum.len(),
+                length,
+                robot_length
+            )
+        };
+        self.add_step(drawables, description);
+    }
+}
+
+/// Returns the hull of the points without collinear vertices.
+fn convex_polygon(points: Vec<Point2<f64>>) -> Vec<Point2<f64>> {
+    let mut graham_scan = GrahamScan::new();
+    graham_scan.set_points(points);
+    graham_scan.calculate();
+    graham_scan
+        .get_hull()
+        .iter()
+        .circular_tuple_windows()
+        .filter(|(previous, current, next)| cross_product(previous, current, next) > 0.0)
+        .map(|(_, current, _)| *current)
+        .collect()
+}
+
+/// Returns the index of the lowest vertex, the leftmost one among ties.
+fn lowest_vertex(polygon: &[Point2<f64>]) -> usize {
+    polygon
+        .iter()
+        .position_min_by(|a, b| (a.y, a.x).partial_cmp(&(b.y, b.x)).unwrap())
+        .unwrap_or(0)
+}
+
+fn polygon_lines(polygon: &[Point2<f64>], color: Color) -> Vec<Drawable> {
+    polygon
+        .iter()
+        .circular_tuple_windows()
+        .map(|(from, to)| Drawable::line(from, to, color))
+        .collect()
+}
+
+impl Algorithm for MinkowskiSum {
+    fn get_title(&self) -> &str {
+        "Minkowski sum"
+    }
+
+    fn get_points(&self) -> &Vec<Point2<f64>> {
+        &self.points
+    }
+
+    fn set_points(&mut self, points: Vec<Point2<f64>>) {
+        self.points = points;
+    }
+
+    fn switch_mode(&mut self) -> bool {
+        self.configuration_space = !self.configuration_space;
+        true
+    }
+
+    fn get_steps(&self) -> Vec<Vec<Drawable>> {
+        self.steps.clone()
+    }
+
+    fn get_draw_method(&self) -> DrawMethod {
+        DrawMethod::Edge
+    }
+
+    fn get_step_description(&self, step: usize) -> Option<String> {
+        self.descriptions.get(step).cloned()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::algorithms::testing::{assert_same_points, brute_force_hull, random_points};
+
+    #[test]
+    fn small_inputs_have_no_sum() {
+        for amount in 0..6 {
+            let mut minkowski_sum = MinkowskiSum::new();
+            minkowski_sum.set_points(random_points(amount));
+            minkowski_sum.calculate();
+            assert!(minkowski_sum.sum.is_empty());
+        }
+    }
+
+    #[test]
+    fn collinear_half_is_rejected() {
+        let mut minkowski_sum = MinkowskiSum::new();
+        let mut points = (0..4)
+            .map(|i| Point2::new(i as f64, i as f64))
+            .collect::<Vec<_>>();
+        points.extend(
+            random_points(4)
+                .iter()
+                .map(|point| point + Vector2::new(10.0, 0.0)),
+        );
+        minkowski_sum.set_points(points);
+        minkowski_sum.calculate();
+        assert!(minkowski_sum.obstacle.is_empty());
+        assert!(minkowski_sum.sum.is_empty());
+    }
+
+    #[test]
+    fn matches_hull_of_pairwise_sums() {
+        for _ in 0..100 {
+            for configuration_space in [false, true] {
+                let mut minkowski_sum = MinkowskiSum::new();
+                minkowski_sum.set_points(random_points(16));
+                minkowski_sum.configuration_space = configuration_space;
+                minkowski_sum.calculate();
+
+                let sums = minkowski_sum
+                    .obstacle
+                    .iter()
+                    .flat_map(|point| {
+                        minkowski_sum
+                            .offsets
+                            .iter()
+                            .map(move |offset| point + offset.coords)
+                    })
+                    .collect::<Vec<_>>();
+                assert_same_points(&minkowski_sum.sum, &brute_force_hull(&sums));
+                assert_eq!(minkowski_sum.steps.len(), minkowski_sum.maximum_step_count);
+            }
+        }
+    }
+}
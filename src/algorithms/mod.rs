@@ -1,2 +1,32 @@
 pub mod algorithm;
+pub mod art_gallery;
+pub mod bentley_ottmann;
+pub mod bowyer_watson;
+pub mod chans_algorithm;
+pub mod closest_pair;
+pub mod dcel;
+pub mod divide_and_conquer_hull;
+pub mod ear_clipping;
+pub mod fortune;
+pub mod geometry;
 pub mod graham_scan;
+pub mod greiner_hormann;
+pub mod jarvis_march;
+pub mod kd_tree;
+pub mod linear_programming;
+pub mod map_overlay;
+pub mod melkman;
+pub mod minimum_enclosing_disk;
+pub mod minkowski_sum;
+pub mod monotone_triangulation;
+pub mod quick_hull;
+pub mod randomized_incremental_delaunay;
+pub mod randomized_incremental_hull;
+pub mod range_tree;
+pub mod rotating_calipers;
+pub mod super_triangle;
+#[cfg(test)]
+mod testing;
+pub mod trapezoidal_map;
+pub mod visibility_graph;
+pub mod voronoi_diagram;
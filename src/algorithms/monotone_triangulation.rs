@@ -0,0 +1,647 @@
+use std::cmp::Ordering;
+
+use itertools::Itertools;
+use nalgebra::Point2;
+use tui::style::Color;
+
+use super::{
+    algorithm::{Algorithm, DrawMethod, Drawable, InputKind},
+    geometry::cross_product,
+};
+
+#[derive(Clone, Copy, PartialEq)]
+enum VertexType {
+    Start,
+    End,
+    Split,
+    Merge,
+    Regular,
+}
+
+impl VertexType {
+    fn describe(&self) -> &str {
+        match self {
+            VertexType::Start => "a start",
+            VertexType::End => "an end",
+            VertexType::Split => "a split",
+            VertexType::Merge => "a merge",
+            VertexType::Regular => "a regular",
+        }
+    }
+
+    fn color(&self) -> Color {
+        match self {
+            VertexType::Start => Color::Green,
+            VertexType::End => Color::Magenta,
+            VertexType::Split => Color::Cyan,
+            VertexType::Merge => Color::LightRed,
+            VertexType::Regular => Color::Gray,
+        }
+    }
+}
+
+pub struct MonotoneTriangulation {
+    // Later passed to app.
+    pub maximum_step_count: usize,
+    pub points: Vec<Point2<f64>>,
+    pub steps: Vec<Vec<Drawable>>,
+    pub descriptions: Vec<String>,
+    types: Vec<VertexType>,
+    // Diagonals splitting the polygon into y-monotone pieces.
+    partition: Vec<(usize, usize)>,
+    // Diagonals triangulating the monotone pieces.
+    diagonals: Vec<(usize, usize)>,
+    // The faces of the final subdivision, all triangles for a simple polygon.
+    pub triangles: Vec<Vec<usize>>,
+    // Edge `i` runs from vertex `i` to vertex `i + 1`. Only edges with the
+    // polygon interior to their right are stored.
+    status: Vec<usize>,
+    helpers: Vec<usize>,
+    x_range: [f64; 2],
+}
+
+impl MonotoneTriangulation {
+    pub fn new() -> Self {
+        MonotoneTriangulation {
+            maximum_step_count: 0,
+            points: vec![],
+            steps: vec![],
+            descriptions: vec![],
+            types: vec![],
+            partition: vec![],
+            diagonals: vec![],
+            triangles: vec![],
+            status: vec![],
+            helpers: vec![],
+            x_range: [0.0, 0.0],
+        }
+    }
+
+    /// Stores the polygon, all diagonals and the vertex types together with the
+    /// given drawables.
+    fn add_step(&mut self, drawables: Vec<Drawable>, description: String) {
+        let mut step = self
+            .points
+            .iter()
+            .circular_tuple_windows()
+            .map(|(from, to)| Drawable::line(from, to, Color::White))
+            .collect::<Vec<_>>();
+        step.extend(
+            self.partition.iter().map(|(from, to)| {
+                Drawable::line(&self.points[*from], &self.points[*to], Color::Blue)
+            }),
+        );
+        step.extend(self.diagonals.iter().map(|(from, to)| {
+            Drawable::line(&self.points[*from], &self.points[*to], Color::LightBlue)
+        }));
+
+        for vertex_type in [
+            VertexType::Start,
+            VertexType::End,
+            VertexType::Split,
+            VertexType::Merge,
+            VertexType::Regular,
+        ] {
+            let points = self
+                .points
+                .iter()
+                .zip(&self.types)
+                .filter(|(_, other)| **other == vertex_type)
+                .map(|(point, _)| *point)
+                .collect::<Vec<_>>();
+            step.push(Drawable::points(&points, vertex_type.color()));
+        }
+        step.extend(drawables);
+
+        self.steps.push(step);
+        self.descriptions.push(description);
+        self.maximum_step_count += 1;
+    }
+
+    /// Stores a step of the sweep with the sweep line, the status edges and
+    /// their helpers.
+    fn add_sweep_step(&mut self, vertex: usize, description: String) {
+        let point = self.points[vertex];
+        let mut drawables = vec![Drawable::line(
+            &Point2::new(self.x_range[0], point.y),
+            &Point2::new(self.x_range[1], point.y),
+            Color::Yellow,
+        )];
+        for edge in self.status.iter() {
+            let (from, to) = self.edge(*edge);
+            drawables.push(Drawable::line(&from, &to, Color::Cyan));
+
+            let helper = self.points[self.helpers[*edge]];
+            let foot = Point2::new(self.x_at(*edge, helper.y), helper.y);
+            drawables.push(Drawable::line(&helper, &foot, Color::DarkGray));
+        }
+        drawables.push(Drawable::points(&[point], Color::Yellow));
+
+        let status = self
+            .status
+            .iter()
+            .map(|edge| format!("e{edge} (helper v{})", self.helpers[*edge]))
+            .join(", ");
+        self.add_step(
+            drawables,
+            format!("Phase 1: {description} Status: [{status}]."),
+        );
+    }
+
+    /// Checks whether the first vertex comes before the second in the sweep
+    /// from top to bottom.
+    fn is_above(&self, a: usize, b: usize) -> bool {
+        let (a, b) = (self.points[a], self.points[b]);
+        a.y > b.y || (a.y == b.y && a.x < b.x)
+    }
+
+    /// Orders the vertices from top to bottom, breaking ties from left to right
+    /// like `is_above`.
+    fn sweep_order(&self, a: usize, b: usize) -> Ordering {
+        let (a, b) = (self.points[a], self.points[b]);
+        b.y.partial_cmp(&a.y)
+            .unwrap()
+            .then(a.x.partial_cmp(&b.x).unwrap())
+    }
+
+    fn edge(&self, edge: usize) -> (Point2<f64>, Point2<f64>) {
+        (
+            self.points[edge],
+            self.points[(edge + 1) % self.points.len()],
+        )
+    }
+
+    fn x_at(&self, edge: usize, y: f64) -> f64 {
+        let (from, to) = self.edge(edge);
+        if from.y == to.y {
+            return from.x.min(to.x);
+        }
+        let t = (y - from.y) / (to.y - from.y);
+        from.x + t * (to.x - from.x)
+    }
+
+    fn classify(&self, vertex: usize) -> VertexType {
+        let length = self.points.len();
+        let previous = (vertex + length - 1) % length;
+        let next = (vertex + 1) % length;
+        let convex = cross_product(
+            &self.points[previous],
+            &self.points[vertex],
+            &self.points[next],
+        ) > 0.0;
+
+        match (
+            self.is_above(vertex, previous),
+            self.is_above(vertex, next),
+            convex,
+        ) {
+            (true, true, true) => VertexType::Start,
+            (true, true, false) => VertexType::Split,
+            (false, false, true) => VertexType::End,
+            (false, false, false) => VertexType::Merge,
+            _ => VertexType::Regular,
+        }
+    }
+
+    /// Returns the status edge directly left of the vertex.
+    fn left_edge(&self, vertex: usize) -> Option<usize> {
+        let point = self.points[vertex];
+        self.status
+            .iter()
+            .map(|edge| (*edge, self.x_at(*edge, point.y)))
+            .filter(|(_, x)| *x < point.x)
+            .max_by(|a, b| a.1.partial_cmp(&b.1).unwrap())
+            .map(|(edge, _)| edge)
+    }
+
+    /// Connects the vertex to the helper of the edge, if that helper is a merge vertex.
+    fn connect_merge_helper(&mut self, vertex: usize, edge: usize) -> Option<usize> {
+        let helper = self.helpers[edge];
+        if self.types[helper] == VertexType::Merge {
+            self.partition.push((vertex, helper));
+            return Some(helper);
+        }
+        None
+    }
+
+    fn remove_edge(&mut self, edge: usize) {
+        self.status.retain(|other| *other != edge);
+    }
+
+    fn insert_edge(&mut self, edge: usize, helper: usize) {
+        self.status.push(edge);
+        self.helpers[edge] = helper;
+    }
+
+    fn handle_vertex(&mut self, vertex: usize) -> String {
+        let length = self.points.len();
+        let previous = (vertex + length - 1) % length;
+        let mut description = format!("v{vertex} is {} vertex.", self.types[vertex].describe());
+        let mut connected = vec![];
+
+        match self.types[vertex] {
+            VertexType::Start => {
+                self.insert_edge(vertex, vertex);
+                description += &format!(" Insert e{vertex}.");
+            }
+            VertexType::End => {
+                connected.extend(self.connect_merge_helper(vertex, previous));
+                self.remove_edge(previous);
+                description += &format!(" Remove e{previous}.");
+            }
+            VertexType::Split => {
+                if let Some(left) = self.left_edge(vertex) {
+                    connected.push(self.helpers[left]);
+                    self.partition.push((vertex, self.helpers[left]));
+                    self.helpers[left] = vertex;
+                    description += &format!(" Connect it to the helper of e{left}.");
+                }
+                self.insert_edge(vertex, vertex);
+                description += &format!(" Insert e{vertex}.");
+            }
+            VertexType::Merge => {
+                connected.extend(self.connect_merge_helper(vertex, previous));
+                self.remove_edge(previous);
+                description += &format!(" Remove e{previous}.");
+                if let Some(left) = self.left_edge(vertex) {
+                    connected.extend(self.connect_merge_helper(vertex, left));
+                    self.helpers[left] = vertex;
+                    description += &format!(" It becomes the helper of e{left}.");
+                }
+            }
+            VertexType::Regular => {
+                // The interior lies right of the vertex if the boundary runs downwards.
+                if self.is_above(previous, vertex) {
+                    connected.extend(self.connect_merge_helper(vertex, previous));
+                    self.remove_edge(previous);
+                    self.insert_edge(vertex, vertex);
+                    description += &format!(" Replace e{previous} by e{vertex}.");
+                } else if let Some(left) = self.left_edge(vertex) {
+                    connected.extend(self.connect_merge_helper(vertex, left));
+                    self.helpers[left] = vertex;
+                    description += &format!(" It becomes the helper of e{left}.");
+                }
+            }
+        }
+
+        for helper in connected {
+            description += &format!(" Add the diagonal v{vertex} - v{helper}.");
+        }
+        description
+    }
+
+    /// Splits the polygon along the given diagonals into counterclockwise pieces.
+    fn pieces(&self, diagonals: &[(usize, usize)]) -> Vec<Vec<usize>> {
+        let length = self.points.len();
+        let mut neighbours = (0..length)
+            .map(|vertex| vec![(vertex + length - 1) % length, (vertex + 1) % length])
+            .collect::<Vec<_>>();
+        for (from, to) in diagonals {
+            neighbours[*from].push(*to);
+            neighbours[*to].push(*from);
+        }
+        for (vertex, adjacent) in neighbours.iter_mut().enumerate() {
+            let origin = self.points[vertex];
+            adjacent.sort_by(|a, b| {
+                let (a, b) = (self.points[*a] - origin, self.points[*b] - origin);
+                a.y.atan2(a.x).partial_cmp(&b.y.atan2(b.x)).unwrap()
+            });
+        }
+
+        // Every piece lies left of its boundary edges. The outer face lies right of
+        // the polygon edges, so only their counterclockwise direction starts a piece.
+        let mut half_edges = (0..length)
+            .map(|vertex| (vertex, (vertex + 1) % length))
+            .collect::<Vec<_>>();
+        for (from, to) in diagonals {
+            half_edges.push((*from, *to));
+            half_edges.push((*to, *from));
+        }
+
+        let mut visited = vec![];
+        let mut pieces = vec![];
+        for half_edge in half_edges {
+            if visited.contains(&half_edge) {
+                continue;
+            }
+            let mut piece = vec![];
+            let (mut from, mut to) = half_edge;
+            while !visited.contains(&(from, to)) {
+                visited.push((from, to));
+                piece.push(from);
+
+                // The next edge is the clockwise neighbour of the reversed edge.
+                let adjacent = &neighbours[to];
+                let index = adjacent.iter().position(|other| *other == from).unwrap();
+                let next = adjacent[(index + adjacent.len() - 1) % adjacent.len()];
+                (from, to) = (to, next);
+            }
+            pieces.push(piece);
+        }
+        pieces
+    }
+
+    /// Stores a step of the stack-based triangulation of a monotone piece.
+    fn add_piece_step(
+        &mut self,
+        piece: &[usize],
+        stack: &[usize],
+        vertex: usize,
+        added: &[(usize, usize)],
+        description: String,
+    ) {
+        let mut drawables = piece
+            .iter()
+            .circular_tuple_windows()
+            .map(|(from, to)| Drawable::line(&self.points[*from], &self.points[*to], Color::Yellow))
+            .collect::<Vec<_>>();
+        drawables.extend(added.iter().map(|(from, to)| {
+            Drawable::line(&self.points[*from], &self.points[*to], Color::Green)
+        }));
+        drawables.push(Drawable::points(
+            &stack
+                .iter()
+                .map(|index| self.points[*index])
+                .collect::<Vec<_>>(),
+            Color::Yellow,
+        ));
+        drawables.push(Drawable::points(&[self.points[vertex]], Color::Magenta));
+
+        let stack = stack.iter().map(|index| format!("v{index}")).join(", ");
+        self.add_step(drawables, format!("{description} Stack: [{stack}]."));
+    }
+
+    /// Triangulates a counterclockwise y-monotone piece from top to bottom.
+    fn triangulate_piece(&mut self, piece: &[usize], label: &str) {
+        let length = piece.len();
+        let top = (0..length)
+            .find(|index| (0..length).all(|other| !self.is_above(piece[other], piece[*index])))
+            .unwrap();
+        let bottom = (0..length)
+            .find(|index| (0..length).all(|other| !self.is_above(piece[*index], piece[other])))
+            .unwrap();
+
+        // Walking counterclockwise from the top vertex leads down the left chain.
+        let mut is_left = vec![false; self.points.len()];
+        let mut index = top;
+        while index != bottom {
+            is_left[piece[index]] = true;
+            index = (index + 1) % length;
+        }
+        let chain = |vertex: usize| if is_left[vertex] { "left" } else { "right" };
+
+        let mut sorted = piece.to_vec();
+        sorted.sort_by(|a, b| self.sweep_order(*a, *b));
+
+        let mut stack = vec![sorted[0], sorted[1]];
+        self.add_piece_step(
+            piece,
+            &stack,
+            sorted[1],
+            &[],
+            format!("Phase 2, {label}: push the two highest vertices."),
+        );
+
+        for vertex in sorted[2..length - 1].iter().copied() {
+            let top_of_stack = *stack.last().unwrap();
+            let mut added = vec![];
+            let description;
+
+            if is_left[vertex] != is_left[top_of_stack] {
+                // Every stacked vertex except the lowest one can be connected.
+                let popped = std::mem::take(&mut stack);
+                for other in popped[1..].iter() {
+                    added.push((vertex, *other));
+                }
+                stack = vec![top_of_stack, vertex];
+                description = format!(
+                    "Phase 2, {label}: v{vertex} lies on the {} chain, opposite to the stack \
+                     top. Connect it to {} stacked vertices.",
+                    chain(vertex),
+                    added.len()
+                );
+            } else {
+                let mut last = stack.pop().unwrap();
+                while let Some(&other) = stack.last() {
+                    // The diagonal lies inside if the skipped vertex is convex.
+                    let convex = if is_left[vertex] {
+                        cross_product(
+                            &self.points[other],
+                            &self.points[last],
+                            &self.points[vertex],
+                        )
+                    } else {
+                        cross_product(
+                            &self.points[vertex],
+                            &self.points[last],
+                            &self.points[other],
+                        )
+                    } > 0.0;
+                    if !convex {
+                        break;
+                    }
+                    added.push((vertex, other));
+                    last = stack.pop().unwrap();
+                }
+                stack.push(last);
+                stack.push(vertex);
+                description = format!(
+                    "Phase 2, {label}: v{vertex} lies on the {} chain, like the stack top. \
+                     Connect it to {} visible stacked vertices.",
+                    chain(vertex),
+                    added.len()
+                );
+            }
+
+            self.diagonals.extend(added.iter().copied());
+            self.add_piece_step(piece, &stack, vertex, &added, description);
+        }
+
+        // The lowest vertex sees all stacked vertices except the first and last one.
+        let vertex = sorted[length - 1];
+        let added = if stack.len() > 2 {
+            stack[1..stack.len() - 1]
+                .iter()
+                .map(|other| (vertex, *other))
+                .collect::<Vec<_>>()
+        } else {
+            vec![]
+        };
+        self.diagonals.extend(added.iter().copied());
+        self.add_piece_step(
+            piece,
+            &[],
+            vertex,
+            &added,
+            format!(
+                "Phase 2, {label}: the lowest vertex v{vertex} is connected to {} stacked \
+                 vertices.",
+                added.len()
+            ),
+        );
+    }
+
+    pub fn calculate(&mut self) {
+        self.steps = vec![];
+        self.descriptions = vec![];
+        self.maximum_step_count = 0;
+        self.types = vec![];
+        self.partition = vec![];
+        self.diagonals = vec![];
+        self.triangles = vec![];
+        self.status = vec![];
+        self.helpers = vec![0; self.points.len()];
+
+        if self.points.len() < 3 {
+            self.add_step(
+                vec![],
+                String::from("A polygon needs at least three vertices."),
+            );
+            return;
+        }
+
+        self.x_range = self
+            .points
+            .iter()
+            .fold([f64::MAX, f64::MIN], |range, point| {
+                [range[0].min(point.x), range[1].max(point.x)]
+            });
+        self.types = (0..self.points.len())
+            .map(|vertex| self.classify(vertex))
+            .collect();
+        self.add_step(
+            vec![],
+            String::from(
+                "Phase 1: classify the vertices as start (green), end (magenta), split (cyan), \
+                 merge (light red) or regular (gray).",
+            ),
+        );
+
+        let mut order = (0..self.points.len()).collect::<Vec<_>>();
+        order.sort_by(|a, b| self.sweep_order(*a, *b));
+        for vertex in order {
+            let description = self.handle_vertex(vertex);
+            self.add_sweep_step(vertex, description);
+        }
+
+        let pieces = self.pieces(&self.partition);
+        self.add_step(
+            vec![],
+            format!(
+                "Phase 1 done: {} diagonals split the polygon into {} y-monotone pieces.",
+                self.partition.len(),
+                pieces.len()
+            ),
+        );
+
+        for (index, piece) in pieces.iter().enumerate() {
+            let label = format!("piece {} of {}", index + 1, pieces.len());
+            self.triangulate_piece(piece, &label);
+        }
+
+        self.triangles = self.pieces(&[self.partition.clone(), self.diagonals.clone()].concat());
+        self.add_step(
+            vec![],
+            format!(
+                "The polygon is split into {} triangles by {} diagonals.",
+                self.triangles.len(),
+                self.partition.len() + self.diagonals.len()
+            ),
+        );
+    }
+}
+
+impl Algorithm for MonotoneTriangulation {
+    fn get_title(&self) -> &str {
+        "Monotone triangulation"
+    }
+
+    fn get_points(&self) -> &Vec<Point2<f64>> {
+        &self.points
+    }
+
+    fn set_points(&mut self, points: Vec<Point2<f64>>) {
+        self.points = points;
+    }
+
+    fn get_input_kind(&self) -> InputKind {
+        InputKind::SimplePolygon
+    }
+
+    fn get_steps(&self) -> Vec<Vec<Drawable>> {
+        self.steps.clone()
+    }
+
+    fn get_draw_method(&self) -> DrawMethod {
+        DrawMethod::Edge
+    }
+
+    fn get_step_description(&self, step: usize) -> Option<String> {
+        self.descriptions.get(step).cloned()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::algorithms::testing::{random_points, random_star_polygon};
+
+    fn triangulate(points: Vec<Point2<f64>>) -> MonotoneTriangulation {
+        let mut monotone_triangulation = MonotoneTriangulation::new();
+        monotone_triangulation.set_points(points);
+        monotone_triangulation.calculate();
+        monotone_triangulation
+    }
+
+    fn assert_triangulates(points: &[Point2<f64>]) {
+        let monotone_triangulation = triangulate(points.to_vec());
+        let triangles = &monotone_triangulation.triangles;
+        assert_eq!(triangles.len(), points.len() - 2);
+
+        let area =
+            |a: usize, b: usize, c: usize| cross_product(&points[a], &points[b], &points[c]) / 2.0;
+        let polygon_area = (1..points.len() - 1)
+            .map(|index| area(0, index, index + 1))
+            .sum::<f64>();
+        let mut triangle_area = 0.0;
+        for triangle in triangles {
+            assert_eq!(triangle.len(), 3);
+            assert!(area(triangle[0], triangle[1], triangle[2]) > 0.0);
+            triangle_area += area(triangle[0], triangle[1], triangle[2]);
+        }
+        assert!((polygon_area - triangle_area).abs() < 1e-6);
+    }
+
+    #[test]
+    fn small_polygons_have_no_triangles() {
+        for amount in 0..3 {
+            let monotone_triangulation = triangulate(random_points(amount));
+            assert!(monotone_triangulation.triangles.is_empty());
+        }
+    }
+
+    #[test]
+    fn triangulates_star_polygons() {
+        for amount in 3..40 {
+            assert_triangulates(&random_star_polygon(amount, Point2::new(50.0, 50.0), 50.0));
+        }
+    }
+
+    #[test]
+    fn triangulates_vertices_of_equal_height() {
+        let staircase = [
+            (0.0, 0.0),
+            (4.0, 0.0),
+            (4.0, 1.0),
+            (3.0, 1.0),
+            (3.0, 2.0),
+            (2.0, 2.0),
+            (2.0, 1.0),
+            (1.0, 1.0),
+            (1.0, 2.0),
+            (0.0, 2.0),
+        ]
+        .map(|(x, y)| Point2::new(x, y));
+        assert_triangulates(&staircase);
+    }
+}
@@ -0,0 +1,223 @@
+use nalgebra::Point2;
+use tui::style::Color;
+
+use super::{
+    algorithm::{Algorithm, DrawMethod, Drawable},
+    geometry::cross_product,
+};
+
+/// A subproblem of the recursion. Contains all points lying strictly
+/// left of the dividing line `from -> to`.
+struct Subproblem {
+    from: Point2<f64>,
+    to: Point2<f64>,
+    points: Vec<Point2<f64>>,
+}
+
+pub struct QuickHull {
+    // Later passed to app.
+    pub maximum_step_count: usize,
+    pub points: Vec<Point2<f64>>,
+    pub steps: Vec<Vec<Drawable>>,
+    hull_edges: Vec<(Point2<f64>, Point2<f64>)>,
+    discarded: Vec<Point2<f64>>,
+}
+
+impl QuickHull {
+    pub fn new() -> Self {
+        QuickHull {
+            maximum_step_count: 0,
+            points: vec![],
+            steps: vec![],
+            hull_edges: vec![],
+            discarded: vec![],
+        }
+    }
+
+    /// Stores a step consisting of the confirmed hull edges, the discarded points
+    /// and the pending subproblems on the stack. The current subproblem, its
+    /// farthest point and the triangle spanned by both are optional.
+    fn add_step(
+        &mut self,
+        stack: &[Subproblem],
+        current: Option<&Subproblem>,
+        farthest: Option<&Point2<f64>>,
+    ) {
+        let mut step = self
+            .hull_edges
+            .iter()
+            .map(|(from, to)| Drawable::line(from, to, Color::Blue))
+            .collect::<Vec<_>>();
+
+        for subproblem in stack {
+            step.push(Drawable::line(
+                &subproblem.from,
+                &subproblem.to,
+                Color::Cyan,
+            ));
+        }
+
+        if let Some(current) = current {
+            step.push(Drawable::line(&current.from, &current.to, Color::Yellow));
+            step.push(Drawable::points(&current.points, Color::Green));
+
+            if let Some(farthest) = farthest {
+                step.push(Drawable::line(&current.from, farthest, Color::Gray));
+                step.push(Drawable::line(farthest, &current.to, Color::Gray));
+                step.push(Drawable::points(&[*farthest], Color::Magenta));
+            }
+        }
+        step.push(Drawable::points(&self.discarded, Color::DarkGray));
+
+        self.steps.push(step);
+        self.maximum_step_count += 1;
+    }
+
+    pub fn calculate(&mut self) {
+        self.steps = vec![];
+        self.maximum_step_count = 0;
+        self.hull_edges = vec![];
+        self.discarded = vec![];
+
+        let mut points = self.points.clone();
+
+        // Sort points lexicographically to find the leftmost and rightmost point.
+        points.sort_by(|a, b| (a.x, a.y).partial_cmp(&(b.x, b.y)).unwrap());
+
+        let (left, right) = match (points.first(), points.last()) {
+            (Some(left), Some(right)) => (*left, *right),
+            _ => {
+                self.add_step(&[], None, None);
+                return;
+            }
+        };
+
+        // Split the point set by the line through the leftmost and rightmost point.
+        let mut stack = vec![
+            Subproblem {
+                from: right,
+                to: left,
+                points: points_left_of(&right, &left, &points),
+            },
+            Subproblem {
+                from: left,
+                to: right,
+                points: points_left_of(&left, &right, &points),
+            },
+        ];
+        self.add_step(&stack, None, None);
+
+        while let Some(current) = stack.pop() {
+            // A subproblem without points is an edge of the hull.
+            if current.points.is_empty() {
+                self.hull_edges.push((current.from, current.to));
+                self.add_step(&stack, None, None);
+                continue;
+            }
+            self.add_step(&stack, Some(&current), None);
+
+            let farthest = *current
+                .points
+                .iter()
+                .max_by(|a, b| {
+                    cross_product(&current.from, &current.to, a)
+                        .partial_cmp(&cross_product(&current.from, &current.to, b))
+                        .unwrap()
+                })
+                .unwrap();
+
+            let first = Subproblem {
+                from: current.from,
+                to: farthest,
+                points: points_left_of(&current.from, &farthest, &current.points),
+            };
+            let second = Subproblem {
+                from: farthest,
+                to: current.to,
+                points: points_left_of(&farthest, &current.to, &current.points),
+            };
+
+            // Everything not left of the two new dividing lines lies inside the triangle.
+            self.discarded.extend(current.points.iter().filter(|point| {
+                **point != farthest
+                    && !first.points.contains(point)
+                    && !second.points.contains(point)
+            }));
+            self.add_step(&stack, Some(&current), Some(&farthest));
+
+            // Push the second subproblem first so the first one is processed next.
+            stack.push(second);
+            stack.push(first);
+        }
+    }
+}
+
+fn points_left_of(
+    from: &Point2<f64>,
+    to: &Point2<f64>,
+    points: &[Point2<f64>],
+) -> Vec<Point2<f64>> {
+    points
+        .iter()
+        .filter(|point| cross_product(from, to, point) > 0.0)
+        .copied()
+        .collect()
+}
+
+impl Algorithm for QuickHull {
+    fn get_title(&self) -> &str {
+        "QuickHull"
+    }
+
+    fn get_points(&self) -> &Vec<Point2<f64>> {
+        &self.points
+    }
+
+    fn set_points(&mut self, points: Vec<Point2<f64>>) {
+        self.points = points;
+    }
+
+    fn get_steps(&self) -> Vec<Vec<Drawable>> {
+        self.steps.clone()
+    }
+
+    fn get_draw_method(&self) -> DrawMethod {
+        DrawMethod::Edge
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::algorithms::testing::{assert_same_points, brute_force_hull, random_points};
+
+    /// Returns the hull vertices as the starts of the confirmed hull edges.
+    fn hull(points: Vec<Point2<f64>>) -> Vec<Point2<f64>> {
+        let mut quick_hull = QuickHull::new();
+        quick_hull.set_points(points);
+        quick_hull.calculate();
+        let mut hull = vec![];
+        for (from, _) in quick_hull.hull_edges {
+            if !hull.contains(&from) {
+                hull.push(from);
+            }
+        }
+        hull
+    }
+
+    #[test]
+    fn fewer_than_three_points_are_their_own_hull() {
+        for amount in 0..3 {
+            let points = random_points(amount);
+            assert_same_points(&hull(points.clone()), &points);
+        }
+    }
+
+    #[test]
+    fn matches_brute_force_hull() {
+        for amount in 3..40 {
+            let points = random_points(amount);
+            assert_same_points(&hull(points.clone()), &brute_force_hull(&points));
+        }
+    }
+}
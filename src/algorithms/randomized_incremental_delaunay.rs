@@ -0,0 +1,443 @@
+use std::collections::HashMap;
+
+use itertools::Itertools;
+use nalgebra::Point2;
+use rand::seq::SliceRandom;
+use tui::style::Color;
+
+use super::{
+    algorithm::{Algorithm, DrawMethod, Drawable},
+    super_triangle::SuperTriangle,
+};
+
+/// A triangle of the history DAG. Leaves form the current triangulation,
+/// inner nodes were split or flipped into their children.
+struct Node {
+    triangle: [usize; 3],
+    children: Vec<usize>,
+}
+
+pub struct RandomizedIncrementalDelaunay {
+    // Later passed to app.
+    pub maximum_step_count: usize,
+    pub points: Vec<Point2<f64>>,
+    pub steps: Vec<Vec<Drawable>>,
+    pub descriptions: Vec<String>,
+    // Counterclockwise triangles of the final triangulation.
+    pub triangles: Vec<[usize; 3]>,
+    // Number of flips of every insertion in the last run.
+    pub flips: Vec<usize>,
+    nodes: Vec<Node>,
+    // Maps every directed edge of the current triangulation to its leaf.
+    edges: HashMap<(usize, usize), usize>,
+    // Statistics over all runs.
+    runs: usize,
+    total_insertions: usize,
+    total_flips: usize,
+}
+
+impl RandomizedIncrementalDelaunay {
+    pub fn new() -> Self {
+        RandomizedIncrementalDelaunay {
+            maximum_step_count: 0,
+            points: vec![],
+            steps: vec![],
+            descriptions: vec![],
+            triangles: vec![],
+            flips: vec![],
+            nodes: vec![],
+            edges: HashMap::new(),
+            runs: 0,
+            total_insertions: 0,
+            total_flips: 0,
+        }
+    }
+
+    /// Stores the current triangulation together with the given drawables.
+    /// Edges leading to the super-triangle are drawn as gray rays.
+    fn add_step(&mut self, drawables: Vec<Drawable>, description: String) {
+        let super_triangle = SuperTriangle::new(&self.points);
+        let mut step = self
+            .nodes
+            .iter()
+            .filter(|node| node.children.is_empty())
+            .flat_map(|node| {
+                let color = if node
+                    .triangle
+                    .iter()
+                    .any(|vertex| super_triangle.is_infinite(*vertex))
+                {
+                    Color::DarkGray
+                } else {
+                    Color::Blue
+                };
+                super_triangle.triangle_lines(&node.triangle, color)
+            })
+            .collect::<Vec<_>>();
+        step.extend(drawables);
+
+        self.steps.push(step);
+        self.descriptions.push(description);
+        self.maximum_step_count += 1;
+    }
+
+    /// Adds a leaf to the history DAG and returns its index.
+    fn add_node(&mut self, triangle: [usize; 3]) -> usize {
+        let index = self.nodes.len();
+        for (from, to) in triangle.iter().circular_tuple_windows() {
+            self.edges.insert((*from, *to), index);
+        }
+        self.nodes.push(Node {
+            triangle,
+            children: vec![],
+        });
+        index
+    }
+
+    /// Walks down the history DAG to the leaf containing the point and returns
+    /// all visited nodes.
+    fn locate(&self, point: &Point2<f64>) -> Vec<usize> {
+        let super_triangle = SuperTriangle::new(&self.points);
+        let mut path = vec![0];
+        let mut node = 0;
+        while !self.nodes[node].children.is_empty() {
+            let children = &self.nodes[node].children;
+            // The children cover their parent, boundaries included.
+            node = *children
+                .iter()
+                .find(|child| super_triangle.in_triangle(&self.nodes[**child].triangle, point))
+                .expect("no child of a located triangle contains the point");
+            path.push(node);
+        }
+        path
+    }
+
+    pub fn calculate(&mut self) {
+        self.steps = vec![];
+        self.descriptions = vec![];
+        self.maximum_step_count = 0;
+        self.triangles = vec![];
+        self.flips = vec![];
+        self.nodes = vec![];
+        self.edges = HashMap::new();
+
+        let points = self.points.clone();
+        let super_triangle = SuperTriangle::new(&points);
+        self.add_node(super_triangle.vertices());
+        self.add_step(
+            vec![],
+            String::from(
+                "Start with a super-triangle containing all points. Its vertices lie \
+                 infinitely far up, lower left and lower right. It is the root of the \
+                 history DAG.",
+            ),
+        );
+
+        let mut order = (0..points.len()).collect::<Vec<_>>();
+        order.shuffle(&mut rand::thread_rng());
+
+        for (insertion, point) in order.into_iter().enumerate() {
+            let position = points[point];
+            let label = format!("Insertion {} of {}", insertion + 1, points.len());
+
+            let path = self.locate(&position);
+            let leaf = *path.last().unwrap();
+            let [a, b, c] = self.nodes[leaf].triangle;
+            let mut drawables = path[..path.len() - 1]
+                .iter()
+                .flat_map(|node| {
+                    super_triangle.triangle_lines(&self.nodes[*node].triangle, Color::Cyan)
+                })
+                .collect::<Vec<_>>();
+            drawables.extend(super_triangle.triangle_lines(&[a, b, c], Color::Yellow));
+            drawables.push(Drawable::points(&[position], Color::Yellow));
+            self.add_step(
+                drawables,
+                format!(
+                    "{label}: locate v{point} by visiting {} of the {} nodes in the history \
+                     DAG.",
+                    path.len(),
+                    self.nodes.len()
+                ),
+            );
+
+            // A point on an edge splits both triangles sharing it into two each.
+            let edge = [(a, b, c), (b, c, a), (c, a, b)]
+                .into_iter()
+                .find(|(from, to, _)| super_triangle.on_edge(*from, *to, &position));
+            let (triangles, description) = match edge {
+                Some((from, to, own)) => {
+                    let opposite = *self
+                        .edges
+                        .get(&(to, from))
+                        .expect("an edge inside the super-triangle has no opposite triangle");
+                    let far = *self.nodes[opposite]
+                        .triangle
+                        .iter()
+                        .find(|vertex| **vertex != from && **vertex != to)
+                        .unwrap();
+                    self.edges.remove(&(from, to));
+                    self.edges.remove(&(to, from));
+                    let triangles = vec![
+                        [to, own, point],
+                        [own, from, point],
+                        [from, far, point],
+                        [far, to, point],
+                    ];
+                    let children = triangles
+                        .iter()
+                        .map(|triangle| self.add_node(*triangle))
+                        .collect::<Vec<_>>();
+                    self.nodes[leaf].children = children[..2].to_vec();
+                    self.nodes[opposite].children = children[2..].to_vec();
+                    (
+                        triangles,
+                        format!(
+                            "{label}: v{point} lies on the edge {} - {}, split both triangles \
+                             sharing it into four.",
+                            super_triangle.label(from),
+                            super_triangle.label(to)
+                        ),
+                    )
+                }
+                None => {
+                    let triangles = vec![[a, b, point], [b, c, point], [c, a, point]];
+                    self.nodes[leaf].children = triangles
+                        .iter()
+                        .map(|triangle| self.add_node(*triangle))
+                        .collect();
+                    (
+                        triangles,
+                        format!("{label}: split the triangle into three."),
+                    )
+                }
+            };
+            let mut drawables = triangles
+                .iter()
+                .flat_map(|triangle| super_triangle.triangle_lines(triangle, Color::Green))
+                .collect::<Vec<_>>();
+            drawables.push(Drawable::points(&[position], Color::Yellow));
+            self.add_step(drawables, description);
+
+            // Edges opposite the new point may be illegal. They are checked in the
+            // order of a recursive legalization.
+            let mut flips = 0;
+            let mut unchecked = triangles
+                .iter()
+                .rev()
+                .map(|[from, to, _]| (*from, *to))
+                .collect::<Vec<_>>();
+            while let Some((from, to)) = unchecked.pop() {
+                let opposite = match self.edges.get(&(to, from)) {
+                    Some(opposite) => *opposite,
+                    None => continue,
+                };
+                let opposite_triangle = self.nodes[opposite].triangle;
+                if !super_triangle.in_circumcircle(&opposite_triangle, &position) {
+                    continue;
+                }
+
+                flips += 1;
+                let mut drawables = super_triangle
+                    .edge_line(from, to, Color::LightRed)
+                    .into_iter()
+                    .collect::<Vec<_>>();
+                drawables.extend(super_triangle.circumcircle(&opposite_triangle, Color::Magenta));
+                drawables.push(Drawable::points(&[position], Color::Yellow));
+                self.add_step(
+                    drawables,
+                    format!(
+                        "{label}: flip {flips}. The edge {} - {} is illegal, v{point} lies in \
+                         the circumcircle of the opposite triangle.",
+                        super_triangle.label(from),
+                        super_triangle.label(to)
+                    ),
+                );
+
+                // Replace both triangles by the ones sharing the flipped edge.
+                let far = *opposite_triangle
+                    .iter()
+                    .find(|vertex| **vertex != from && **vertex != to)
+                    .unwrap();
+                let own = self.edges[&(from, to)];
+                self.edges.remove(&(from, to));
+                self.edges.remove(&(to, from));
+                let children = vec![
+                    self.add_node([from, far, point]),
+                    self.add_node([far, to, point]),
+                ];
+                self.nodes[own].children = children.clone();
+                self.nodes[opposite].children = children;
+                unchecked.push((far, to));
+                unchecked.push((from, far));
+            }
+
+            let drawables = self
+                .edges
+                .keys()
+                .filter(|(from, _)| *from == point)
+                .filter_map(|(from, to)| super_triangle.edge_line(*from, *to, Color::Green))
+                .chain([Drawable::points(&[position], Color::Yellow)])
+                .collect();
+            self.add_step(
+                drawables,
+                format!("{label}: all edges are legal again after {flips} flips."),
+            );
+            self.flips.push(flips);
+        }
+
+        self.triangles = self
+            .nodes
+            .iter()
+            .filter(|node| node.children.is_empty())
+            .map(|node| node.triangle)
+            .filter(|triangle| {
+                triangle
+                    .iter()
+                    .all(|vertex| !super_triangle.is_infinite(*vertex))
+            })
+            .collect();
+
+        let flips = self.flips.iter().sum::<usize>();
+        self.runs += 1;
+        self.total_insertions += points.len();
+        self.total_flips += flips;
+        let average = |flips: usize, insertions: usize| flips as f64 / insertions.max(1) as f64;
+        self.add_step(
+            vec![],
+            format!(
+                "The Delaunay triangulation has {} triangles and {} DAG nodes. This run needed \
+                 {flips} flips, {:.2} per insertion. Over {} runs: {:.2} flips per insertion.",
+                self.triangles.len(),
+                self.nodes.len(),
+                average(flips, points.len()),
+                self.runs,
+                average(self.total_flips, self.total_insertions)
+            ),
+        );
+    }
+}
+
+impl Algorithm for RandomizedIncrementalDelaunay {
+    fn get_title(&self) -> &str {
+        "Randomized incremental Delaunay"
+    }
+
+    fn get_points(&self) -> &Vec<Point2<f64>> {
+        &self.points
+    }
+
+    fn set_points(&mut self, points: Vec<Point2<f64>>) {
+        self.points = points;
+    }
+
+    fn get_steps(&self) -> Vec<Vec<Drawable>> {
+        self.steps.clone()
+    }
+
+    fn get_draw_method(&self) -> DrawMethod {
+        DrawMethod::Edge
+    }
+
+    fn get_step_description(&self, step: usize) -> Option<String> {
+        self.descriptions.get(step).cloned()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::algorithms::{
+        bowyer_watson::BowyerWatson,
+        geometry::{cross_product, in_circle},
+        testing::{brute_force_hull, random_points},
+    };
+
+    fn randomized_incremental_delaunay(points: Vec<Point2<f64>>) -> RandomizedIncrementalDelaunay {
+        let mut randomized_incremental_delaunay = RandomizedIncrementalDelaunay::new();
+        randomized_incremental_delaunay.set_points(points);
+        randomized_incremental_delaunay.calculate();
+        randomized_incremental_delaunay
+    }
+
+    /// Rotates every triangle to start at its smallest index and sorts them.
+    fn normalized(triangles: &[[usize; 3]]) -> Vec<[usize; 3]> {
+        triangles
+            .iter()
+            .map(|triangle| {
+                let start = (0..3).min_by_key(|index| triangle[*index]).unwrap();
+                [0, 1, 2].map(|offset| triangle[(start + offset) % 3])
+            })
+            .sorted()
+            .collect()
+    }
+
+    #[test]
+    fn small_and_collinear_inputs() {
+        for amount in 0..4 {
+            let delaunay = randomized_incremental_delaunay(random_points(amount));
+            assert!(delaunay.maximum_step_count >= 2);
+            assert_eq!(delaunay.triangles.len(), amount.saturating_sub(2));
+            assert_eq!(delaunay.flips.len(), amount);
+        }
+        let line = (0..5).map(|i| Point2::new(10.0 * i as f64, 5.0)).collect();
+        assert!(randomized_incremental_delaunay(line).triangles.is_empty());
+    }
+
+    #[test]
+    fn splits_triangles_at_points_on_their_edges() {
+        // A grid puts points on edges, also on rays to the super-triangle.
+        let points = (0..25)
+            .map(|index| Point2::new((index % 5) as f64 * 10.0, (index / 5) as f64 * 10.0))
+            .collect::<Vec<_>>();
+        for _ in 0..20 {
+            let delaunay = randomized_incremental_delaunay(points.clone());
+            // Splitting a single triangle would leave flat ones in the history DAG.
+            assert!(delaunay
+                .nodes
+                .iter()
+                .filter(|node| node.triangle.iter().all(|vertex| *vertex < points.len()))
+                .all(|node| {
+                    let [a, b, c] = node.triangle.map(|vertex| points[vertex]);
+                    cross_product(&a, &b, &c) > 0.0
+                }));
+            assert_eq!(delaunay.triangles.len(), 32);
+            for [a, b, c] in delaunay.triangles.iter() {
+                let [a, b, c] = [a, b, c].map(|vertex| points[*vertex]);
+                assert!(cross_product(&a, &b, &c) > 0.0);
+                assert!(!points.iter().any(|point| in_circle(&a, &b, &c, point)));
+            }
+        }
+    }
+
+    #[test]
+    fn circumcircles_are_empty() {
+        for amount in 3..60 {
+            let points = random_points(amount);
+            let delaunay = randomized_incremental_delaunay(points.clone());
+            assert_eq!(
+                delaunay.triangles.len(),
+                2 * amount - 2 - brute_force_hull(&points).len()
+            );
+            for [a, b, c] in delaunay.triangles.iter() {
+                let [a, b, c] = [a, b, c].map(|vertex| points[*vertex]);
+                assert!(cross_product(&a, &b, &c) > 0.0);
+                assert!(!points.iter().any(|point| in_circle(&a, &b, &c, point)));
+            }
+        }
+    }
+
+    #[test]
+    fn matches_bowyer_watson() {
+        for amount in 3..40 {
+            let points = random_points(amount);
+            let mut bowyer_watson = BowyerWatson::new();
+            bowyer_watson.set_points(points.clone());
+            bowyer_watson.calculate();
+            assert_eq!(
+                normalized(&randomized_incremental_delaunay(points).triangles),
+                normalized(&bowyer_watson.triangles)
+            );
+        }
+    }
+}
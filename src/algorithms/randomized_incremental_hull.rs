@@ -0,0 +1,324 @@
+use itertools::Itertools;
+use nalgebra::Point2;
+use rand::seq::SliceRandom;
+use tui::style::Color;
+
+use super::{
+    algorithm::{Algorithm, DrawMethod, Drawable},
+    geometry::cross_product,
+};
+
+/// An uninserted point together with the start of the hull edge it is in
+/// conflict with, i.e. one hull edge visible from the point.
+struct Conflict {
+    point: Point2<f64>,
+    edge: Point2<f64>,
+}
+
+pub struct RandomizedIncrementalHull {
+    // Later passed to app.
+    pub maximum_step_count: usize,
+    pub points: Vec<Point2<f64>>,
+    pub steps: Vec<Vec<Drawable>>,
+    pub descriptions: Vec<String>,
+    // The current hull in counterclockwise order.
+    hull: Vec<Point2<f64>>,
+    conflicts: Vec<Conflict>,
+    discarded: Vec<Point2<f64>>,
+}
+
+impl RandomizedIncrementalHull {
+    pub fn new() -> Self {
+        RandomizedIncrementalHull {
+            maximum_step_count: 0,
+            points: vec![],
+            steps: vec![],
+            descriptions: vec![],
+            hull: vec![],
+            conflicts: vec![],
+            discarded: vec![],
+        }
+    }
+
+    /// Stores the current hull, the conflict graph and the discarded points
+    /// together with the given drawables.
+    fn add_step(&mut self, drawables: Vec<Drawable>, description: String) {
+        let mut step = self
+            .conflicts
+            .iter()
+            .map(|conflict| {
+                let to = self.next_vertex(&conflict.edge);
+                let middle = Point2::from((conflict.edge.coords + to.coords) / 2.0);
+                Drawable::line(&conflict.point, &middle, Color::DarkGray)
+            })
+            .collect::<Vec<_>>();
+        step.extend(
+            self.hull
+                .iter()
+                .circular_tuple_windows()
+                .map(|(from, to)| Drawable::line(from, to, Color::Blue)),
+        );
+        step.extend(drawables);
+        step.push(Drawable::points(&self.discarded, Color::DarkGray));
+
+        self.steps.push(step);
+        self.descriptions.push(description);
+        self.maximum_step_count += 1;
+    }
+
+    fn next_vertex(&self, vertex: &Point2<f64>) -> Point2<f64> {
+        let index = self.hull.iter().position(|point| point == vertex).unwrap();
+        self.hull[(index + 1) % self.hull.len()]
+    }
+
+    /// Returns the start of the first hull edge that is visible from the point.
+    fn find_conflict(&self, point: &Point2<f64>) -> Option<Point2<f64>> {
+        self.hull
+            .iter()
+            .find(|from| cross_product(from, &self.next_vertex(from), point) < 0.0)
+            .copied()
+    }
+
+    pub fn calculate(&mut self) {
+        self.steps = vec![];
+        self.descriptions = vec![];
+        self.maximum_step_count = 0;
+        self.hull = vec![];
+        self.conflicts = vec![];
+        self.discarded = vec![];
+
+        let mut points = self.points.clone();
+        points.shuffle(&mut rand::thread_rng());
+
+        // Find three points that are not collinear to start with a triangle.
+        let third = (2..points.len())
+            .find(|index| cross_product(&points[0], &points[1], &points[*index]) != 0.0);
+        let third = match third {
+            Some(third) => third,
+            None => {
+                // Without a triangle the hull is the segment between the
+                // extreme points.
+                let by_coordinates = |a: &&Point2<f64>, b: &&Point2<f64>| {
+                    (a.x, a.y).partial_cmp(&(b.x, b.y)).unwrap()
+                };
+                self.hull = points
+                    .iter()
+                    .min_by(by_coordinates)
+                    .into_iter()
+                    .chain(points.iter().max_by(by_coordinates))
+                    .copied()
+                    .dedup()
+                    .collect();
+                self.add_step(
+                    vec![],
+                    String::from(
+                        "There are not enough points in general position, so the hull is the \
+                         segment between the extreme points.",
+                    ),
+                );
+                return;
+            }
+        };
+        points.swap(2, third);
+
+        self.hull = points.drain(..3).collect();
+        if cross_product(&self.hull[0], &self.hull[1], &self.hull[2]) < 0.0 {
+            self.hull.swap(1, 2);
+        }
+
+        // Points without a visible edge lie inside the triangle.
+        for point in points {
+            match self.find_conflict(&point) {
+                Some(edge) => self.conflicts.push(Conflict { point, edge }),
+                None => self.discarded.push(point),
+            }
+        }
+        self.add_step(
+            vec![],
+            String::from(
+                "Start with a triangle. Every remaining point is linked to one visible hull edge.",
+            ),
+        );
+
+        // Points that end up inside the hull lose their conflict and are never inserted.
+        let mut inserted = 0;
+        while !self.conflicts.is_empty() {
+            let Conflict { point, edge: from } = self.conflicts.remove(0);
+            inserted += 1;
+            let description = format!("Insertion {inserted}");
+
+            let to = self.next_vertex(&from);
+            self.add_step(
+                vec![
+                    Drawable::line(&from, &to, Color::Magenta),
+                    Drawable::line(&point, &from, Color::Yellow),
+                    Drawable::line(&point, &to, Color::Yellow),
+                    Drawable::points(&[point], Color::Yellow),
+                ],
+                format!("{description}: the point is inserted, its conflict edge is highlighted."),
+            );
+
+            // Walk from the conflict edge in both directions over all visible edges.
+            let length = self.hull.len();
+            let visible = |hull: &[Point2<f64>], index: usize| {
+                cross_product(&hull[index % length], &hull[(index + 1) % length], &point) < 0.0
+            };
+            let conflict_index = self.hull.iter().position(|vertex| *vertex == from).unwrap();
+            let mut start = conflict_index + length;
+            while visible(&self.hull, start - 1) {
+                start -= 1;
+            }
+            let mut end = conflict_index + 1;
+            while visible(&self.hull, end) {
+                end += 1;
+            }
+            let start = start % length;
+            let end = end % length;
+
+            let removed = (0..(end + length - start) % length)
+                .map(|offset| self.hull[(start + offset) % length])
+                .collect::<Vec<_>>();
+            let removed_edges = removed
+                .iter()
+                .map(|from| (*from, self.next_vertex(from)))
+                .collect::<Vec<_>>();
+
+            // Replace the visible chain between both horizon vertices by the new point.
+            let (first, last) = (self.hull[start], self.hull[end]);
+            let mut hull = vec![point];
+            let mut index = end;
+            loop {
+                hull.push(self.hull[index]);
+                if index == start {
+                    break;
+                }
+                index = (index + 1) % length;
+            }
+            self.hull = hull;
+
+            // Only points in conflict with a removed edge need a new conflict, which
+            // has to be one of the two created edges. Otherwise the point lies inside.
+            let mut reassigned = 0;
+            let mut discarded = 0;
+            let conflicts = std::mem::take(&mut self.conflicts);
+            for conflict in conflicts {
+                if !removed.contains(&conflict.edge) {
+                    self.conflicts.push(conflict);
+                    continue;
+                }
+                if cross_product(&first, &point, &conflict.point) < 0.0 {
+                    self.conflicts.push(Conflict {
+                        point: conflict.point,
+                        edge: first,
+                    });
+                    reassigned += 1;
+                } else if cross_product(&point, &last, &conflict.point) < 0.0 {
+                    self.conflicts.push(Conflict {
+                        point: conflict.point,
+                        edge: point,
+                    });
+                    reassigned += 1;
+                } else {
+                    self.discarded.push(conflict.point);
+                    discarded += 1;
+                }
+            }
+
+            let mut drawables = removed_edges
+                .iter()
+                .map(|(from, to)| Drawable::line(from, to, Color::LightRed))
+                .collect::<Vec<_>>();
+            drawables.push(Drawable::line(&first, &point, Color::Green));
+            drawables.push(Drawable::line(&point, &last, Color::Green));
+            self.add_step(
+                drawables,
+                format!(
+                    "{description}: {} edges are removed and 2 edges are created. \
+                     {reassigned} conflicts are reassigned, {discarded} points are discarded.",
+                    removed_edges.len()
+                ),
+            );
+        }
+
+        self.add_step(
+            vec![],
+            format!("The convex hull has {} points.", self.hull.len()),
+        );
+    }
+}
+
+impl Algorithm for RandomizedIncrementalHull {
+    fn get_title(&self) -> &str {
+        "Randomized incremental hull"
+    }
+
+    fn get_points(&self) -> &Vec<Point2<f64>> {
+        &self.points
+    }
+
+    fn set_points(&mut self, points: Vec<Point2<f64>>) {
+        self.points = points;
+    }
+
+    fn get_steps(&self) -> Vec<Vec<Drawable>> {
+        self.steps.clone()
+    }
+
+    fn get_draw_method(&self) -> DrawMethod {
+        DrawMethod::Edge
+    }
+
+    fn get_step_description(&self, step: usize) -> Option<String> {
+        self.descriptions.get(step).cloned()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::algorithms::testing::{assert_same_points, brute_force_hull, random_points};
+
+    fn randomized_incremental_hull(points: Vec<Point2<f64>>) -> RandomizedIncrementalHull {
+        let mut randomized_incremental_hull = RandomizedIncrementalHull::new();
+        randomized_incremental_hull.set_points(points);
+        randomized_incremental_hull.calculate();
+        randomized_incremental_hull
+    }
+
+    #[test]
+    fn small_inputs_are_their_own_hull() {
+        for amount in 0..3 {
+            let points = random_points(amount);
+            let randomized_incremental_hull = randomized_incremental_hull(points.clone());
+            assert_same_points(&randomized_incremental_hull.hull, &points);
+        }
+    }
+
+    #[test]
+    fn collinear_inputs_keep_the_extreme_points() {
+        let mut line = (0..5)
+            .map(|i| Point2::new(10.0 * i as f64, 2.0 * i as f64))
+            .collect::<Vec<_>>();
+        line.push(line[2]);
+        let randomized_incremental_hull = randomized_incremental_hull(line.clone());
+        assert_same_points(&randomized_incremental_hull.hull, &[line[0], line[4]]);
+    }
+
+    #[test]
+    fn matches_brute_force_hull() {
+        for amount in 3..80 {
+            let points = random_points(amount);
+            let randomized_incremental_hull = randomized_incremental_hull(points.clone());
+            assert!(randomized_incremental_hull.conflicts.is_empty());
+            assert_same_points(
+                &randomized_incremental_hull.hull,
+                &brute_force_hull(&points),
+            );
+            assert!(randomized_incremental_hull
+                .hull
+                .iter()
+                .circular_tuple_windows()
+                .all(|(a, b, c)| cross_product(a, b, c) > 0.0));
+        }
+    }
+}
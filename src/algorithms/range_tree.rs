@@ -0,0 +1,632 @@
+use std::cmp::Ordering;
+
+use itertools::Itertools;
+use nalgebra::Point2;
+use tui::style::Color;
+
+use super::{
+    algorithm::{Algorithm, DrawMethod, Drawable, QueryKind},
+    geometry::Rectangle,
+};
+
+struct Node {
+    // Leaves store their point, inner nodes the last point of their left subtree.
+    split: usize,
+    children: Option<[usize; 2]>,
+    // The associated structure: all points of the subtree sorted by y.
+    by_y: Vec<usize>,
+    // For every position in `by_y` and one past its end, the first position in
+    // the associated structure of each child that is not below it.
+    cascade: Vec<[usize; 2]>,
+    // The x-range of the points in the subtree.
+    slab: [f64; 2],
+}
+
+pub struct RangeTree {
+    // Later passed to app.
+    pub maximum_step_count: usize,
+    pub points: Vec<Point2<f64>>,
+    pub steps: Vec<Vec<Drawable>>,
+    pub descriptions: Vec<String>,
+    // Indices of the points inside the query rectangle.
+    pub reported: Vec<usize>,
+    // Number of comparisons spent in binary searches on y.
+    pub comparisons: usize,
+    nodes: Vec<Node>,
+    canonical: Vec<usize>,
+    cascading: bool,
+    // The query entered by the user and the one searched for in this run.
+    query: Option<Rectangle>,
+    search: Option<Rectangle>,
+    x_bounds: [f64; 2],
+    y_bounds: [f64; 2],
+}
+
+impl RangeTree {
+    pub fn new() -> Self {
+        RangeTree {
+            maximum_step_count: 0,
+            points: vec![],
+            steps: vec![],
+            descriptions: vec![],
+            reported: vec![],
+            comparisons: 0,
+            nodes: vec![],
+            canonical: vec![],
+            cascading: false,
+            query: None,
+            search: None,
+            x_bounds: [0.0, 0.0],
+            y_bounds: [0.0, 0.0],
+        }
+    }
+
+    /// Stores the splitting lines of the primary tree and the state of the
+    /// search together with the given drawables.
+    fn add_step(&mut self, drawables: Vec<Drawable>, description: String) {
+        let mut step = self
+            .nodes
+            .iter()
+            .filter(|node| node.children.is_some())
+            .map(|node| self.vertical_line(self.points[node.split].x, Color::Blue))
+            .collect::<Vec<_>>();
+        for node in self.canonical.iter() {
+            step.extend(self.slab_lines(*node, Color::Green));
+        }
+        if let Some(search) = &self.search {
+            step.extend(
+                search
+                    .corners()
+                    .iter()
+                    .circular_tuple_windows()
+                    .map(|(from, to)| Drawable::line(from, to, Color::Magenta)),
+            );
+        }
+        step.extend(drawables);
+        step.push(Drawable::points(
+            &self
+                .reported
+                .iter()
+                .map(|point| self.points[*point])
+                .collect::<Vec<_>>(),
+            Color::Green,
+        ));
+
+        self.steps.push(step);
+        self.descriptions.push(description);
+        self.maximum_step_count += 1;
+    }
+
+    fn vertical_line(&self, x: f64, color: Color) -> Drawable {
+        Drawable::line(
+            &Point2::new(x, self.y_bounds[0]),
+            &Point2::new(x, self.y_bounds[1]),
+            color,
+        )
+    }
+
+    fn slab_lines(&self, node: usize, color: Color) -> Vec<Drawable> {
+        self.nodes[node]
+            .slab
+            .iter()
+            .map(|x| self.vertical_line(*x, color))
+            .collect()
+    }
+
+    /// Connects the given points of an associated structure in the order of y.
+    fn chain(&self, points: &[usize], color: Color) -> Vec<Drawable> {
+        points
+            .iter()
+            .tuple_windows()
+            .map(|(from, to)| Drawable::line(&self.points[*from], &self.points[*to], color))
+            .collect()
+    }
+
+    /// Orders points by x, or by y, and breaks ties by their index. This makes
+    /// all keys distinct, even if points share coordinates.
+    fn compare(&self, a: usize, b: usize, by_x: bool) -> Ordering {
+        let key = |point: usize| {
+            let point = self.points[point];
+            if by_x {
+                (point.x, point.y)
+            } else {
+                (point.y, point.x)
+            }
+        };
+        key(a).partial_cmp(&key(b)).unwrap().then_with(|| a.cmp(&b))
+    }
+
+    /// Builds the subtree of the points sorted by x and returns its root.
+    fn build(&mut self, by_x: &[usize]) -> usize {
+        let slab = [self.points[by_x[0]].x, self.points[by_x[by_x.len() - 1]].x];
+        if by_x.len() == 1 {
+            self.nodes.push(Node {
+                split: by_x[0],
+                children: None,
+                by_y: by_x.to_vec(),
+                cascade: vec![],
+                slab,
+            });
+            return self.nodes.len() - 1;
+        }
+
+        let middle = by_x.len().div_ceil(2);
+        let children = [self.build(&by_x[..middle]), self.build(&by_x[middle..])];
+        let [left, right] = children.map(|child| self.nodes[child].by_y.clone());
+
+        // Merge both lists. The number of points taken from each child so far is
+        // the position a cascade pointer leads to.
+        let mut by_y = vec![];
+        let mut cascade = vec![];
+        let mut positions = [0, 0];
+        while positions[0] < left.len() || positions[1] < right.len() {
+            cascade.push(positions);
+            let side = if positions[1] == right.len()
+                || (positions[0] < left.len()
+                    && self.compare(left[positions[0]], right[positions[1]], false)
+                        == Ordering::Less)
+            {
+                0
+            } else {
+                1
+            };
+            by_y.push([&left, &right][side][positions[side]]);
+            positions[side] += 1;
+        }
+        cascade.push(positions);
+
+        self.nodes.push(Node {
+            split: by_x[middle - 1],
+            children: Some(children),
+            by_y,
+            cascade,
+            slab,
+        });
+        let node = self.nodes.len() - 1;
+
+        let mut drawables = self.slab_lines(node, Color::Yellow);
+        drawables.extend(self.chain(&self.nodes[node].by_y, Color::Cyan));
+        if self.cascading {
+            drawables.extend(self.cascade_lines(node));
+        }
+        self.add_step(
+            drawables,
+            format!(
+                "The node of the {} points with x in [{:.2}, {:.2}] splits at x = {:.2}. Its \
+                 associated structure merges both children into a list sorted by y{}.",
+                by_x.len(),
+                slab[0],
+                slab[1],
+                self.points[by_x[middle - 1]].x,
+                if self.cascading {
+                    ", with pointers into the lists of both children"
+                } else {
+                    ""
+                }
+            ),
+        );
+        node
+    }
+
+    /// Draws the cascade pointers of a node from each point of its associated
+    /// structure to the same point in the lists of the children.
+    fn cascade_lines(&self, node: usize) -> Vec<Drawable> {
+        let node = &self.nodes[node];
+        let children = match node.children {
+            Some(children) => children,
+            None => return vec![],
+        };
+        node.by_y
+            .iter()
+            .zip(&node.cascade)
+            .flat_map(|(point, positions)| {
+                (0..2).filter_map(move |side| {
+                    let target = *self.nodes[children[side]].by_y.get(positions[side])?;
+                    (target != *point).then(|| {
+                        Drawable::line(&self.points[*point], &self.points[target], Color::DarkGray)
+                    })
+                })
+            })
+            .collect()
+    }
+
+    /// Finds the first position in the associated structure not below y by binary
+    /// search and counts the comparisons.
+    fn lower_bound(&mut self, node: usize, y: f64) -> usize {
+        let (mut low, mut high) = (0, self.nodes[node].by_y.len());
+        while low < high {
+            let middle = (low + high) / 2;
+            self.comparisons += 1;
+            if self.points[self.nodes[node].by_y[middle]].y < y {
+                low = middle + 1;
+            } else {
+                high = middle;
+            }
+        }
+        low
+    }
+
+    /// Reports the canonical subset of the node with a 1D query on y, starting at
+    /// the given position if fractional cascading found it already.
+    fn report(
+        &mut self,
+        node: usize,
+        parent: Option<(usize, usize)>,
+        query: &Rectangle,
+        label: &str,
+    ) {
+        let (start, how) = match parent {
+            Some((parent, position)) if self.cascading => {
+                let side = usize::from(self.nodes[parent].children.unwrap()[1] == node);
+                (
+                    self.nodes[parent].cascade[position][side],
+                    String::from("Follow the cascade pointer instead of searching"),
+                )
+            }
+            _ => {
+                let comparisons = self.comparisons;
+                let start = self.lower_bound(node, query.min.y);
+                (
+                    start,
+                    format!(
+                        "Binary search its {} points on y with {} comparisons",
+                        self.nodes[node].by_y.len(),
+                        self.comparisons - comparisons
+                    ),
+                )
+            }
+        };
+        let found = self.nodes[node].by_y[start..]
+            .iter()
+            .copied()
+            .take_while(|point| self.points[*point].y <= query.max.y)
+            .collect::<Vec<_>>();
+        self.reported.extend(found.iter().copied());
+        self.canonical.push(node);
+
+        let mut drawables = self.chain(&self.nodes[node].by_y, Color::Cyan);
+        drawables.extend(self.chain(&found, Color::Yellow));
+        if let (Some((parent, position)), true) = (parent, self.cascading) {
+            drawables.extend(self.pointer_line(parent, position, start, node));
+        }
+        self.add_step(
+            drawables,
+            format!(
+                "{label} {how} and report {} points with y in the query range.",
+                found.len()
+            ),
+        );
+    }
+
+    /// Draws the followed cascade pointer between both associated structures.
+    fn pointer_line(
+        &self,
+        parent: usize,
+        position: usize,
+        target: usize,
+        child: usize,
+    ) -> Option<Drawable> {
+        let from = self.nodes[parent].by_y.get(position)?;
+        let to = self.nodes[child].by_y.get(target)?;
+        Some(Drawable::line(
+            &self.points[*from],
+            &self.points[*to],
+            Color::Magenta,
+        ))
+    }
+
+    /// Follows the cascade pointer from the parent to the child, if cascading.
+    fn descend(&self, parent: usize, position: usize, side: usize) -> usize {
+        if self.cascading {
+            self.nodes[parent].cascade[position][side]
+        } else {
+            position
+        }
+    }
+
+    fn check_leaf(&mut self, node: usize, query: &Rectangle) {
+        let point = self.nodes[node].split;
+        let inside = query.contains_point(&self.points[point]);
+        if inside {
+            self.reported.push(point);
+        }
+        self.add_step(
+            vec![Drawable::points(&[self.points[point]], Color::Yellow)],
+            format!(
+                "The path ends at the leaf of v{point}. It lies {} the query.",
+                if inside { "inside" } else { "outside" }
+            ),
+        );
+    }
+
+    /// Walks from the split node towards one side of the query and reports the
+    /// subtrees hanging off the path towards the inside.
+    fn walk(&mut self, split: usize, side: usize, position: usize, query: &Rectangle) {
+        let children = self.nodes[split].children.unwrap();
+        let mut position = self.descend(split, position, side);
+        let mut node = children[side];
+        while let Some(children) = self.nodes[node].children {
+            let x = self.points[self.nodes[node].split].x;
+            // On the left path, the query goes left if it starts left of the split.
+            let (inward, bound) = if side == 0 {
+                (query.min.x <= x, query.min.x)
+            } else {
+                (query.max.x >= x, query.max.x)
+            };
+            let next = if inward { side } else { 1 - side };
+            let mut drawables = self.slab_lines(node, Color::Yellow);
+            drawables.push(self.vertical_line(bound, Color::Magenta));
+            self.add_step(
+                drawables,
+                format!(
+                    "Walk the {} path at the split x = {x:.2}: go {}.",
+                    if side == 0 { "left" } else { "right" },
+                    if next == 0 { "left" } else { "right" }
+                ),
+            );
+            if inward {
+                self.report(
+                    children[1 - side],
+                    Some((node, position)),
+                    query,
+                    "The other child lies completely inside the x-range of the query.",
+                );
+            }
+            position = self.descend(node, position, next);
+            node = children[next];
+        }
+        self.check_leaf(node, query);
+    }
+
+    fn query(&mut self, root: usize, query: &Rectangle) {
+        let mut node = root;
+        let mut path = vec![];
+        while let Some(children) = self.nodes[node].children {
+            let x = self.points[self.nodes[node].split].x;
+            if query.max.x < x {
+                node = children[0];
+            } else if query.min.x > x {
+                node = children[1];
+            } else {
+                break;
+            }
+            path.push(node);
+        }
+        let mut drawables = self.slab_lines(node, Color::Yellow);
+        drawables.extend(
+            path.iter()
+                .flat_map(|node| self.slab_lines(*node, Color::Cyan)),
+        );
+        match self.nodes[node].children {
+            None => {
+                self.add_step(
+                    drawables,
+                    format!(
+                        "The search descends {} levels to a leaf. The x-range of the query \
+                         contains at most this point.",
+                        path.len()
+                    ),
+                );
+                self.check_leaf(node, query);
+            }
+            Some(_) => {
+                let position = if self.cascading {
+                    self.lower_bound(node, query.min.y)
+                } else {
+                    0
+                };
+                self.add_step(
+                    drawables,
+                    format!(
+                        "{}, the query splits at x = {:.2}. Walk both paths from here{}.",
+                        if path.is_empty() {
+                            String::from("At the root")
+                        } else {
+                            format!("After {} levels", path.len())
+                        },
+                        self.points[self.nodes[node].split].x,
+                        if self.cascading {
+                            format!(
+                                ". Fractional cascading needs a single binary search on y, \
+                                 which took {} comparisons",
+                                self.comparisons
+                            )
+                        } else {
+                            String::new()
+                        }
+                    ),
+                );
+                self.walk(node, 0, position, query);
+                self.walk(node, 1, position, query);
+            }
+        }
+    }
+
+    pub fn calculate(&mut self) {
+        self.steps = vec![];
+        self.descriptions = vec![];
+        self.maximum_step_count = 0;
+        self.reported = vec![];
+        self.comparisons = 0;
+        self.nodes = vec![];
+        self.canonical = vec![];
+        self.search = None;
+
+        if self.points.is_empty() {
+            self.add_step(vec![], String::from("There are no points to store."));
+            return;
+        }
+
+        let mut by_x = (0..self.points.len()).collect::<Vec<_>>();
+        by_x.sort_by(|a, b| self.compare(*a, *b, true));
+        self.add_step(
+            vec![],
+            format!(
+                "Sort the points by x. They become the leaves of the primary tree. Fractional \
+                 cascading is {}, press m to toggle it.",
+                if self.cascading { "on" } else { "off" }
+            ),
+        );
+        let root = self.build(&by_x);
+        let size = self.nodes.iter().map(|node| node.by_y.len()).sum::<usize>();
+        self.add_step(
+            vec![],
+            format!(
+                "The primary tree has {} nodes. Its associated structures store {size} points \
+                 in total, O(n log n).",
+                self.nodes.len()
+            ),
+        );
+
+        // Without a query of the user, search the center quarter of the bounds.
+        let bounds = Rectangle::new(
+            Point2::new(self.x_bounds[0], self.y_bounds[0]),
+            Point2::new(self.x_bounds[1], self.y_bounds[1]),
+        );
+        let query = self.query.unwrap_or_else(|| {
+            let center = bounds.min + (bounds.max - bounds.min) / 2.0;
+            let offset = (bounds.max - bounds.min) / 4.0;
+            Rectangle::new(center - offset, center + offset)
+        });
+        self.search = Some(query);
+        self.add_step(
+            vec![],
+            format!(
+                "Query the rectangle from ({:.2}, {:.2}) to ({:.2}, {:.2}). Press r to enter \
+                 another one.",
+                query.min.x, query.min.y, query.max.x, query.max.y
+            ),
+        );
+        self.query(root, &query);
+
+        self.add_step(
+            vec![],
+            format!(
+                "Found {} points in {} canonical subsets with {} comparisons on y. {}",
+                self.reported.len(),
+                self.canonical.len(),
+                self.comparisons,
+                if self.cascading {
+                    "Fractional cascading answers the query in O(log n + k)."
+                } else {
+                    "Searching every canonical subset answers the query in O(log² n + k)."
+                }
+            ),
+        );
+    }
+}
+
+impl Algorithm for RangeTree {
+    fn get_title(&self) -> &str {
+        "Range tree"
+    }
+
+    fn get_points(&self) -> &Vec<Point2<f64>> {
+        &self.points
+    }
+
+    fn set_points(&mut self, points: Vec<Point2<f64>>) {
+        self.points = points;
+    }
+
+    fn set_bounds(&mut self, x_bounds: [f64; 2], y_bounds: [f64; 2]) {
+        self.x_bounds = x_bounds;
+        self.y_bounds = y_bounds;
+    }
+
+    fn switch_mode(&mut self) -> bool {
+        self.cascading = !self.cascading;
+        true
+    }
+
+    fn get_query_kind(&self) -> Option<QueryKind> {
+        Some(QueryKind::Rectangle)
+    }
+
+    fn set_query(&mut self, query: Vec<f64>) -> bool {
+        match query[..] {
+            [x1, y1, x2, y2] => {
+                self.query = Some(Rectangle::new(Point2::new(x1, y1), Point2::new(x2, y2)));
+                true
+            }
+            _ => false,
+        }
+    }
+
+    fn get_steps(&self) -> Vec<Vec<Drawable>> {
+        self.steps.clone()
+    }
+
+    fn get_draw_method(&self) -> DrawMethod {
+        DrawMethod::Edge
+    }
+
+    fn get_step_description(&self, step: usize) -> Option<String> {
+        self.descriptions.get(step).cloned()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::algorithms::testing::{assert_range_queries, random_points};
+
+    fn new() -> RangeTree {
+        let mut range_tree = RangeTree::new();
+        range_tree.set_bounds([0.0, 100.0], [0.0, 100.0]);
+        range_tree
+    }
+
+    fn query(
+        range_tree: &mut RangeTree,
+        points: &[Point2<f64>],
+        rectangle: [f64; 4],
+    ) -> Vec<usize> {
+        range_tree.set_points(points.to_vec());
+        range_tree.set_query(rectangle.to_vec());
+        range_tree.calculate();
+        let mut reported = range_tree.reported.clone();
+        reported.sort();
+        reported
+    }
+
+    #[test]
+    fn empty_input_reports_nothing() {
+        let mut range_tree = new();
+        range_tree.calculate();
+        assert!(range_tree.reported.is_empty());
+    }
+
+    #[test]
+    fn matches_brute_force() {
+        for cascading in [false, true] {
+            let mut range_tree = new();
+            range_tree.cascading = cascading;
+            assert_range_queries(|points, rectangle| query(&mut range_tree, points, rectangle));
+        }
+    }
+
+    #[test]
+    fn searches_few_canonical_subsets() {
+        let mut range_tree = new();
+        let amount = 1024;
+        query(
+            &mut range_tree,
+            &random_points(amount),
+            [20.0, 20.0, 80.0, 80.0],
+        );
+        assert!(range_tree.canonical.len() <= 2 * amount.ilog2() as usize);
+    }
+
+    #[test]
+    fn cascading_saves_comparisons() {
+        let points = random_points(1024);
+        let comparisons = [false, true].map(|cascading| {
+            let mut range_tree = new();
+            range_tree.cascading = cascading;
+            query(&mut range_tree, &points, [20.0, 20.0, 80.0, 80.0]);
+            range_tree.comparisons
+        });
+        assert!(comparisons[1] < comparisons[0]);
+    }
+}
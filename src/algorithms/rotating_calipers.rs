@@ -0,0 +1,413 @@
+use itertools::Itertools;
+use nalgebra::{Point2, Vector2};
+use tui::style::Color;
+
+use super::{
+    algorithm::{Algorithm, DrawMethod, Drawable},
+    geometry::{clip_segment, cross_product},
+    graham_scan::GrahamScan,
+};
+
+/// A rectangle flush with a hull edge, spanned by the unit edge direction and
+/// its inward normal.
+#[derive(Clone, Copy)]
+struct Rectangle {
+    direction: Vector2<f64>,
+    // The extent of the hull along the direction and along the normal.
+    along: [f64; 2],
+    across: [f64; 2],
+}
+
+impl Rectangle {
+    fn normal(&self) -> Vector2<f64> {
+        Vector2::new(-self.direction.y, self.direction.x)
+    }
+
+    fn corners(&self) -> [Point2<f64>; 4] {
+        let normal = self.normal();
+        [
+            (self.along[0], self.across[0]),
+            (self.along[1], self.across[0]),
+            (self.along[1], self.across[1]),
+            (self.along[0], self.across[1]),
+        ]
+        .map(|(along, across)| Point2::from(self.direction * along + normal * across))
+    }
+
+    fn sides(&self) -> (f64, f64) {
+        (
+            self.along[1] - self.along[0],
+            self.across[1] - self.across[0],
+        )
+    }
+
+    fn area(&self) -> f64 {
+        let (length, width) = self.sides();
+        length * width
+    }
+
+    fn perimeter(&self) -> f64 {
+        let (length, width) = self.sides();
+        2.0 * (length + width)
+    }
+
+    fn lines(&self, color: Color) -> Vec<Drawable> {
+        self.corners()
+            .iter()
+            .circular_tuple_windows()
+            .map(|(from, to)| Drawable::line(from, to, color))
+            .collect()
+    }
+}
+
+pub struct RotatingCalipers {
+    // Later passed to app.
+    pub maximum_step_count: usize,
+    pub points: Vec<Point2<f64>>,
+    pub steps: Vec<Vec<Drawable>>,
+    pub descriptions: Vec<String>,
+    // The hull vertices of the diameter and its length.
+    pub diameter: Option<(usize, usize, f64)>,
+    // The hull edge and vertex of the minimum width and the width itself.
+    pub width: Option<(usize, usize, f64)>,
+    hull: Vec<Point2<f64>>,
+    minimum_area: Option<Rectangle>,
+    minimum_perimeter: Option<Rectangle>,
+    x_bounds: [f64; 2],
+    y_bounds: [f64; 2],
+}
+
+impl RotatingCalipers {
+    pub fn new() -> Self {
+        RotatingCalipers {
+            maximum_step_count: 0,
+            points: vec![],
+            steps: vec![],
+            descriptions: vec![],
+            diameter: None,
+            width: None,
+            hull: vec![],
+            minimum_area: None,
+            minimum_perimeter: None,
+            x_bounds: [0.0, 0.0],
+            y_bounds: [0.0, 0.0],
+        }
+    }
+
+    /// Stores the hull together with the given drawables.
+    fn add_step(&mut self, drawables: Vec<Drawable>, description: String) {
+        let mut step = self
+            .hull
+            .iter()
+            .circular_tuple_windows()
+            .map(|(from, to)| Drawable::line(from, to, Color::Blue))
+            .collect::<Vec<_>>();
+        step.extend(drawables);
+
+        self.steps.push(step);
+        self.descriptions.push(description);
+        self.maximum_step_count += 1;
+    }
+
+    /// Returns the line through the point in the given direction, clipped to the
+    /// bounds.
+    fn caliper(&self, point: &Point2<f64>, direction: Vector2<f64>) -> Option<Drawable> {
+        let length = (self.x_bounds[1] - self.x_bounds[0]) + (self.y_bounds[1] - self.y_bounds[0]);
+        clip_segment(
+            &(point - direction * length),
+            &(point + direction * length),
+            self.x_bounds,
+            self.y_bounds,
+        )
+        .map(|(from, to)| Drawable::line(&from, &to, Color::Yellow))
+    }
+
+    fn vertex(&self, index: usize) -> &Point2<f64> {
+        &self.hull[index % self.hull.len()]
+    }
+
+    /// Moves the pointer forward along the hull as long as the value increases.
+    fn advance(&self, mut pointer: usize, value: impl Fn(&Point2<f64>) -> f64) -> usize {
+        for _ in 0..self.hull.len() {
+            if value(self.vertex(pointer + 1)) <= value(self.vertex(pointer)) {
+                break;
+            }
+            pointer += 1;
+        }
+        pointer % self.hull.len()
+    }
+
+    fn update_diameter(&mut self, a: usize, b: usize) {
+        let (a, b) = (a % self.hull.len(), b % self.hull.len());
+        let distance = (self.vertex(a) - self.vertex(b)).norm();
+        if self
+            .diameter
+            .is_none_or(|(_, _, diameter)| distance > diameter)
+        {
+            self.diameter = Some((a, b, distance));
+        }
+    }
+
+    pub fn calculate(&mut self) {
+        self.steps = vec![];
+        self.descriptions = vec![];
+        self.maximum_step_count = 0;
+        self.diameter = None;
+        self.width = None;
+        self.minimum_area = None;
+        self.minimum_perimeter = None;
+
+        let mut graham_scan = GrahamScan::new();
+        graham_scan.set_points(self.points.clone());
+        graham_scan.calculate();
+        // Collinear hull vertices would create edges without a turn.
+        let hull = graham_scan.get_hull();
+        self.hull = hull
+            .iter()
+            .circular_tuple_windows()
+            .filter(|(previous, current, next)| cross_product(previous, current, next) > 0.0)
+            .map(|(_, current, _)| *current)
+            .collect();
+        if self.hull.len() < 3 {
+            self.add_step(
+                vec![Drawable::points(&hull, Color::Yellow)],
+                String::from(
+                    "The Graham scan yields a hull without a turn. Rotating calipers need a hull \
+                     with at least three vertices. Add more points that are not collinear.",
+                ),
+            );
+            return;
+        }
+        self.add_step(
+            vec![],
+            format!(
+                "The Graham scan yields a hull with {} vertices. Place a pair of parallel \
+                 calipers on each of its edges in turn.",
+                self.hull.len()
+            ),
+        );
+
+        let length = self.hull.len();
+        // The antipodal vertex and the extreme vertices along the edge direction.
+        let (mut antipodal, mut front, mut back) = (1, 1, 1);
+        for edge in 0..length {
+            let (from, to) = (*self.vertex(edge), *self.vertex(edge + 1));
+            let direction = (to - from).normalize();
+            let normal = Vector2::new(-direction.y, direction.x);
+            let height = |point: &Point2<f64>| (point - from).dot(&normal);
+            let along = |point: &Point2<f64>| point.coords.dot(&direction);
+            // Counterclockwise from the edge, the hull first reaches the front, then
+            // the antipodal and then the back vertex. All three only move forward.
+            front = self.advance(front, along);
+            antipodal = self.advance(antipodal, height);
+            if edge == 0 {
+                back = antipodal;
+            }
+            back = self.advance(back, |point| -along(point));
+
+            // Both endpoints of the edge are antipodal to the vertex opposite, and
+            // to the next one if it lies on a parallel edge.
+            for vertex in [edge, edge + 1] {
+                self.update_diameter(vertex, antipodal);
+                if (height(self.vertex(antipodal + 1)) - height(self.vertex(antipodal))).abs()
+                    < 1e-9
+                {
+                    self.update_diameter(vertex, antipodal + 1);
+                }
+            }
+
+            let width = height(self.vertex(antipodal));
+            if self.width.is_none_or(|(_, _, minimum)| width < minimum) {
+                self.width = Some((edge, antipodal, width));
+            }
+            let rectangle = Rectangle {
+                direction,
+                along: [along(self.vertex(back)), along(self.vertex(front))],
+                across: [
+                    from.coords.dot(&normal),
+                    self.vertex(antipodal).coords.dot(&normal),
+                ],
+            };
+            if self
+                .minimum_area
+                .is_none_or(|minimum| rectangle.area() < minimum.area())
+            {
+                self.minimum_area = Some(rectangle);
+            }
+            if self
+                .minimum_perimeter
+                .is_none_or(|minimum| rectangle.perimeter() < minimum.perimeter())
+            {
+                self.minimum_perimeter = Some(rectangle);
+            }
+
+            let mut drawables = self.minimum_area.unwrap().lines(Color::Green);
+            drawables.extend(rectangle.lines(Color::Cyan));
+            drawables.extend(
+                [
+                    (from, direction),
+                    (*self.vertex(antipodal), direction),
+                    (*self.vertex(front), normal),
+                    (*self.vertex(back), normal),
+                ]
+                .iter()
+                .filter_map(|(point, direction)| self.caliper(point, *direction)),
+            );
+            let (a, b, diameter) = self.diameter.unwrap();
+            drawables.push(Drawable::line(
+                self.vertex(a),
+                self.vertex(b),
+                Color::Magenta,
+            ));
+            drawables.push(Drawable::points(
+                &[
+                    *self.vertex(antipodal),
+                    *self.vertex(front),
+                    *self.vertex(back),
+                ],
+                Color::Yellow,
+            ));
+            self.add_step(
+                drawables,
+                format!(
+                    "Rotate the calipers onto edge {edge}. The opposite caliper touches the \
+                     hull at distance {width:.2}, the rectangle has area {:.2} and perimeter \
+                     {:.2}. Diameter so far: {diameter:.2}.",
+                    rectangle.area(),
+                    rectangle.perimeter()
+                ),
+            );
+        }
+
+        let (a, b, diameter) = self.diameter.unwrap();
+        let (edge, vertex, width) = self.width.unwrap();
+        let direction = (self.vertex(edge + 1) - self.vertex(edge)).normalize();
+        let mut drawables = vec![Drawable::line(
+            self.vertex(a),
+            self.vertex(b),
+            Color::Magenta,
+        )];
+        drawables.extend(self.caliper(self.vertex(edge), direction));
+        drawables.extend(self.caliper(self.vertex(vertex), direction));
+        self.add_step(
+            drawables,
+            format!(
+                "After a full turn the calipers visited all {length} antipodal edge-vertex \
+                 pairs. The diameter is {diameter:.2}, the minimum width {width:.2} with \
+                 the calipers flush with edge {edge}."
+            ),
+        );
+        for (name, rectangle, value) in [
+            (
+                "area",
+                self.minimum_area.unwrap(),
+                self.minimum_area.unwrap().area(),
+            ),
+            (
+                "perimeter",
+                self.minimum_perimeter.unwrap(),
+                self.minimum_perimeter.unwrap().perimeter(),
+            ),
+        ] {
+            let (length, width) = rectangle.sides();
+            self.add_step(
+                rectangle.lines(Color::Green),
+                format!(
+                    "The minimum-{name} bounding rectangle has a side flush with a hull edge. \
+                     It measures {length:.2} × {width:.2}, its {name} is {value:.2}."
+                ),
+            );
+        }
+    }
+}
+
+impl Algorithm for RotatingCalipers {
+    fn get_title(&self) -> &str {
+        "Rotating calipers"
+    }
+
+    fn get_points(&self) -> &Vec<Point2<f64>> {
+        &self.points
+    }
+
+    fn set_points(&mut self, points: Vec<Point2<f64>>) {
+        self.points = points;
+    }
+
+    fn set_bounds(&mut self, x_bounds: [f64; 2], y_bounds: [f64; 2]) {
+        self.x_bounds = x_bounds;
+        self.y_bounds = y_bounds;
+    }
+
+    fn get_steps(&self) -> Vec<Vec<Drawable>> {
+        self.steps.clone()
+    }
+
+    fn get_draw_method(&self) -> DrawMethod {
+        DrawMethod::Edge
+    }
+
+    fn get_step_description(&self, step: usize) -> Option<String> {
+        self.descriptions.get(step).cloned()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::algorithms::testing::random_points;
+
+    fn calipers(points: Vec<Point2<f64>>) -> RotatingCalipers {
+        let mut rotating_calipers = RotatingCalipers::new();
+        rotating_calipers.set_bounds([0.0, 100.0], [0.0, 100.0]);
+        rotating_calipers.set_points(points);
+        rotating_calipers.calculate();
+        rotating_calipers
+    }
+
+    #[test]
+    fn small_and_collinear_inputs_have_no_diameter() {
+        for amount in 0..3 {
+            assert!(calipers(random_points(amount)).diameter.is_none());
+        }
+        let line = (0..5)
+            .map(|i| Point2::new(i as f64, 2.0 * i as f64))
+            .collect();
+        assert!(calipers(line).diameter.is_none());
+    }
+
+    #[test]
+    fn matches_brute_force() {
+        for amount in 3..40 {
+            let points = random_points(amount);
+            let rotating_calipers = calipers(points.clone());
+            let hull = &rotating_calipers.hull;
+
+            let diameter = points
+                .iter()
+                .flat_map(|a| points.iter().map(move |b| (a - b).norm()))
+                .fold(0.0, f64::max);
+            assert!((rotating_calipers.diameter.unwrap().2 - diameter).abs() < 1e-9);
+
+            // Every edge spans a rectangle, the extents are taken over all points.
+            let (mut width, mut area, mut perimeter) = (f64::MAX, f64::MAX, f64::MAX);
+            for (from, to) in hull.iter().circular_tuple_windows() {
+                let direction = (to - from).normalize();
+                let normal = Vector2::new(-direction.y, direction.x);
+                let extent = |axis: &Vector2<f64>| {
+                    let values = points.iter().map(|point| (point - from).dot(axis));
+                    values.clone().fold(f64::MIN, f64::max) - values.fold(f64::MAX, f64::min)
+                };
+                let (length, height) = (extent(&direction), extent(&normal));
+                width = width.min(height);
+                area = area.min(length * height);
+                perimeter = perimeter.min(2.0 * (length + height));
+            }
+            assert!((rotating_calipers.width.unwrap().2 - width).abs() < 1e-9);
+            assert!((rotating_calipers.minimum_area.unwrap().area() - area).abs() < 1e-6);
+            assert!(
+                (rotating_calipers.minimum_perimeter.unwrap().perimeter() - perimeter).abs() < 1e-6
+            );
+        }
+    }
+}
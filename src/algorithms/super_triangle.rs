@@ -0,0 +1,166 @@
+use itertools::Itertools;
+use nalgebra::{Point2, Vector2};
+use tui::style::Color;
+
+use super::{
+    algorithm::Drawable,
+    geometry::{circumcircle, cross_product, in_circle},
+};
+
+// Directions of the super-triangle vertices, which lie infinitely far away.
+const DIRECTIONS: [(f64, f64); 3] = [(0.0, 1.0), (-0.866, -0.5), (0.866, -0.5)];
+
+// Length used to draw rays and half-plane boundaries.
+const FAR: f64 = 1e6;
+
+/// # Super-triangle with vertices at infinity.
+/// Triangulations of `n` points use the indices `n`, `n + 1` and `n + 2`
+/// for the counterclockwise vertices of a triangle containing all points.
+/// Placing them infinitely far away keeps them from influencing the
+/// triangulation of the points.
+pub struct SuperTriangle<'a> {
+    points: &'a [Point2<f64>],
+}
+
+impl<'a> SuperTriangle<'a> {
+    pub fn new(points: &'a [Point2<f64>]) -> Self {
+        SuperTriangle { points }
+    }
+
+    pub fn vertices(&self) -> [usize; 3] {
+        let length = self.points.len();
+        [length, length + 1, length + 2]
+    }
+
+    pub fn is_infinite(&self, vertex: usize) -> bool {
+        vertex >= self.points.len()
+    }
+
+    /// Names finite vertices `v0, v1, ...` and the super-triangle vertices
+    /// `p-1, p-2, p-3` as in the textbook by de Berg et al.
+    pub fn label(&self, vertex: usize) -> String {
+        if self.is_infinite(vertex) {
+            format!("p-{}", vertex - self.points.len() + 1)
+        } else {
+            format!("v{vertex}")
+        }
+    }
+
+    fn direction(&self, vertex: usize) -> Vector2<f64> {
+        let (x, y) = DIRECTIONS[vertex - self.points.len()];
+        Vector2::new(x, y)
+    }
+
+    pub fn triangle_lines(&self, triangle: &[usize; 3], color: Color) -> Vec<Drawable> {
+        triangle
+            .iter()
+            .circular_tuple_windows()
+            .filter_map(|(from, to)| self.edge_line(*from, *to, color))
+            .collect()
+    }
+
+    /// Draws an edge to a vertex at infinity as a ray. Edges between two such
+    /// vertices are not drawn.
+    pub fn edge_line(&self, from: usize, to: usize, color: Color) -> Option<Drawable> {
+        let (from, to) = match (self.is_infinite(from), self.is_infinite(to)) {
+            (false, false) => (self.points[from], self.points[to]),
+            (false, true) => (
+                self.points[from],
+                self.points[from] + self.direction(to) * FAR,
+            ),
+            (true, false) => (
+                self.points[to],
+                self.points[to] + self.direction(from) * FAR,
+            ),
+            (true, true) => return None,
+        };
+        Some(Drawable::line(&from, &to, color))
+    }
+
+    /// Rotates the triangle such that its finite vertices come first.
+    fn finite_first(&self, triangle: &[usize; 3]) -> [usize; 3] {
+        let mut triangle = *triangle;
+        for _ in 0..3 {
+            let finite = triangle.map(|vertex| !self.is_infinite(vertex));
+            if finite == [true, true, true]
+                || finite == [true, true, false]
+                || finite == [true, false, false]
+                || finite == [false, false, false]
+            {
+                break;
+            }
+            triangle.rotate_left(1);
+        }
+        triangle
+    }
+
+    /// Returns on which side of the directed edge the point lies, positive on the
+    /// left. Every point lies inside the edge between two vertices at infinity.
+    fn side(&self, from: usize, to: usize, point: &Point2<f64>) -> f64 {
+        match (self.is_infinite(from), self.is_infinite(to)) {
+            (false, false) => cross_product(&self.points[from], &self.points[to], point),
+            (false, true) => self.direction(to).perp(&(point - self.points[from])),
+            (true, false) => (-self.direction(from)).perp(&(point - self.points[to])),
+            (true, true) => f64::INFINITY,
+        }
+    }
+
+    /// Checks whether the point lies left of or on the directed edge.
+    fn is_left(&self, from: usize, to: usize, point: &Point2<f64>) -> bool {
+        self.side(from, to, point) >= 0.0
+    }
+
+    /// Checks whether the point lies on the line through the edge.
+    pub fn on_edge(&self, from: usize, to: usize, point: &Point2<f64>) -> bool {
+        self.side(from, to, point) == 0.0
+    }
+
+    /// Checks whether the point lies inside or on the boundary of the triangle.
+    pub fn in_triangle(&self, triangle: &[usize; 3], point: &Point2<f64>) -> bool {
+        triangle
+            .iter()
+            .circular_tuple_windows()
+            .all(|(from, to)| self.is_left(*from, *to, point))
+    }
+
+    /// Checks whether the point lies inside the circumcircle of the triangle. With
+    /// vertices at infinity, the circumcircle degenerates to a half-plane.
+    pub fn in_circumcircle(&self, triangle: &[usize; 3], point: &Point2<f64>) -> bool {
+        let [a, b, c] = self.finite_first(triangle);
+        match (self.is_infinite(b), self.is_infinite(c)) {
+            _ if self.is_infinite(a) => true,
+            (false, false) => in_circle(&self.points[a], &self.points[b], &self.points[c], point),
+            (false, true) => {
+                let (a, b) = (self.points[a], self.points[b]);
+                let side = cross_product(&a, &b, point);
+                side > 0.0 || (side == 0.0 && (point - a).dot(&(point - b)) < 0.0)
+            }
+            _ => (point - self.points[a]).dot(&(self.direction(b) + self.direction(c))) > 0.0,
+        }
+    }
+
+    /// Returns the circumcircle of the triangle or the boundary of its half-plane.
+    pub fn circumcircle(&self, triangle: &[usize; 3], color: Color) -> Option<Drawable> {
+        let [a, b, c] = self.finite_first(triangle);
+        if self.is_infinite(a) {
+            return None;
+        }
+        let a = self.points[a];
+        let direction = match (self.is_infinite(b), self.is_infinite(c)) {
+            (false, false) => {
+                let (center, radius) = circumcircle(&a, &self.points[b], &self.points[c])?;
+                return Some(Drawable::circle(&center, radius, color));
+            }
+            (false, true) => (self.points[b] - a).normalize(),
+            _ => {
+                let normal = self.direction(b) + self.direction(c);
+                Vector2::new(-normal.y, normal.x).normalize()
+            }
+        };
+        Some(Drawable::line(
+            &(a - direction * FAR),
+            &(a + direction * FAR),
+            color,
+        ))
+    }
+}
@@ -0,0 +1,104 @@
+//! Helpers shared by the tests of the algorithms.
+
+use std::f64::consts::TAU;
+
+use nalgebra::Point2;
+use rand::Rng;
+
+use super::geometry::{cross_product, Rectangle};
+
+/// Returns uniformly distributed points in the square [0, 100]², which are in
+/// general position with probability one.
+pub fn random_points(amount: usize) -> Vec<Point2<f64>> {
+    let mut rng = rand::thread_rng();
+    (0..amount)
+        .map(|_| Point2::new(rng.gen_range(0.0..100.0), rng.gen_range(0.0..100.0)))
+        .collect()
+}
+
+/// Returns a counterclockwise polygon that is star-shaped around the center,
+/// with one vertex in each of the equal angular slices.
+pub fn random_star_polygon(amount: usize, center: Point2<f64>, radius: f64) -> Vec<Point2<f64>> {
+    let mut rng = rand::thread_rng();
+    let slice = TAU / amount as f64;
+    (0..amount)
+        .map(|index| {
+            let angle = (index as f64 + rng.gen_range(0.1..0.9)) * slice;
+            let distance = radius * rng.gen_range(0.2..1.0);
+            center + nalgebra::Vector2::new(angle.cos(), angle.sin()) * distance
+        })
+        .collect()
+}
+
+/// Sorts the points lexicographically.
+pub fn sorted(mut points: Vec<Point2<f64>>) -> Vec<Point2<f64>> {
+    points.sort_by(|a, b| (a.x, a.y).partial_cmp(&(b.x, b.y)).unwrap());
+    points
+}
+
+/// Returns the hull vertices of points in general position in lexicographic
+/// order, by testing every pair of points for a hull edge.
+pub fn brute_force_hull(points: &[Point2<f64>]) -> Vec<Point2<f64>> {
+    let mut hull = vec![];
+    for a in points {
+        for b in points {
+            if a != b
+                && points
+                    .iter()
+                    .all(|point| point == a || point == b || cross_product(a, b, point) > 0.0)
+            {
+                hull.push(*a);
+            }
+        }
+    }
+    sorted(hull)
+}
+
+/// Asserts that both point sets agree up to rounding, which grows with the
+/// distance from the origin.
+pub fn assert_same_points(actual: &[Point2<f64>], expected: &[Point2<f64>]) {
+    let (actual, expected) = (sorted(actual.to_vec()), sorted(expected.to_vec()));
+    assert_eq!(actual.len(), expected.len(), "{actual:?} != {expected:?}");
+    for (a, b) in actual.iter().zip(&expected) {
+        assert!(
+            (a - b).norm() < 1e-9 * (1.0 + b.coords.norm()),
+            "{actual:?} != {expected:?}"
+        );
+    }
+}
+
+/// Returns a random query rectangle as two opposite corners `[x1, y1, x2, y2]`.
+pub fn random_rectangle() -> [f64; 4] {
+    let corners = random_points(2);
+    [corners[0].x, corners[0].y, corners[1].x, corners[1].y]
+}
+
+/// Returns the indices of the points inside the rectangle, including its
+/// boundary, by testing every point.
+pub fn brute_force_range(points: &[Point2<f64>], [x1, y1, x2, y2]: [f64; 4]) -> Vec<usize> {
+    let rectangle = Rectangle::new(Point2::new(x1, y1), Point2::new(x2, y2));
+    (0..points.len())
+        .filter(|index| rectangle.contains_point(&points[*index]))
+        .collect()
+}
+
+/// Asserts that the range query reports the same points as the brute force,
+/// given as sorted indices. Besides random inputs, a grid with every point
+/// twice puts points on all sides of the query and shares coordinates.
+pub fn assert_range_queries(mut query: impl FnMut(&[Point2<f64>], [f64; 4]) -> Vec<usize>) {
+    for amount in 1..60 {
+        let points = random_points(amount);
+        let rectangle = random_rectangle();
+        assert_eq!(
+            query(&points, rectangle),
+            brute_force_range(&points, rectangle)
+        );
+    }
+
+    let mut grid = (0..36)
+        .map(|index| Point2::new((index % 6) as f64 * 10.0, (index / 6) as f64 * 10.0))
+        .collect::<Vec<_>>();
+    grid.extend(grid.clone());
+    let rectangle = [10.0, 20.0, 40.0, 30.0];
+    assert_eq!(query(&grid, rectangle), brute_force_range(&grid, rectangle));
+}
@@ -0,0 +1,621 @@
+use itertools::Itertools;
+use nalgebra::Point2;
+use rand::seq::SliceRandom;
+use tui::style::Color;
+
+use super::{
+    algorithm::{Algorithm, DrawMethod, Drawable, InputKind, QueryKind},
+    geometry::{cross_product, Segment},
+};
+
+/// A trapezoid between two segments, bounded left and right by vertical lines
+/// through two endpoints.
+#[derive(Clone, Copy)]
+struct Trapezoid {
+    top: usize,
+    bottom: usize,
+    left: usize,
+    right: usize,
+    // The leaf of the search DAG pointing at this trapezoid.
+    node: usize,
+    active: bool,
+}
+
+#[derive(Clone, Copy)]
+enum Node {
+    // Splits into the parts left and right of an endpoint.
+    X {
+        vertex: usize,
+        children: [usize; 2],
+    },
+    // Splits into the parts above and below a segment.
+    Y {
+        segment: usize,
+        children: [usize; 2],
+    },
+    Leaf(usize),
+}
+
+pub struct TrapezoidalMap {
+    // Later passed to app.
+    pub maximum_step_count: usize,
+    pub points: Vec<Point2<f64>>,
+    pub steps: Vec<Vec<Drawable>>,
+    pub descriptions: Vec<String>,
+    // Oriented from left to right. The top and bottom of the bounding box
+    // follow the input segments.
+    pub segments: Vec<Segment>,
+    // The endpoints of every segment, followed by the lower left and upper
+    // right corner of the bounding box.
+    vertices: Vec<Point2<f64>>,
+    trapezoids: Vec<Trapezoid>,
+    nodes: Vec<Node>,
+    inserted: Vec<bool>,
+    // The query point entered by the user.
+    query: Option<Point2<f64>>,
+    x_bounds: [f64; 2],
+    y_bounds: [f64; 2],
+}
+
+impl TrapezoidalMap {
+    pub fn new() -> Self {
+        TrapezoidalMap {
+            maximum_step_count: 0,
+            points: vec![],
+            steps: vec![],
+            descriptions: vec![],
+            segments: vec![],
+            vertices: vec![],
+            trapezoids: vec![],
+            nodes: vec![],
+            inserted: vec![],
+            query: None,
+            x_bounds: [0.0, 0.0],
+            y_bounds: [0.0, 0.0],
+        }
+    }
+
+    /// Stores the segments and the vertical extensions of the current map
+    /// together with the given drawables.
+    fn add_step(&mut self, drawables: Vec<Drawable>, description: String) {
+        let mut step = self
+            .segments
+            .iter()
+            .zip(&self.inserted)
+            .map(|(segment, inserted)| {
+                let color = if *inserted {
+                    Color::White
+                } else {
+                    Color::DarkGray
+                };
+                Drawable::line(&segment.from, &segment.to, color)
+            })
+            .collect::<Vec<_>>();
+        step.extend(
+            self.trapezoids
+                .iter()
+                .filter(|trapezoid| trapezoid.active)
+                .flat_map(|trapezoid| {
+                    let corners = self.corners(trapezoid);
+                    [
+                        Drawable::line(&corners[0], &corners[3], Color::Blue),
+                        Drawable::line(&corners[1], &corners[2], Color::Blue),
+                    ]
+                }),
+        );
+        step.extend(drawables);
+
+        self.steps.push(step);
+        self.descriptions.push(description);
+        self.maximum_step_count += 1;
+    }
+
+    /// Returns the corners of the trapezoid in counterclockwise order, starting
+    /// at the lower left.
+    fn corners(&self, trapezoid: &Trapezoid) -> [Point2<f64>; 4] {
+        let left = self.vertices[trapezoid.left].x;
+        let right = self.vertices[trapezoid.right].x;
+        let at = |segment: usize, x: f64| Point2::new(x, y_at(&self.segments[segment], x));
+        [
+            at(trapezoid.bottom, left),
+            at(trapezoid.bottom, right),
+            at(trapezoid.top, right),
+            at(trapezoid.top, left),
+        ]
+    }
+
+    fn trapezoid_lines(&self, trapezoid: usize, color: Color) -> Vec<Drawable> {
+        self.corners(&self.trapezoids[trapezoid])
+            .iter()
+            .circular_tuple_windows()
+            .map(|(from, to)| Drawable::line(from, to, color))
+            .collect()
+    }
+
+    fn label(&self, segment: usize) -> String {
+        match segment.checked_sub(self.segments.len() - 2) {
+            Some(0) => String::from("the top of the bounding box"),
+            Some(_) => String::from("the bottom of the bounding box"),
+            None => format!("s{segment}"),
+        }
+    }
+
+    /// Adds a trapezoid together with its leaf in the search DAG.
+    fn add_trapezoid(&mut self, top: usize, bottom: usize, left: usize, right: usize) -> usize {
+        let index = self.trapezoids.len();
+        self.nodes.push(Node::Leaf(index));
+        self.trapezoids.push(Trapezoid {
+            top,
+            bottom,
+            left,
+            right,
+            node: self.nodes.len() - 1,
+            active: true,
+        });
+        index
+    }
+
+    /// Walks down the search DAG and returns all visited nodes. A point lying on
+    /// a segment, like a shared endpoint, is placed by the direction towards
+    /// the given other point.
+    fn locate(&self, point: &Point2<f64>, towards: Option<&Point2<f64>>) -> Vec<usize> {
+        let mut path = vec![0];
+        loop {
+            match self.nodes[*path.last().unwrap()] {
+                Node::X { vertex, children } => {
+                    let right = !left_of(point, &self.vertices[vertex]);
+                    path.push(children[usize::from(right)]);
+                }
+                Node::Y { segment, children } => {
+                    let segment = &self.segments[segment];
+                    let mut side = cross_product(&segment.from, &segment.to, point);
+                    if let (0.0, Some(towards)) = (side, towards) {
+                        side = cross_product(&segment.from, &segment.to, towards);
+                    }
+                    path.push(children[usize::from(side <= 0.0)]);
+                }
+                Node::Leaf(_) => return path,
+            }
+        }
+    }
+
+    /// Finds the active trapezoid right of the given one that the segment enters.
+    fn right_neighbour(&self, trapezoid: &Trapezoid, segment: &Segment) -> usize {
+        let vertex = trapezoid.right;
+        let above = cross_product(&segment.from, &segment.to, &self.vertices[vertex]) > 0.0;
+        (0..self.trapezoids.len())
+            .find(|other| {
+                let other = &self.trapezoids[*other];
+                other.active
+                    && other.left == vertex
+                    && if above {
+                        other.bottom == trapezoid.bottom
+                    } else {
+                        other.top == trapezoid.top
+                    }
+            })
+            .unwrap()
+    }
+
+    fn insert(&mut self, index: usize, insertion: usize) {
+        let segment = self.segments[index];
+        let (p, q) = (2 * index, 2 * index + 1);
+        let label = format!("Insertion {} of {}", insertion + 1, self.inserted.len() - 2);
+
+        // Locate the left endpoint and follow the segment through the map.
+        let path = self.locate(&segment.from, Some(&segment.to));
+        let first = match self.nodes[*path.last().unwrap()] {
+            Node::Leaf(trapezoid) => trapezoid,
+            _ => unreachable!(),
+        };
+        let mut crossed = vec![first];
+        while left_of(
+            &self.vertices[self.trapezoids[*crossed.last().unwrap()].right],
+            &segment.to,
+        ) {
+            let last = self.trapezoids[*crossed.last().unwrap()];
+            crossed.push(self.right_neighbour(&last, &segment));
+        }
+
+        self.inserted[index] = true;
+        let mut drawables = crossed
+            .iter()
+            .flat_map(|trapezoid| self.trapezoid_lines(*trapezoid, Color::LightRed))
+            .collect::<Vec<_>>();
+        drawables.push(Drawable::line(&segment.from, &segment.to, Color::Yellow));
+        self.add_step(
+            drawables,
+            format!(
+                "{label}: locate the left endpoint of s{index} by visiting {} DAG nodes. The \
+                 segment crosses {} trapezoids.",
+                path.len(),
+                crossed.len()
+            ),
+        );
+
+        let trapezoids = self.trapezoids.len();
+        let first = self.trapezoids[crossed[0]];
+        let last = self.trapezoids[*crossed.last().unwrap()];
+        let left_part = left_of(&self.vertices[first.left], &segment.from)
+            .then(|| self.add_trapezoid(first.top, first.bottom, first.left, p));
+        let right_part = left_of(&segment.to, &self.vertices[last.right])
+            .then(|| self.add_trapezoid(last.top, last.bottom, q, last.right));
+
+        // Above and below the segment, the vertical extensions of endpoints on the
+        // other side are cut off. Trapezoids between them merge.
+        let mut upper = vec![self.add_trapezoid(first.top, index, p, q)];
+        let mut lower = vec![self.add_trapezoid(index, first.bottom, p, q)];
+        let mut upper_of = vec![upper[0]];
+        let mut lower_of = vec![lower[0]];
+        for (current, next) in crossed.iter().tuple_windows() {
+            let vertex = self.trapezoids[*current].right;
+            let next = self.trapezoids[*next];
+            if cross_product(&segment.from, &segment.to, &self.vertices[vertex]) > 0.0 {
+                self.trapezoids[*upper.last().unwrap()].right = vertex;
+                upper.push(self.add_trapezoid(next.top, index, vertex, q));
+            } else {
+                self.trapezoids[*lower.last().unwrap()].right = vertex;
+                lower.push(self.add_trapezoid(index, next.bottom, vertex, q));
+            }
+            upper_of.push(*upper.last().unwrap());
+            lower_of.push(*lower.last().unwrap());
+        }
+
+        // Every crossed leaf becomes a small tree splitting by the segment and,
+        // at both ends, by its endpoints.
+        for (position, trapezoid) in crossed.iter().enumerate() {
+            self.trapezoids[*trapezoid].active = false;
+            let leaf = |map: &Self, trapezoid: usize| map.trapezoids[trapezoid].node;
+            let mut node = Node::Y {
+                segment: index,
+                children: [
+                    leaf(self, upper_of[position]),
+                    leaf(self, lower_of[position]),
+                ],
+            };
+            if let (true, Some(right_part)) = (position == crossed.len() - 1, right_part) {
+                self.nodes.push(node);
+                node = Node::X {
+                    vertex: q,
+                    children: [self.nodes.len() - 1, leaf(self, right_part)],
+                };
+            }
+            if let (0, Some(left_part)) = (position, left_part) {
+                self.nodes.push(node);
+                node = Node::X {
+                    vertex: p,
+                    children: [leaf(self, left_part), self.nodes.len() - 1],
+                };
+            }
+            let replaced = self.trapezoids[*trapezoid].node;
+            self.nodes[replaced] = node;
+        }
+
+        let created = (trapezoids..self.trapezoids.len()).collect::<Vec<_>>();
+        let mut drawables = created
+            .iter()
+            .flat_map(|trapezoid| self.trapezoid_lines(*trapezoid, Color::Green))
+            .collect::<Vec<_>>();
+        drawables.push(Drawable::line(&segment.from, &segment.to, Color::Yellow));
+        self.add_step(
+            drawables,
+            format!(
+                "{label}: replace them by {} trapezoids. Their leaves in the search DAG become \
+                 nodes testing the endpoints and s{index}. The DAG has {} nodes now.",
+                created.len(),
+                self.nodes.len()
+            ),
+        );
+    }
+
+    /// Calculates the length of the longest path in the search DAG.
+    fn depth(&self, node: usize, depths: &mut Vec<Option<usize>>) -> usize {
+        if let Some(depth) = depths[node] {
+            return depth;
+        }
+        let depth = match self.nodes[node] {
+            Node::X { children, .. } | Node::Y { children, .. } => {
+                1 + children
+                    .iter()
+                    .map(|child| self.depth(*child, depths))
+                    .max()
+                    .unwrap()
+            }
+            Node::Leaf(_) => 1,
+        };
+        depths[node] = Some(depth);
+        depth
+    }
+
+    pub fn calculate(&mut self) {
+        self.steps = vec![];
+        self.descriptions = vec![];
+        self.maximum_step_count = 0;
+        self.trapezoids = vec![];
+        self.nodes = vec![];
+
+        // The bounding box is part of the map, so its top and bottom are
+        // handled like segments.
+        let length = self.points.len() / 2;
+        let (lower_left, upper_right) = (
+            Point2::new(self.x_bounds[0], self.y_bounds[0]),
+            Point2::new(self.x_bounds[1], self.y_bounds[1]),
+        );
+        self.segments.truncate(length);
+        self.segments.extend([
+            Segment::new(Point2::new(lower_left.x, upper_right.y), upper_right),
+            Segment::new(lower_left, Point2::new(upper_right.x, lower_left.y)),
+        ]);
+        self.inserted = vec![false; length];
+        self.inserted.extend([true, true]);
+        self.vertices = self.points.clone();
+        self.vertices.extend([lower_left, upper_right]);
+        self.add_trapezoid(length, length + 1, 2 * length, 2 * length + 1);
+        self.add_step(
+            vec![],
+            format!(
+                "Start with the bounding box as the only trapezoid. It is the only leaf of the \
+                 search DAG. Insert the {length} segments in random order."
+            ),
+        );
+
+        let mut order = (0..length).collect::<Vec<_>>();
+        order.shuffle(&mut rand::thread_rng());
+        for (insertion, index) in order.into_iter().enumerate() {
+            self.insert(index, insertion);
+        }
+
+        let depth = self.depth(0, &mut vec![None; self.nodes.len()]);
+        let active = self.trapezoids.iter().filter(|trapezoid| trapezoid.active);
+        self.add_step(
+            vec![],
+            format!(
+                "The map has {} trapezoids, at most 3n + 1 = {}. The search DAG has {} nodes \
+                 and depth {depth}, in expectation O(log n) with log₂ n ≈ {:.1}.",
+                active.count(),
+                3 * length + 1,
+                self.nodes.len(),
+                (length.max(1) as f64).log2()
+            ),
+        );
+
+        // Without a query of the user, locate the center of the bounds.
+        let query = self
+            .query
+            .unwrap_or(lower_left + (upper_right - lower_left) / 2.0);
+        let path = self.locate(&query, None);
+        for (depth, node) in path.iter().enumerate() {
+            let mut drawables = vec![];
+            let description = match self.nodes[*node] {
+                Node::X { vertex, .. } => {
+                    let point = self.vertices[vertex];
+                    drawables.push(Drawable::line(
+                        &Point2::new(point.x, lower_left.y),
+                        &Point2::new(point.x, upper_right.y),
+                        Color::Yellow,
+                    ));
+                    drawables.push(Drawable::points(&[point], Color::Yellow));
+                    format!(
+                        "The query visits node {} of its path, an endpoint of s{}: it lies {} \
+                         of ({:.2}, {:.2}).",
+                        depth + 1,
+                        vertex / 2,
+                        if left_of(&query, &point) {
+                            "left"
+                        } else {
+                            "right"
+                        },
+                        point.x,
+                        point.y
+                    )
+                }
+                Node::Y { segment, .. } => {
+                    let line = &self.segments[segment];
+                    drawables.push(Drawable::line(&line.from, &line.to, Color::Yellow));
+                    format!(
+                        "The query visits node {} of its path, the segment s{segment}: it lies \
+                         {} of it.",
+                        depth + 1,
+                        if cross_product(&line.from, &line.to, &query) > 0.0 {
+                            "above"
+                        } else {
+                            "below"
+                        }
+                    )
+                }
+                Node::Leaf(trapezoid) => {
+                    drawables.extend(self.trapezoid_lines(trapezoid, Color::Green));
+                    let trapezoid = self.trapezoids[trapezoid];
+                    format!(
+                        "The query ends at a leaf after {} nodes. It lies in the trapezoid \
+                         below {} and above {}. Press r to locate another point.",
+                        path.len(),
+                        self.label(trapezoid.top),
+                        self.label(trapezoid.bottom)
+                    )
+                }
+            };
+            drawables.push(Drawable::points(&[query], Color::Yellow));
+            self.add_step(drawables, description);
+        }
+    }
+}
+
+/// Orders points by x and breaks ties by y, as if the plane was sheared
+/// slightly. This keeps the map well-defined for endpoints sharing an x.
+fn left_of(a: &Point2<f64>, b: &Point2<f64>) -> bool {
+    (a.x, a.y) < (b.x, b.y)
+}
+
+fn y_at(segment: &Segment, x: f64) -> f64 {
+    let direction = segment.to - segment.from;
+    if direction.x == 0.0 {
+        return segment.from.y;
+    }
+    segment.from.y + (x - segment.from.x) / direction.x * direction.y
+}
+
+impl Algorithm for TrapezoidalMap {
+    fn get_title(&self) -> &str {
+        "Trapezoidal map"
+    }
+
+    fn get_points(&self) -> &Vec<Point2<f64>> {
+        &self.points
+    }
+
+    fn set_points(&mut self, points: Vec<Point2<f64>>) {
+        self.points = points;
+    }
+
+    fn set_segments(&mut self, segments: Vec<Segment>) {
+        // Orient all segments from left to right.
+        self.segments = segments
+            .into_iter()
+            .map(|segment| {
+                if left_of(&segment.to, &segment.from) {
+                    Segment::new(segment.to, segment.from)
+                } else {
+                    segment
+                }
+            })
+            .collect();
+        self.points = self
+            .segments
+            .iter()
+            .flat_map(|segment| [segment.from, segment.to])
+            .collect();
+    }
+
+    fn set_bounds(&mut self, x_bounds: [f64; 2], y_bounds: [f64; 2]) {
+        self.x_bounds = x_bounds;
+        self.y_bounds = y_bounds;
+    }
+
+    fn get_input_kind(&self) -> InputKind {
+        InputKind::NonCrossingSegments
+    }
+
+    fn get_query_kind(&self) -> Option<QueryKind> {
+        Some(QueryKind::Point)
+    }
+
+    fn set_query(&mut self, query: Vec<f64>) -> bool {
+        match query[..] {
+            [x, y] => {
+                self.query = Some(Point2::new(x, y));
+                true
+            }
+            _ => false,
+        }
+    }
+
+    fn get_steps(&self) -> Vec<Vec<Drawable>> {
+        self.steps.clone()
+    }
+
+    fn get_draw_method(&self) -> DrawMethod {
+        DrawMethod::Edge
+    }
+
+    fn get_step_description(&self, step: usize) -> Option<String> {
+        self.descriptions.get(step).cloned()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::algorithms::testing::random_points;
+
+    fn trapezoidal_map(segments: Vec<Segment>) -> TrapezoidalMap {
+        let mut trapezoidal_map = TrapezoidalMap::new();
+        trapezoidal_map.set_bounds([-10.0, 110.0], [-10.0, 110.0]);
+        trapezoidal_map.set_segments(segments);
+        trapezoidal_map.calculate();
+        trapezoidal_map
+    }
+
+    /// Returns one random segment inside every cell of a grid over [0, 100]²,
+    /// so no two segments touch.
+    fn random_segments(amount: usize) -> Vec<Segment> {
+        let cells = (amount as f64).sqrt().ceil().max(1.0) as usize;
+        let size = 100.0 / cells as f64;
+        (0..amount)
+            .map(|cell| {
+                let corner = Point2::new((cell % cells) as f64, (cell / cells) as f64) * size;
+                let [from, to] = [0, 1].map(|_| corner + random_points(1)[0].coords * size / 100.0);
+                Segment::new(from, to)
+            })
+            .collect()
+    }
+
+    /// Finds the nearest segments above and below the point by testing every
+    /// segment spanning its x-coordinate.
+    fn brute_force_locate(trapezoidal_map: &TrapezoidalMap, point: &Point2<f64>) -> [usize; 2] {
+        let spanning = (0..trapezoidal_map.segments.len())
+            .filter(|segment| {
+                let segment = &trapezoidal_map.segments[*segment];
+                segment.from.x <= point.x && point.x <= segment.to.x
+            })
+            .map(|segment| {
+                (
+                    segment,
+                    y_at(&trapezoidal_map.segments[segment], point.x) - point.y,
+                )
+            })
+            .collect::<Vec<_>>();
+        let nearest = |above: bool| {
+            spanning
+                .iter()
+                .filter(|(_, offset)| (*offset > 0.0) == above)
+                .min_by(|a, b| a.1.abs().partial_cmp(&b.1.abs()).unwrap())
+                .unwrap()
+                .0
+        };
+        [nearest(true), nearest(false)]
+    }
+
+    #[test]
+    fn small_inputs_locate_their_query() {
+        for amount in 0..3 {
+            let trapezoidal_map = trapezoidal_map(random_segments(amount));
+            assert!(trapezoidal_map.maximum_step_count >= 3);
+            let active = trapezoidal_map
+                .trapezoids
+                .iter()
+                .filter(|trapezoid| trapezoid.active)
+                .count();
+            assert_eq!(active, 3 * amount + 1);
+        }
+    }
+
+    #[test]
+    fn point_location_matches_brute_force() {
+        for amount in 1..40 {
+            let trapezoidal_map = trapezoidal_map(random_segments(amount));
+            let active = trapezoidal_map
+                .trapezoids
+                .iter()
+                .filter(|trapezoid| trapezoid.active)
+                .count();
+            assert_eq!(active, 3 * amount + 1);
+
+            for query in random_points(50) {
+                let path = trapezoidal_map.locate(&query, None);
+                let trapezoid = match trapezoidal_map.nodes[*path.last().unwrap()] {
+                    Node::Leaf(trapezoid) => trapezoidal_map.trapezoids[trapezoid],
+                    _ => unreachable!(),
+                };
+                assert!(trapezoid.active);
+                assert_eq!(
+                    [trapezoid.top, trapezoid.bottom],
+                    brute_force_locate(&trapezoidal_map, &query)
+                );
+                let [left, right] = [trapezoid.left, trapezoid.right]
+                    .map(|vertex| trapezoidal_map.vertices[vertex].x);
+                assert!(left <= query.x && query.x <= right);
+            }
+        }
+    }
+}
@@ -0,0 +1,652 @@
+use std::f64::consts::TAU;
+
+use itertools::Itertools;
+use nalgebra::Point2;
+use tui::style::Color;
+
+use super::{
+    algorithm::{Algorithm, DrawMethod, Drawable, InputKind, QueryKind},
+    geometry::{cross_product, in_polygon, Segment},
+};
+
+/// A vertex of the graph, either a corner of an obstacle or one of the start
+/// and the goal.
+struct Vertex {
+    point: Point2<f64>,
+    obstacle: Option<usize>,
+    // The neighbouring corners along the boundary of the obstacle.
+    neighbours: Vec<usize>,
+}
+
+pub struct VisibilityGraph {
+    // Later passed to app.
+    pub maximum_step_count: usize,
+    pub points: Vec<Point2<f64>>,
+    pub steps: Vec<Vec<Drawable>>,
+    pub descriptions: Vec<String>,
+    // Pairs of vertices that see each other.
+    pub edges: Vec<(usize, usize)>,
+    // The vertices of the shortest path from the start to the goal.
+    pub path: Vec<usize>,
+    obstacles: Vec<Vec<Point2<f64>>>,
+    vertices: Vec<Vertex>,
+    // The start and the goal entered by the user.
+    query: Option<[Point2<f64>; 2]>,
+    x_bounds: [f64; 2],
+    y_bounds: [f64; 2],
+}
+
+impl VisibilityGraph {
+    pub fn new() -> Self {
+        VisibilityGraph {
+            maximum_step_count: 0,
+            points: vec![],
+            steps: vec![],
+            descriptions: vec![],
+            edges: vec![],
+            path: vec![],
+            obstacles: vec![],
+            vertices: vec![],
+            query: None,
+            x_bounds: [0.0, 0.0],
+            y_bounds: [0.0, 0.0],
+        }
+    }
+
+    /// Stores the obstacles, the visibility graph so far and the start and goal
+    /// together with the given drawables.
+    fn add_step(&mut self, drawables: Vec<Drawable>, description: String) {
+        let mut step = self
+            .edges
+            .iter()
+            .map(|(a, b)| {
+                Drawable::line(
+                    &self.vertices[*a].point,
+                    &self.vertices[*b].point,
+                    Color::DarkGray,
+                )
+            })
+            .collect::<Vec<_>>();
+        step.extend(self.obstacles.iter().flat_map(|obstacle| {
+            obstacle
+                .iter()
+                .circular_tuple_windows()
+                .map(|(from, to)| Drawable::line(from, to, Color::Blue))
+        }));
+        step.extend(drawables);
+        step.push(Drawable::points(
+            &self
+                .vertices
+                .iter()
+                .filter(|vertex| vertex.obstacle.is_none())
+                .map(|vertex| vertex.point)
+                .collect::<Vec<_>>(),
+            Color::Magenta,
+        ));
+
+        self.steps.push(step);
+        self.descriptions.push(description);
+        self.maximum_step_count += 1;
+    }
+
+    fn obstacle_edges(&self) -> Vec<(usize, usize)> {
+        (0..self.vertices.len())
+            .flat_map(|vertex| {
+                self.vertices[vertex]
+                    .neighbours
+                    .iter()
+                    .filter(move |neighbour| vertex < **neighbour)
+                    .map(move |neighbour| (vertex, *neighbour))
+            })
+            .collect()
+    }
+
+    /// Returns where the line through the origin and the target meets the line
+    /// through the edge, as a multiple of the distance from the origin to the
+    /// target.
+    fn ray_distance(
+        &self,
+        origin: &Point2<f64>,
+        target: &Point2<f64>,
+        edge: (usize, usize),
+    ) -> f64 {
+        let (a, b) = (self.vertices[edge.0].point, self.vertices[edge.1].point);
+        let (from_origin, from_target) =
+            (cross_product(&a, &b, origin), cross_product(&a, &b, target));
+        from_origin / (from_origin - from_target)
+    }
+
+    /// Sweeps a ray around the vertex and returns the vertices it sees. The
+    /// status holds the obstacle edges crossing the ray ordered by their
+    /// distance along it. Obstacles do not cross, so the order only changes
+    /// at vertices and only the nearest edge can block the next vertex.
+    fn sweep(&self, origin: usize) -> (Vec<usize>, Vec<usize>, usize) {
+        let point = self.vertices[origin].point;
+        let angle = |vertex: &usize| {
+            let direction = self.vertices[*vertex].point - point;
+            direction.y.atan2(direction.x).rem_euclid(TAU)
+        };
+        let distance = |vertex: &usize| (self.vertices[*vertex].point - point).norm();
+        let mut order = (0..self.vertices.len())
+            .filter(|vertex| *vertex != origin)
+            .collect::<Vec<_>>();
+        order.sort_by(|a, b| {
+            angle(a)
+                .partial_cmp(&angle(b))
+                .unwrap()
+                .then(distance(a).partial_cmp(&distance(b)).unwrap())
+        });
+
+        // Start with the edges crossing the ray pointing to the right. It reaches
+        // past every vertex, even from a start or goal outside of the bounds.
+        let length = self
+            .vertices
+            .iter()
+            .map(|vertex| (vertex.point - point).norm())
+            .fold(0.0, f64::max)
+            + 1.0;
+        let right = Point2::new(point.x + length, point.y);
+        let ray = Segment::new(point, right);
+        let segment = |(a, b): &(usize, usize)| {
+            Segment::new(self.vertices[*a].point, self.vertices[*b].point)
+        };
+        let mut status = self
+            .obstacle_edges()
+            .into_iter()
+            .filter(|edge| ray.intersection(&segment(edge)).is_some())
+            .collect::<Vec<_>>();
+        status.sort_by(|a, b| {
+            self.ray_distance(&point, &right, *a)
+                .partial_cmp(&self.ray_distance(&point, &right, *b))
+                .unwrap()
+        });
+        let mut largest_status = status.len();
+
+        let (mut visible, mut blocked) = (vec![], vec![]);
+        let mut previous: Option<(usize, bool)> = None;
+        for vertex in order {
+            let target = self.vertices[vertex].point;
+            // Edges turning clockwise from the ray stop crossing it, the others start.
+            let (mut starting, mut stopping) = (vec![], vec![]);
+            for neighbour in self.vertices[vertex].neighbours.iter() {
+                if *neighbour == origin {
+                    continue;
+                }
+                let edge = (vertex.min(*neighbour), vertex.max(*neighbour));
+                let turn = cross_product(&point, &target, &self.vertices[*neighbour].point);
+                if turn > 0.0 {
+                    starting.push((edge, *neighbour));
+                } else if turn < 0.0 {
+                    stopping.push(edge);
+                }
+            }
+            status.retain(|edge| !stopping.contains(edge));
+
+            // A diagonal of a convex obstacle runs through its interior.
+            let diagonal = self.vertices[origin].obstacle.is_some()
+                && self.vertices[origin].obstacle == self.vertices[vertex].obstacle
+                && !self.vertices[origin].neighbours.contains(&vertex);
+            let sight = Segment::new(point, target);
+            let crossed = status
+                .first()
+                .is_some_and(|edge| sight.intersection(&segment(edge)).is_some());
+            // A previous vertex on the line of sight blocks it, if it is blocked
+            // itself or the line of sight enters its obstacle there.
+            let hidden = previous.is_some_and(|(previous, seen)| {
+                let previous_point = self.vertices[previous].point;
+                cross_product(&point, &previous_point, &target) == 0.0
+                    && (previous_point - point).dot(&(target - point)) > 0.0
+                    && (!seen
+                        || self.vertices[previous].obstacle.is_some_and(|obstacle| {
+                            in_polygon(
+                                &nalgebra::center(&previous_point, &target),
+                                &self.obstacles[obstacle],
+                            )
+                        }))
+            });
+            let seen = !(diagonal || crossed || hidden);
+            if seen {
+                visible.push(vertex);
+            } else {
+                blocked.push(vertex);
+            }
+            previous = Some((vertex, seen));
+
+            // The starting edges meet the ray at the vertex. Among them, the one
+            // separating the origin from the other one lies nearer.
+            for (edge, neighbour) in starting {
+                let position = status.partition_point(|other| {
+                    if other.0 == vertex || other.1 == vertex {
+                        let end = &self.vertices[other.0 + other.1 - vertex].point;
+                        (cross_product(&target, end, &point) > 0.0)
+                            != (cross_product(&target, end, &self.vertices[neighbour].point) > 0.0)
+                    } else {
+                        self.ray_distance(&point, &target, *other) < 1.0
+                    }
+                });
+                status.insert(position, edge);
+            }
+            largest_status = largest_status.max(status.len());
+        }
+        (visible, blocked, largest_status)
+    }
+
+    /// Runs Dijkstra's algorithm from the start to the goal on the visibility
+    /// graph, with one step per settled vertex.
+    fn shortest_path(&mut self, start: usize, goal: usize) {
+        let mut distances = vec![f64::INFINITY; self.vertices.len()];
+        let mut predecessors = vec![None; self.vertices.len()];
+        let mut settled = vec![false; self.vertices.len()];
+        distances[start] = 0.0;
+
+        while let Some(vertex) = (0..self.vertices.len())
+            .filter(|vertex| !settled[*vertex] && distances[*vertex].is_finite())
+            .min_by(|a, b| distances[*a].partial_cmp(&distances[*b]).unwrap())
+        {
+            settled[vertex] = true;
+            let mut relaxed = 0;
+            for (a, b) in self.edges.clone() {
+                let neighbour = match (a == vertex, b == vertex) {
+                    (true, _) => b,
+                    (_, true) => a,
+                    _ => continue,
+                };
+                let distance = distances[vertex]
+                    + (self.vertices[vertex].point - self.vertices[neighbour].point).norm();
+                if !settled[neighbour] && distance < distances[neighbour] {
+                    distances[neighbour] = distance;
+                    predecessors[neighbour] = Some(vertex);
+                    relaxed += 1;
+                }
+            }
+
+            let mut drawables = (0..self.vertices.len())
+                .filter_map(|other| predecessors[other].map(|predecessor| (other, predecessor)))
+                .map(|(other, predecessor)| {
+                    Drawable::line(
+                        &self.vertices[other].point,
+                        &self.vertices[predecessor].point,
+                        if settled[other] {
+                            Color::Green
+                        } else {
+                            Color::Cyan
+                        },
+                    )
+                })
+                .collect::<Vec<_>>();
+            drawables.push(Drawable::points(
+                &[self.vertices[vertex].point],
+                Color::Yellow,
+            ));
+            let name = if vertex == start {
+                String::from("the start")
+            } else if vertex == goal {
+                String::from("the goal")
+            } else {
+                format!("vertex {vertex}")
+            };
+            self.add_step(
+                drawables,
+                format!(
+                    "Dijkstra settles {name} at distance {:.2} and shortens the tentative \
+                     distance of {relaxed} neighbours.",
+                    distances[vertex]
+                ),
+            );
+            if vertex == goal {
+                break;
+            }
+        }
+
+        if !settled[goal] {
+            self.add_step(
+                vec![],
+                String::from("The goal is not reachable from the start."),
+            );
+            return;
+        }
+        self.path = vec![goal];
+        while let Some(predecessor) = predecessors[*self.path.last().unwrap()] {
+            self.path.push(predecessor);
+        }
+        self.path.reverse();
+        let drawables = self
+            .path
+            .iter()
+            .tuple_windows()
+            .map(|(a, b)| {
+                Drawable::line(
+                    &self.vertices[*a].point,
+                    &self.vertices[*b].point,
+                    Color::Yellow,
+                )
+            })
+            .collect();
+        self.add_step(
+            drawables,
+            format!(
+                "The shortest path has length {:.2} and bends at {} obstacle corners.",
+                distances[goal],
+                self.path.len() - 2
+            ),
+        );
+    }
+
+    pub fn calculate(&mut self) {
+        self.steps = vec![];
+        self.descriptions = vec![];
+        self.maximum_step_count = 0;
+        self.edges = vec![];
+        self.path = vec![];
+        self.vertices = vec![];
+
+        for (index, obstacle) in self.obstacles.iter().enumerate() {
+            let offset = self.vertices.len();
+            self.vertices
+                .extend(obstacle.iter().enumerate().map(|(corner, point)| Vertex {
+                    point: *point,
+                    obstacle: Some(index),
+                    neighbours: vec![
+                        offset + (corner + obstacle.len() - 1) % obstacle.len(),
+                        offset + (corner + 1) % obstacle.len(),
+                    ],
+                }));
+        }
+        // Without a query of the user, connect the lower left and the upper right
+        // corner of the bounds, which obstacles keep clear of.
+        let [start, goal] = self.query.unwrap_or_else(|| {
+            let (width, height) = (
+                self.x_bounds[1] - self.x_bounds[0],
+                self.y_bounds[1] - self.y_bounds[0],
+            );
+            [
+                Point2::new(
+                    self.x_bounds[0] + width / 20.0,
+                    self.y_bounds[0] + height / 20.0,
+                ),
+                Point2::new(
+                    self.x_bounds[1] - width / 20.0,
+                    self.y_bounds[1] - height / 20.0,
+                ),
+            ]
+        });
+        for point in [start, goal] {
+            self.vertices.push(Vertex {
+                point,
+                obstacle: None,
+                neighbours: vec![],
+            });
+        }
+        let (start, goal) = (self.vertices.len() - 2, self.vertices.len() - 1);
+
+        let inside = self.obstacles.iter().any(|obstacle| {
+            [start, goal]
+                .iter()
+                .any(|vertex| in_polygon(&self.vertices[*vertex].point, obstacle))
+        });
+        if inside {
+            self.add_step(
+                vec![],
+                String::from(
+                    "The start and the goal have to lie outside of all obstacles. Press r to \
+                     enter another start and goal.",
+                ),
+            );
+            return;
+        }
+        self.add_step(
+            vec![],
+            format!(
+                "Find a shortest path around {} convex obstacles. Its bends lie at obstacle \
+                 corners, so build the visibility graph of the {} corners, the start and the \
+                 goal. Press r to enter another start and goal.",
+                self.obstacles.len(),
+                self.vertices.len() - 2
+            ),
+        );
+
+        for origin in 0..self.vertices.len() {
+            let (visible, blocked, largest_status) = self.sweep(origin);
+            let point = self.vertices[origin].point;
+            self.edges.extend(
+                visible
+                    .iter()
+                    .filter(|vertex| origin < **vertex)
+                    .map(|vertex| (origin, *vertex)),
+            );
+            let mut drawables = blocked
+                .iter()
+                .map(|vertex| {
+                    Drawable::line(&point, &self.vertices[*vertex].point, Color::LightRed)
+                })
+                .collect::<Vec<_>>();
+            drawables.extend(
+                visible.iter().map(|vertex| {
+                    Drawable::line(&point, &self.vertices[*vertex].point, Color::Green)
+                }),
+            );
+            drawables.push(Drawable::points(&[point], Color::Yellow));
+            self.add_step(
+                drawables,
+                format!(
+                    "Sweep a ray around vertex {origin} in angular order: it sees {} vertices \
+                     and {} are blocked. At most {largest_status} obstacle edges crossed the \
+                     ray at once.",
+                    visible.len(),
+                    blocked.len()
+                ),
+            );
+        }
+        self.add_step(
+            vec![],
+            format!(
+                "The visibility graph has {} vertices and {} edges. Each sweep sorts the \
+                 vertices by angle and tests only the nearest edge of the status. With a \
+                 balanced search tree as status, all sweeps take O(n² log n) time.",
+                self.vertices.len(),
+                self.edges.len()
+            ),
+        );
+
+        self.shortest_path(start, goal);
+    }
+}
+
+impl Algorithm for VisibilityGraph {
+    fn get_title(&self) -> &str {
+        "Visibility graph"
+    }
+
+    fn get_points(&self) -> &Vec<Point2<f64>> {
+        &self.points
+    }
+
+    fn set_points(&mut self, points: Vec<Point2<f64>>) {
+        self.points = points;
+    }
+
+    fn set_polygons(&mut self, polygons: Vec<Vec<Point2<f64>>>) {
+        self.points = polygons.concat();
+        self.obstacles = polygons;
+    }
+
+    fn set_bounds(&mut self, x_bounds: [f64; 2], y_bounds: [f64; 2]) {
+        self.x_bounds = x_bounds;
+        self.y_bounds = y_bounds;
+    }
+
+    fn get_input_kind(&self) -> InputKind {
+        InputKind::ConvexObstacles
+    }
+
+    fn get_query_kind(&self) -> Option<QueryKind> {
+        Some(QueryKind::StartAndGoal)
+    }
+
+    fn set_query(&mut self, query: Vec<f64>) -> bool {
+        match query[..] {
+            [x1, y1, x2, y2] => {
+                self.query = Some([Point2::new(x1, y1), Point2::new(x2, y2)]);
+                true
+            }
+            _ => false,
+        }
+    }
+
+    fn get_steps(&self) -> Vec<Vec<Drawable>> {
+        self.steps.clone()
+    }
+
+    fn get_draw_method(&self) -> DrawMethod {
+        DrawMethod::Edge
+    }
+
+    fn get_step_description(&self, step: usize) -> Option<String> {
+        self.descriptions.get(step).cloned()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::Rng;
+
+    use super::*;
+
+    /// Returns convex obstacles in the cells of a grid, each with its vertices
+    /// on a circle.
+    fn random_obstacles() -> Vec<Vec<Point2<f64>>> {
+        let mut rng = rand::thread_rng();
+        let mut obstacles = vec![];
+        for (column, row) in (0..3).cartesian_product(0..3) {
+            if rng.gen_bool(0.2) {
+                continue;
+            }
+            let center = Point2::new(20.0 + 30.0 * column as f64, 20.0 + 30.0 * row as f64);
+            let mut angles = (0..rng.gen_range(3..7))
+                .map(|_| rng.gen_range(0.0..TAU))
+                .collect::<Vec<_>>();
+            angles.sort_by(|a, b| a.partial_cmp(b).unwrap());
+            let radius = rng.gen_range(5.0..14.0);
+            obstacles.push(
+                angles
+                    .iter()
+                    .map(|angle| center + nalgebra::Vector2::new(angle.cos(), angle.sin()) * radius)
+                    .collect(),
+            );
+        }
+        obstacles
+    }
+
+    fn visibility_graph(obstacles: Vec<Vec<Point2<f64>>>) -> VisibilityGraph {
+        let mut visibility_graph = VisibilityGraph::new();
+        visibility_graph.set_bounds([0.0, 100.0], [0.0, 100.0]);
+        visibility_graph.set_polygons(obstacles);
+        visibility_graph.calculate();
+        visibility_graph
+    }
+
+    /// Two vertices see each other if their connection neither crosses an
+    /// obstacle edge nor runs through the interior of an obstacle.
+    fn brute_force(visibility_graph: &VisibilityGraph) -> Vec<(usize, usize)> {
+        let vertices = &visibility_graph.vertices;
+        let edges = visibility_graph.obstacle_edges();
+        (0..vertices.len())
+            .tuple_combinations()
+            .filter(|(a, b)| {
+                if vertices[*a].neighbours.contains(b) {
+                    return true;
+                }
+                let sight = Segment::new(vertices[*a].point, vertices[*b].point);
+                let middle = nalgebra::center(&sight.from, &sight.to);
+                // Edges ending at either vertex only touch the line of sight.
+                edges
+                    .iter()
+                    .filter(|(from, to)| ![from, to].iter().any(|end| [a, b].contains(end)))
+                    .all(|(from, to)| {
+                        let edge = Segment::new(vertices[*from].point, vertices[*to].point);
+                        sight.intersection(&edge).is_none()
+                    })
+                    && visibility_graph
+                        .obstacles
+                        .iter()
+                        .all(|obstacle| !in_polygon(&middle, obstacle))
+            })
+            .collect()
+    }
+
+    #[test]
+    fn matches_brute_force_visibility() {
+        for _ in 0..100 {
+            let visibility_graph = visibility_graph(random_obstacles());
+            let mut edges = visibility_graph.edges.clone();
+            edges.sort();
+            assert_eq!(edges, brute_force(&visibility_graph));
+        }
+    }
+
+    #[test]
+    fn sees_correctly_from_outside_the_bounds() {
+        let mut visibility_graph = VisibilityGraph::new();
+        visibility_graph.set_bounds([0.0, 100.0], [0.0, 100.0]);
+        let square = |x: f64, y: f64, size: f64| {
+            [(0.0, 0.0), (1.0, 0.0), (1.0, 1.0), (0.0, 1.0)]
+                .map(|(dx, dy)| Point2::new(x + dx * size, y + dy * size))
+                .to_vec()
+        };
+        // The first square hides the second one and the goal from the start.
+        // Both lie on the ray to the right of the start.
+        visibility_graph.set_polygons(vec![square(10.0, 40.0, 20.0), square(40.0, 45.0, 10.0)]);
+        visibility_graph.set_query(vec![-500.0, 50.0, 600.0, 50.5]);
+        visibility_graph.calculate();
+        let mut edges = visibility_graph.edges.clone();
+        edges.sort();
+        assert_eq!(edges, brute_force(&visibility_graph));
+    }
+
+    #[test]
+    fn finds_shortest_paths() {
+        for _ in 0..20 {
+            let visibility_graph = visibility_graph(random_obstacles());
+            let vertices = &visibility_graph.vertices;
+            let length = |a: usize, b: usize| (vertices[a].point - vertices[b].point).norm();
+
+            // Floyd-Warshall on the brute force graph.
+            let mut distances = vec![vec![f64::INFINITY; vertices.len()]; vertices.len()];
+            for (a, b) in brute_force(&visibility_graph) {
+                distances[a][b] = length(a, b);
+                distances[b][a] = length(a, b);
+            }
+            for via in 0..vertices.len() {
+                for a in 0..vertices.len() {
+                    for b in 0..vertices.len() {
+                        distances[a][b] =
+                            distances[a][b].min(distances[a][via] + distances[via][b]);
+                    }
+                }
+            }
+
+            let path = &visibility_graph.path;
+            let (start, goal) = (vertices.len() - 2, vertices.len() - 1);
+            assert_eq!((path[0], path[path.len() - 1]), (start, goal));
+            let total = path
+                .iter()
+                .tuple_windows()
+                .map(|(a, b)| length(*a, *b))
+                .sum::<f64>();
+            assert!((total - distances[start][goal]).abs() < 1e-9);
+        }
+    }
+
+    #[test]
+    fn rejects_a_start_inside_an_obstacle() {
+        let mut visibility_graph = VisibilityGraph::new();
+        visibility_graph.set_bounds([0.0, 100.0], [0.0, 100.0]);
+        let square = [(10.0, 10.0), (30.0, 10.0), (30.0, 30.0), (10.0, 30.0)]
+            .map(|(x, y)| Point2::new(x, y));
+        visibility_graph.set_polygons(vec![square.to_vec()]);
+        visibility_graph.set_query(vec![20.0, 20.0, 90.0, 90.0]);
+        visibility_graph.calculate();
+        assert!(visibility_graph.edges.is_empty());
+        assert!(visibility_graph.path.is_empty());
+    }
+}
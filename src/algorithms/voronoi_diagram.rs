@@ -0,0 +1,289 @@
+use std::collections::HashMap;
+
+use itertools::Itertools;
+use nalgebra::{Point2, Vector2};
+use tui::style::Color;
+
+use super::{
+    algorithm::{Algorithm, DrawMethod, Drawable},
+    bowyer_watson::BowyerWatson,
+    geometry::{circumcircle, clip_segment},
+};
+
+pub struct VoronoiDiagram {
+    // Later passed to app.
+    pub maximum_step_count: usize,
+    pub points: Vec<Point2<f64>>,
+    pub steps: Vec<Vec<Drawable>>,
+    pub descriptions: Vec<String>,
+    // Voronoi edges clipped to the bounds.
+    pub edges: Vec<(Point2<f64>, Point2<f64>)>,
+    triangles: Vec<[usize; 3]>,
+    // The circumcenter of every Delaunay triangle.
+    centers: Vec<Point2<f64>>,
+    show_delaunay: bool,
+    x_bounds: [f64; 2],
+    y_bounds: [f64; 2],
+}
+
+impl VoronoiDiagram {
+    pub fn new() -> Self {
+        VoronoiDiagram {
+            maximum_step_count: 0,
+            points: vec![],
+            steps: vec![],
+            descriptions: vec![],
+            edges: vec![],
+            triangles: vec![],
+            centers: vec![],
+            show_delaunay: true,
+            x_bounds: [0.0, 0.0],
+            y_bounds: [0.0, 0.0],
+        }
+    }
+
+    /// Stores the Delaunay layer, if it is shown, and the Voronoi layer
+    /// together with the given drawables.
+    fn add_step(&mut self, drawables: Vec<Drawable>, description: String) {
+        let mut step = vec![];
+        if self.show_delaunay {
+            step.extend(
+                self.triangles
+                    .iter()
+                    .flat_map(|triangle| self.triangle_lines(triangle, Color::Blue)),
+            );
+        }
+        step.extend(
+            self.edges
+                .iter()
+                .map(|(from, to)| Drawable::line(from, to, Color::Green)),
+        );
+        step.push(Drawable::points(&self.centers, Color::Green));
+        step.extend(drawables);
+
+        self.steps.push(step);
+        self.descriptions.push(description);
+        self.maximum_step_count += 1;
+    }
+
+    fn triangle_lines(&self, triangle: &[usize; 3], color: Color) -> Vec<Drawable> {
+        triangle
+            .iter()
+            .circular_tuple_windows()
+            .map(|(from, to)| Drawable::line(&self.points[*from], &self.points[*to], color))
+            .collect()
+    }
+
+    /// Clips the Voronoi edge to the bounds and stores it, if anything is left.
+    fn add_edge(&mut self, from: Point2<f64>, to: Point2<f64>) -> Option<Drawable> {
+        let (from, to) = clip_segment(&from, &to, self.x_bounds, self.y_bounds)?;
+        self.edges.push((from, to));
+        Some(Drawable::line(&from, &to, Color::Yellow))
+    }
+
+    pub fn calculate(&mut self) {
+        self.steps = vec![];
+        self.descriptions = vec![];
+        self.maximum_step_count = 0;
+        self.edges = vec![];
+        self.centers = vec![];
+
+        let mut delaunay = BowyerWatson::new();
+        delaunay.set_points(self.points.clone());
+        delaunay.calculate();
+        self.triangles = delaunay.triangles;
+        let points = self.points.clone();
+        self.triangles
+            .retain(|[a, b, c]| circumcircle(&points[*a], &points[*b], &points[*c]).is_some());
+        if self.triangles.is_empty() {
+            self.add_step(
+                vec![],
+                String::from(
+                    "The Delaunay triangulation has no triangles. The Voronoi diagram needs \
+                     three points in general position.",
+                ),
+            );
+            return;
+        }
+        self.add_step(
+            vec![],
+            format!(
+                "Compute the Delaunay triangulation with Bowyer-Watson. It has {} triangles. \
+                 Press m to toggle the Delaunay layer.",
+                self.triangles.len()
+            ),
+        );
+
+        for index in 0..self.triangles.len() {
+            let triangle = self.triangles[index];
+            let [a, b, c] = triangle.map(|vertex| self.points[vertex]);
+            let (center, radius) = circumcircle(&a, &b, &c).unwrap();
+            self.centers.push(center);
+
+            let mut drawables = self.triangle_lines(&triangle, Color::Yellow);
+            drawables.push(Drawable::circle(&center, radius, Color::Magenta));
+            drawables.push(Drawable::points(&[center], Color::Yellow));
+            self.add_step(
+                drawables,
+                format!(
+                    "The circumcenter of the triangle v{} v{} v{} becomes a Voronoi vertex.",
+                    triangle[0], triangle[1], triangle[2]
+                ),
+            );
+        }
+
+        // Every Delaunay edge is shared by two triangles, except for those on the hull.
+        let mut sharing: HashMap<(usize, usize), Vec<usize>> = HashMap::new();
+        for (index, triangle) in self.triangles.iter().enumerate() {
+            for (from, to) in triangle.iter().circular_tuple_windows() {
+                sharing
+                    .entry((*from.min(to), *from.max(to)))
+                    .or_default()
+                    .push(index);
+            }
+        }
+        let reach = (self.x_bounds[1] - self.x_bounds[0]) + (self.y_bounds[1] - self.y_bounds[0]);
+        let mut edges = sharing.into_iter().collect::<Vec<_>>();
+        edges.sort_by_key(|(edge, _)| *edge);
+
+        for ((from, to), triangles) in edges {
+            let delaunay_edge = Drawable::line(&self.points[from], &self.points[to], Color::Yellow);
+            let (edge, description) = match triangles[..] {
+                [first, second] => (
+                    self.add_edge(self.centers[first], self.centers[second]),
+                    format!(
+                        "The Delaunay edge v{from} - v{to} is shared by two triangles. Connect \
+                         their circumcenters."
+                    ),
+                ),
+                _ => {
+                    // The ray leaves the hull on the side opposite to the third vertex.
+                    let triangle = self.triangles[triangles[0]];
+                    let third = *triangle
+                        .iter()
+                        .find(|vertex| **vertex != from && **vertex != to)
+                        .unwrap();
+                    let direction = self.points[to] - self.points[from];
+                    let mut normal = Vector2::new(direction.y, -direction.x).normalize();
+                    if normal.dot(&(self.points[third] - self.points[from])) > 0.0 {
+                        normal = -normal;
+                    }
+                    let center = self.centers[triangles[0]];
+                    let distance = reach + (center - self.points[from]).norm();
+                    (
+                        self.add_edge(center, center + normal * distance),
+                        format!(
+                            "The Delaunay edge v{from} - v{to} lies on the hull. Its Voronoi \
+                             edge is unbounded and clipped to the bounds."
+                        ),
+                    )
+                }
+            };
+            let drawables = [delaunay_edge].into_iter().chain(edge).collect();
+            self.add_step(drawables, description);
+        }
+
+        self.add_step(
+            vec![],
+            format!(
+                "The Voronoi diagram has {} vertices and {} edges inside the bounds.",
+                self.centers.len(),
+                self.edges.len()
+            ),
+        );
+    }
+}
+
+impl Algorithm for VoronoiDiagram {
+    fn get_title(&self) -> &str {
+        "Voronoi diagram"
+    }
+
+    fn get_points(&self) -> &Vec<Point2<f64>> {
+        &self.points
+    }
+
+    fn set_points(&mut self, points: Vec<Point2<f64>>) {
+        self.points = points;
+    }
+
+    fn set_bounds(&mut self, x_bounds: [f64; 2], y_bounds: [f64; 2]) {
+        self.x_bounds = x_bounds;
+        self.y_bounds = y_bounds;
+    }
+
+    fn switch_mode(&mut self) -> bool {
+        self.show_delaunay = !self.show_delaunay;
+        true
+    }
+
+    fn get_steps(&self) -> Vec<Vec<Drawable>> {
+        self.steps.clone()
+    }
+
+    fn get_draw_method(&self) -> DrawMethod {
+        DrawMethod::Edge
+    }
+
+    fn get_step_description(&self, step: usize) -> Option<String> {
+        self.descriptions.get(step).cloned()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::algorithms::testing::random_points;
+
+    fn voronoi_diagram(points: Vec<Point2<f64>>) -> VoronoiDiagram {
+        let mut voronoi_diagram = VoronoiDiagram::new();
+        voronoi_diagram.set_bounds([0.0, 100.0], [0.0, 100.0]);
+        voronoi_diagram.set_points(points);
+        voronoi_diagram.calculate();
+        voronoi_diagram
+    }
+
+    /// Returns the distances to the two nearest sites.
+    fn nearest_two(points: &[Point2<f64>], point: &Point2<f64>) -> (f64, f64) {
+        let mut distances = points
+            .iter()
+            .map(|site| (site - point).norm())
+            .collect::<Vec<_>>();
+        distances.sort_by(|a, b| a.partial_cmp(b).unwrap());
+        (distances[0], distances[1])
+    }
+
+    #[test]
+    fn small_and_collinear_inputs_have_no_edges() {
+        let line = (0..5).map(|i| Point2::new(10.0 * i as f64, 5.0)).collect();
+        for voronoi_diagram in (0..3)
+            .map(|amount| voronoi_diagram(random_points(amount)))
+            .chain([voronoi_diagram(line)])
+        {
+            assert!(voronoi_diagram.centers.is_empty());
+            assert!(voronoi_diagram.edges.is_empty());
+        }
+    }
+
+    #[test]
+    fn edges_are_equidistant_to_their_nearest_sites() {
+        for amount in 3..40 {
+            let points = random_points(amount);
+            let voronoi_diagram = voronoi_diagram(points.clone());
+            // The circumcircles are empty, so their vertices are the nearest sites.
+            for (center, triangle) in voronoi_diagram
+                .centers
+                .iter()
+                .zip(&voronoi_diagram.triangles)
+            {
+                let (first, second) = nearest_two(&points, center);
+                assert!((first - second).abs() < 1e-6);
+                assert!((first - (points[triangle[0]] - center).norm()).abs() < 1e-6);
+            }
+            for (from, to) in &voronoi_diagram.edges {
+                let (first, second) = nearest_two(&points, &nalgebra::center(from, to));
+                assert!((first - second).abs() < 1e-6);
+            }
+        }
+    }
+}
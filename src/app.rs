@@ -2,12 +2,43 @@ use anyhow::Error;
 use crossterm::event::{KeyCode, KeyEvent};
 use nalgebra::Point2;
 use rand::Rng;
+use std::f64::consts::TAU;
 
-use crate::algorithms::{algorithm::AlgorithmWrapper, graham_scan::GrahamScan};
+use crate::algorithms::{
+    algorithm::{AlgorithmWrapper, InputKind, QueryKind},
+    art_gallery::ArtGallery,
+    bentley_ottmann::BentleyOttmann,
+    bowyer_watson::BowyerWatson,
+    chans_algorithm::ChansAlgorithm,
+    closest_pair::ClosestPair,
+    divide_and_conquer_hull::DivideAndConquerHull,
+    ear_clipping::EarClipping,
+    fortune::Fortune,
+    geometry::{cross_product, Rectangle, Segment},
+    graham_scan::GrahamScan,
+    greiner_hormann::GreinerHormann,
+    jarvis_march::JarvisMarch,
+    kd_tree::KdTree,
+    linear_programming::LinearProgramming,
+    map_overlay::MapOverlay,
+    melkman::Melkman,
+    minimum_enclosing_disk::MinimumEnclosingDisk,
+    minkowski_sum::MinkowskiSum,
+    monotone_triangulation::MonotoneTriangulation,
+    quick_hull::QuickHull,
+    randomized_incremental_delaunay::RandomizedIncrementalDelaunay,
+    randomized_incremental_hull::RandomizedIncrementalHull,
+    range_tree::RangeTree,
+    rotating_calipers::RotatingCalipers,
+    trapezoidal_map::TrapezoidalMap,
+    visibility_graph::VisibilityGraph,
+    voronoi_diagram::VoronoiDiagram,
+};
 
 pub enum InputMode {
     Normal,
     Editing,
+    Query,
 }
 
 pub struct TabsState {
@@ -57,6 +88,14 @@ pub struct App<'a> {
 
     pub input_mode: InputMode,
     pub input: String,
+    pub query: String,
+
+    // The random point cloud shared by all tabs working on one, so that their
+    // algorithms can be compared on the same input.
+    pub points: Vec<Point2<f64>>,
+    // The last accepted query together with its kind, answered by all tabs
+    // taking queries of that kind.
+    pub shared_query: Option<(QueryKind, Vec<f64>)>,
 
     pub x_bounds: [f64; 2],
     pub y_bounds: [f64; 2],
@@ -70,10 +109,47 @@ impl<'a> App<'a> {
             title,
             tab_state: TabsState::new(vec![
                 Tab::new(AlgorithmWrapper::GrahamScan(GrahamScan::new())),
-                Tab::new(AlgorithmWrapper::GrahamScan(GrahamScan::new())),
+                Tab::new(AlgorithmWrapper::JarvisMarch(JarvisMarch::new())),
+                Tab::new(AlgorithmWrapper::QuickHull(QuickHull::new())),
+                Tab::new(AlgorithmWrapper::ChansAlgorithm(ChansAlgorithm::new())),
+                Tab::new(AlgorithmWrapper::DivideAndConquerHull(
+                    DivideAndConquerHull::new(),
+                )),
+                Tab::new(AlgorithmWrapper::RandomizedIncrementalHull(
+                    RandomizedIncrementalHull::new(),
+                )),
+                Tab::new(AlgorithmWrapper::Melkman(Melkman::new())),
+                Tab::new(AlgorithmWrapper::BentleyOttmann(BentleyOttmann::new())),
+                Tab::new(AlgorithmWrapper::EarClipping(EarClipping::new())),
+                Tab::new(AlgorithmWrapper::MonotoneTriangulation(
+                    MonotoneTriangulation::new(),
+                )),
+                Tab::new(AlgorithmWrapper::ArtGallery(ArtGallery::new())),
+                Tab::new(AlgorithmWrapper::BowyerWatson(BowyerWatson::new())),
+                Tab::new(AlgorithmWrapper::RandomizedIncrementalDelaunay(
+                    RandomizedIncrementalDelaunay::new(),
+                )),
+                Tab::new(AlgorithmWrapper::Fortune(Fortune::new())),
+                Tab::new(AlgorithmWrapper::VoronoiDiagram(VoronoiDiagram::new())),
+                Tab::new(AlgorithmWrapper::ClosestPair(ClosestPair::new())),
+                Tab::new(AlgorithmWrapper::KdTree(KdTree::new())),
+                Tab::new(AlgorithmWrapper::RangeTree(RangeTree::new())),
+                Tab::new(AlgorithmWrapper::TrapezoidalMap(TrapezoidalMap::new())),
+                Tab::new(AlgorithmWrapper::MapOverlay(MapOverlay::new())),
+                Tab::new(AlgorithmWrapper::LinearProgramming(LinearProgramming::new())),
+                Tab::new(AlgorithmWrapper::MinimumEnclosingDisk(
+                    MinimumEnclosingDisk::new(),
+                )),
+                Tab::new(AlgorithmWrapper::RotatingCalipers(RotatingCalipers::new())),
+                Tab::new(AlgorithmWrapper::MinkowskiSum(MinkowskiSum::new())),
+                Tab::new(AlgorithmWrapper::GreinerHormann(GreinerHormann::new())),
+                Tab::new(AlgorithmWrapper::VisibilityGraph(VisibilityGraph::new())),
             ]),
             input_mode: InputMode::Normal,
             input: String::new(),
+            query: String::new(),
+            points: vec![],
+            shared_query: None,
             x_bounds,
             y_bounds,
             should_quit: false,
@@ -86,7 +162,7 @@ impl<'a> App<'a> {
         }
         self.tab_state
             .tabs
-            .get(0)
+            .first()
             .expect("The application has no tabs open.")
     }
 
@@ -100,20 +176,291 @@ impl<'a> App<'a> {
         Ok(())
     }
 
-    /// Generates points in random locations bounded by the App structs bounds and
-    /// passes them to its defined algorithm.
+    /// Generates the input of the current tab's algorithm bounded by the App structs
+    /// bounds and passes it to its defined algorithm.
     fn generate_points(&mut self) {
+        let point_amount = self.get_current_tab().point_amount.unwrap_or(0);
+        let (x_bounds, y_bounds) = (self.x_bounds, self.y_bounds);
+        self.get_current_tab_mut()
+            .algorithm
+            .set_bounds(x_bounds, y_bounds);
+
+        match self.get_current_tab().algorithm.get_input_kind() {
+            InputKind::PointCloud => {
+                if self.points.len() != point_amount {
+                    self.points = self.generate_point_cloud(point_amount);
+                }
+                let points = self.points.clone();
+                self.get_current_tab_mut().algorithm.set_points(points);
+            }
+            InputKind::SimplePolyline | InputKind::SimplePolygon => {
+                let points = self.generate_simple_polygon(point_amount);
+                self.get_current_tab_mut().algorithm.set_points(points);
+            }
+            InputKind::CombPolygon => {
+                let points = self.generate_comb_polygon(point_amount);
+                self.get_current_tab_mut().algorithm.set_points(points);
+            }
+            InputKind::Segments => {
+                let segments = self.generate_segments(point_amount);
+                self.get_current_tab_mut().algorithm.set_segments(segments);
+            }
+            InputKind::NonCrossingSegments => {
+                let segments = self.generate_non_crossing_segments(point_amount);
+                self.get_current_tab_mut().algorithm.set_segments(segments);
+            }
+            InputKind::SimplePolygonPair => {
+                let polygons = self.generate_simple_polygon_pair(point_amount);
+                self.get_current_tab_mut().algorithm.set_polygons(polygons);
+            }
+            InputKind::ConvexObstacles => {
+                let obstacles = self.generate_convex_obstacles(point_amount);
+                self.get_current_tab_mut().algorithm.set_polygons(obstacles);
+            }
+        }
+
+        if let Some((kind, query)) = &self.shared_query {
+            let algorithm = &mut self.tab_state.tabs[self.tab_state.index].algorithm;
+            if algorithm.get_query_kind() == Some(*kind) {
+                algorithm.set_query(query.clone());
+            }
+        }
+    }
+
+    /// Generates points in random locations bounded by the App structs bounds.
+    fn generate_point_cloud(&self, point_amount: usize) -> Vec<Point2<f64>> {
         let mut points = vec![];
+        for _ in 0..point_amount {
+            let x = rand::thread_rng().gen_range(self.x_bounds[0]..=self.x_bounds[1]);
+            let y = rand::thread_rng().gen_range(self.y_bounds[0]..=self.y_bounds[1]);
+            let point = Point2::new(x, y);
+            points.push(point);
+        }
+        points
+    }
 
-        if let Some(point_amount) = self.get_current_tab().point_amount {
-            for _ in 0..point_amount {
-                let x = rand::thread_rng().gen_range(self.x_bounds[0]..=self.x_bounds[1]);
-                let y = rand::thread_rng().gen_range(self.y_bounds[0]..=self.y_bounds[1]);
-                let point = Point2::new(x, y);
-                points.push(point);
+    /// Generates segments in random locations bounded by the App structs bounds.
+    /// Their length is limited to a quarter of the bounds in every direction.
+    fn generate_segments(&self, segment_amount: usize) -> Vec<Segment> {
+        let width = (self.x_bounds[1] - self.x_bounds[0]) / 4.0;
+        let height = (self.y_bounds[1] - self.y_bounds[0]) / 4.0;
+
+        self.generate_point_cloud(segment_amount)
+            .into_iter()
+            .map(|from| {
+                let x = from.x + rand::thread_rng().gen_range(-width..=width);
+                let y = from.y + rand::thread_rng().gen_range(-height..=height);
+                let to = Point2::new(
+                    x.clamp(self.x_bounds[0], self.x_bounds[1]),
+                    y.clamp(self.y_bounds[0], self.y_bounds[1]),
+                );
+                Segment::new(from, to)
+            })
+            .collect()
+    }
+
+    /// Generates segments like `generate_segments`, but rejects every segment
+    /// touching the bounds or another segment. Crowded bounds may therefore hold
+    /// fewer segments than requested.
+    fn generate_non_crossing_segments(&self, segment_amount: usize) -> Vec<Segment> {
+        let width = (self.x_bounds[1] - self.x_bounds[0]) / 4.0;
+        let height = (self.y_bounds[1] - self.y_bounds[0]) / 4.0;
+        let inside = |point: &Point2<f64>| {
+            point.x > self.x_bounds[0]
+                && point.x < self.x_bounds[1]
+                && point.y > self.y_bounds[0]
+                && point.y < self.y_bounds[1]
+        };
+
+        let mut segments: Vec<Segment> = vec![];
+        for _ in 0..segment_amount * 100 {
+            if segments.len() == segment_amount {
+                break;
+            }
+            let from = self.generate_point_cloud(1)[0];
+            let to = Point2::new(
+                from.x + rand::thread_rng().gen_range(-width..=width),
+                from.y + rand::thread_rng().gen_range(-height..=height),
+            );
+            let segment = Segment::new(from, to);
+            // Disjoint segments lie completely on one side of each other's line,
+            // at least for one of both lines.
+            let disjoint = |other: &Segment| {
+                let side = |line: &Segment, a: &Point2<f64>, b: &Point2<f64>| {
+                    cross_product(&line.from, &line.to, a) * cross_product(&line.from, &line.to, b)
+                        > 0.0
+                };
+                side(other, &segment.from, &segment.to) || side(&segment, &other.from, &other.to)
+            };
+            if inside(&from) && inside(&to) && segments.iter().all(disjoint) {
+                segments.push(segment);
             }
         }
-        self.get_current_tab_mut().algorithm.set_points(points);
+        segments
+    }
+
+    /// Generates convex polygons in counterclockwise order with pairwise disjoint
+    /// bounding boxes. They keep a margin to the bounds, which leaves the corners
+    /// free for other input. Crowded bounds may hold fewer obstacles than requested.
+    fn generate_convex_obstacles(&self, obstacle_amount: usize) -> Vec<Vec<Point2<f64>>> {
+        let width = self.x_bounds[1] - self.x_bounds[0];
+        let height = self.y_bounds[1] - self.y_bounds[0];
+        let inner = Rectangle::new(
+            Point2::new(
+                self.x_bounds[0] + width / 10.0,
+                self.y_bounds[0] + height / 10.0,
+            ),
+            Point2::new(
+                self.x_bounds[1] - width / 10.0,
+                self.y_bounds[1] - height / 10.0,
+            ),
+        );
+
+        let mut boxes: Vec<Rectangle> = vec![];
+        let mut obstacles = vec![];
+        for _ in 0..obstacle_amount * 100 {
+            if obstacles.len() == obstacle_amount {
+                break;
+            }
+            let center = self.generate_point_cloud(1)[0];
+            let size = rand::thread_rng().gen_range(0.04..=0.1);
+            let radius = [size * width, size * height];
+            let bounding_box = Rectangle::new(
+                Point2::new(center.x - radius[0], center.y - radius[1]),
+                Point2::new(center.x + radius[0], center.y + radius[1]),
+            );
+            if !inner.contains(&bounding_box)
+                || boxes.iter().any(|other| other.intersects(&bounding_box))
+            {
+                continue;
+            }
+
+            // Vertices on an ellipse in the order of their angles form a convex polygon.
+            let vertex_amount = rand::thread_rng().gen_range(3..=6);
+            let slice = TAU / vertex_amount as f64;
+            let obstacle = (0..vertex_amount)
+                .map(|index| {
+                    let angle = (index as f64 + rand::thread_rng().gen_range(0.0..1.0)) * slice;
+                    Point2::new(
+                        center.x + radius[0] * angle.cos(),
+                        center.y + radius[1] * angle.sin(),
+                    )
+                })
+                .collect::<Vec<_>>();
+            boxes.push(bounding_box);
+            obstacles.push(obstacle);
+        }
+        obstacles
+    }
+
+    /// Generates the vertices of a random simple polygon in counterclockwise order.
+    /// Every vertex gets a random angle inside its own slice of a full turn around
+    /// the center of the bounds. This keeps the polygon star-shaped and therefore
+    /// simple, while random distances to the center create deep concavities.
+    fn generate_simple_polygon(&self, point_amount: usize) -> Vec<Point2<f64>> {
+        let center = Point2::new(
+            (self.x_bounds[0] + self.x_bounds[1]) / 2.0,
+            (self.y_bounds[0] + self.y_bounds[1]) / 2.0,
+        );
+        let radius = [
+            (self.x_bounds[1] - self.x_bounds[0]) / 2.0,
+            (self.y_bounds[1] - self.y_bounds[0]) / 2.0,
+        ];
+        self.generate_star_shaped_polygon(point_amount, center, radius)
+    }
+
+    /// Generates a counterclockwise comb whose teeth rise from a common base.
+    /// Unlike star-shaped polygons, no single vertex sees all of it. The teeth
+    /// take three vertices each and leftover vertices bend the base downwards.
+    fn generate_comb_polygon(&self, point_amount: usize) -> Vec<Point2<f64>> {
+        // The base and a single tooth already take five vertices.
+        if point_amount < 5 {
+            return self.generate_simple_polygon(point_amount);
+        }
+        let (width, height) = (
+            self.x_bounds[1] - self.x_bounds[0],
+            self.y_bounds[1] - self.y_bounds[0],
+        );
+        let at = |x: f64, y: f64| {
+            Point2::new(self.x_bounds[0] + x * width, self.y_bounds[0] + y * height)
+        };
+        let teeth = (point_amount - 2) / 3;
+        let spacing = 0.9 / teeth as f64;
+
+        let mut points = vec![at(0.0, 0.1)];
+        let mut leftover = (0..point_amount - 2 - 3 * teeth)
+            .map(|_| rand::thread_rng().gen_range(0.05..0.95))
+            .collect::<Vec<_>>();
+        leftover.sort_by(|a, b| a.partial_cmp(b).unwrap());
+        points.extend(
+            leftover
+                .into_iter()
+                .map(|x| at(x, rand::thread_rng().gen_range(0.0..0.05))),
+        );
+        points.push(at(1.0, 0.1));
+
+        // Each tooth stays within its own column, so the teeth cannot cross.
+        let foot = || rand::thread_rng().gen_range(0.25..0.35);
+        for tooth in (0..teeth).rev() {
+            let column = 0.05 + tooth as f64 * spacing;
+            points.push(at(column + 0.75 * spacing, foot()));
+            points.push(at(
+                column + rand::thread_rng().gen_range(0.0..1.0) * spacing,
+                rand::thread_rng().gen_range(0.85..1.0),
+            ));
+            points.push(at(column + 0.25 * spacing, foot()));
+        }
+        points
+    }
+
+    /// Generates two overlapping simple polygons, splitting the vertices among
+    /// them, each one around a center shifted away from the other one.
+    fn generate_simple_polygon_pair(&self, point_amount: usize) -> Vec<Vec<Point2<f64>>> {
+        let (width, height) = (
+            self.x_bounds[1] - self.x_bounds[0],
+            self.y_bounds[1] - self.y_bounds[0],
+        );
+        let radius = [width / 3.0, height / 3.0];
+        [-1.0, 1.0]
+            .into_iter()
+            // Each polygon needs at least three vertices.
+            .zip([point_amount.div_ceil(2).max(3), (point_amount / 2).max(3)])
+            .map(|(side, amount)| {
+                let center = Point2::new(
+                    (self.x_bounds[0] + self.x_bounds[1]) / 2.0 + side * width / 8.0,
+                    (self.y_bounds[0] + self.y_bounds[1]) / 2.0 + side * height / 10.0,
+                );
+                self.generate_star_shaped_polygon(amount, center, radius)
+            })
+            .collect()
+    }
+
+    /// Generates a polygon whose vertices are ordered by their angle around the
+    /// center and lie within the given radii.
+    fn generate_star_shaped_polygon(
+        &self,
+        point_amount: usize,
+        center: Point2<f64>,
+        radius: [f64; 2],
+    ) -> Vec<Point2<f64>> {
+        let slice = TAU / point_amount as f64;
+        let mut points = (0..point_amount)
+            .map(|index| {
+                let angle = (index as f64 + rand::thread_rng().gen_range(0.0..1.0)) * slice;
+                let distance = rand::thread_rng().gen_range(0.1..=1.0);
+                Point2::new(
+                    center.x + distance * radius[0] * angle.cos(),
+                    center.y + distance * radius[1] * angle.sin(),
+                )
+            })
+            .collect::<Vec<_>>();
+
+        // Three vertices do not necessarily surround the center, so the
+        // triangle might be clockwise.
+        if points.len() == 3 && cross_product(&points[0], &points[1], &points[2]) < 0.0 {
+            points.reverse();
+        }
+        points
     }
 
     pub fn setup_tab(&mut self) {
@@ -122,12 +469,84 @@ impl<'a> App<'a> {
             Some(self.get_current_tab().algorithm.get_maximum_step_count());
     }
 
+    /// Draws new random input of the same size for the current tab and
+    /// recalculates it. A new point cloud replaces the shared one, so every
+    /// other tab that ran on it is recalculated as well.
+    fn regenerate(&mut self) {
+        if self.get_current_tab().max_steps.is_none() {
+            return;
+        }
+        self.points = vec![];
+        self.get_current_tab_mut().step = 0;
+        self.generate_points();
+        self.setup_tab();
+
+        let current = self.tab_state.index;
+        if !matches!(
+            self.get_current_tab().algorithm.get_input_kind(),
+            InputKind::PointCloud
+        ) {
+            return;
+        }
+        for index in 0..self.tab_state.tabs.len() {
+            let tab = &self.tab_state.tabs[index];
+            if index == current
+                || tab.max_steps.is_none()
+                || tab.point_amount != Some(self.points.len())
+                || !matches!(tab.algorithm.get_input_kind(), InputKind::PointCloud)
+            {
+                continue;
+            }
+            self.tab_state.index = index;
+            self.get_current_tab_mut().step = 0;
+            self.generate_points();
+            self.setup_tab();
+        }
+        self.tab_state.index = current;
+    }
+
+    /// Switches the mode of the current tab's algorithm and recalculates it on
+    /// the same input, staying at the current step if possible.
+    fn switch_mode(&mut self) {
+        let tab = self.get_current_tab_mut();
+        if !tab.algorithm.switch_mode() || tab.max_steps.is_none() {
+            return;
+        }
+        let step = tab.step;
+        self.setup_tab();
+        let tab = self.get_current_tab_mut();
+        tab.step = step.min(tab.max_steps.unwrap_or(0).saturating_sub(1));
+    }
+
+    /// Passes the typed in query to the current tab's algorithm and recalculates
+    /// it on the same input, if the query matches the algorithm's format.
+    fn run_query(&mut self) {
+        let query = self
+            .query
+            .split_whitespace()
+            .map(|number| number.parse::<f64>())
+            .collect::<Result<Vec<_>, _>>();
+        let tab = self.get_current_tab_mut();
+        let accepted = match query {
+            Ok(query) if tab.algorithm.set_query(query.clone()) => {
+                self.shared_query = tab.algorithm.get_query_kind().map(|kind| (kind, query));
+                true
+            }
+            _ => false,
+        };
+        let tab = self.get_current_tab_mut();
+        if accepted && tab.max_steps.is_some() {
+            tab.step = 0;
+            self.setup_tab();
+        }
+    }
+
     pub fn on_key(&mut self, key: KeyEvent) -> Result<(), Error> {
         match key.code {
             KeyCode::Right => {
                 let tab = self.get_current_tab_mut();
                 if let Some(max_steps) = tab.max_steps {
-                    if tab.step < max_steps - 1 {
+                    if tab.step < max_steps.saturating_sub(1) {
                         tab.step += 1;
                     }
                 }
@@ -154,6 +573,17 @@ impl<'a> App<'a> {
                 KeyCode::Char('q') => {
                     self.should_quit = true;
                 }
+                KeyCode::Char('m') => {
+                    self.switch_mode();
+                }
+                KeyCode::Char('g') => {
+                    self.regenerate();
+                }
+                KeyCode::Char('r')
+                    if self.get_current_tab().algorithm.get_query_kind().is_some() =>
+                {
+                    self.input_mode = InputMode::Query;
+                }
                 _ => {}
             },
             InputMode::Editing => match key.code {
@@ -162,10 +592,8 @@ impl<'a> App<'a> {
                     self.generate_points();
                     self.setup_tab();
                 }
-                KeyCode::Char(c) => {
-                    if c.is_ascii_digit() {
-                        self.input.push(c);
-                    }
+                KeyCode::Char(c) if c.is_ascii_digit() => {
+                    self.input.push(c);
                 }
                 KeyCode::Backspace => {
                     self.input.pop();
@@ -175,7 +603,181 @@ impl<'a> App<'a> {
                 }
                 _ => {}
             },
+            InputMode::Query => match key.code {
+                KeyCode::Enter => {
+                    self.run_query();
+                    self.input_mode = InputMode::Normal;
+                }
+                KeyCode::Char(c) if c.is_ascii_digit() || c == '-' || c == '.' || c == ' ' => {
+                    self.query.push(c);
+                }
+                KeyCode::Backspace => {
+                    self.query.pop();
+                }
+                KeyCode::Esc => {
+                    self.input_mode = InputMode::Normal;
+                }
+                _ => {}
+            },
         };
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crossterm::event::KeyModifiers;
+    use itertools::Itertools;
+
+    use super::*;
+
+    fn is_simple(polygon: &[Point2<f64>]) -> bool {
+        let edges = polygon
+            .iter()
+            .circular_tuple_windows()
+            .map(|(from, to)| Segment::new(*from, *to))
+            .collect::<Vec<_>>();
+        edges
+            .iter()
+            .tuple_combinations()
+            .all(|(a, b)| a.intersection(b).is_none())
+    }
+
+    fn signed_area(polygon: &[Point2<f64>]) -> f64 {
+        polygon
+            .iter()
+            .circular_tuple_windows()
+            .map(|(from, to)| from.x * to.y - to.x * from.y)
+            .sum::<f64>()
+            / 2.0
+    }
+
+    fn press(app: &mut App, code: KeyCode) {
+        app.on_key(KeyEvent::new(code, KeyModifiers::NONE)).unwrap();
+    }
+
+    fn run(app: &mut App, amount: &str) {
+        press(app, KeyCode::Char('i'));
+        for digit in amount.chars() {
+            press(app, KeyCode::Char(digit));
+        }
+        press(app, KeyCode::Enter);
+        press(app, KeyCode::Esc);
+        app.input.clear();
+    }
+
+    /// Returns the input of the Graham scan or Jarvis march tab.
+    fn hull_input(app: &App, index: usize) -> Vec<Point2<f64>> {
+        match &app.tab_state.tabs[index].algorithm {
+            AlgorithmWrapper::GrahamScan(algorithm) => algorithm.points.clone(),
+            AlgorithmWrapper::JarvisMarch(algorithm) => algorithm.points.clone(),
+            _ => unreachable!(),
+        }
+    }
+
+    #[test]
+    fn point_cloud_tabs_share_their_input() {
+        let mut app = App::new("test", [0.0, 100.0], [0.0, 100.0]);
+        run(&mut app, "20");
+        press(&mut app, KeyCode::Tab);
+        run(&mut app, "20");
+        let points = hull_input(&app, 0);
+        assert_eq!(points.len(), 20);
+        assert_eq!(hull_input(&app, 1), points);
+
+        // Regenerating replaces the shared points in every tab that ran on them.
+        app.tab_state.tabs[0].step = 1;
+        press(&mut app, KeyCode::Char('g'));
+        assert_ne!(hull_input(&app, 1), points);
+        assert_eq!(hull_input(&app, 1), app.points);
+        assert_eq!(hull_input(&app, 0), app.points);
+        assert_eq!(app.tab_state.tabs[0].step, 0);
+        assert_eq!(app.tab_state.index, 1);
+
+        // Another amount draws new points.
+        press(&mut app, KeyCode::BackTab);
+        run(&mut app, "10");
+        assert_eq!(hull_input(&app, 0).len(), 10);
+    }
+
+    /// Returns the points reported by the k-d tree or range tree tab.
+    fn reported(app: &App, index: usize) -> Vec<usize> {
+        let mut reported = match &app.tab_state.tabs[index].algorithm {
+            AlgorithmWrapper::KdTree(algorithm) => algorithm.reported.clone(),
+            AlgorithmWrapper::RangeTree(algorithm) => algorithm.reported.clone(),
+            _ => unreachable!(),
+        };
+        reported.sort();
+        reported
+    }
+
+    #[test]
+    fn trees_answer_the_same_query_on_the_same_points() {
+        let mut app = App::new("test", [0.0, 100.0], [0.0, 100.0]);
+        let kd_tree = app
+            .tab_state
+            .tabs
+            .iter()
+            .position(|tab| matches!(tab.algorithm, AlgorithmWrapper::KdTree(_)))
+            .unwrap();
+        let range_tree = app
+            .tab_state
+            .tabs
+            .iter()
+            .position(|tab| matches!(tab.algorithm, AlgorithmWrapper::RangeTree(_)))
+            .unwrap();
+
+        app.tab_state.index = kd_tree;
+        run(&mut app, "50");
+        press(&mut app, KeyCode::Char('r'));
+        for c in "10 20 70 60".chars() {
+            press(&mut app, KeyCode::Char(c));
+        }
+        press(&mut app, KeyCode::Enter);
+        assert!(matches!(app.shared_query, Some((QueryKind::Rectangle, _))));
+        app.tab_state.index = range_tree;
+        run(&mut app, "50");
+
+        assert!(!reported(&app, kd_tree).is_empty());
+        assert_eq!(reported(&app, kd_tree), reported(&app, range_tree));
+    }
+
+    #[test]
+    fn tabs_have_distinct_titles() {
+        let app = App::new("test", [0.0, 100.0], [0.0, 100.0]);
+        let titles = app
+            .tab_state
+            .tabs
+            .iter()
+            .map(|tab| tab.algorithm.get_title())
+            .collect::<Vec<_>>();
+        assert!(titles.iter().all(|title| !title.is_empty()));
+        assert!(titles.iter().all_unique());
+    }
+
+    #[test]
+    fn empty_timelines_keep_the_first_step() {
+        let mut app = App::new("test", [0.0, 100.0], [0.0, 100.0]);
+        for index in 0..app.tab_state.tabs.len() {
+            app.tab_state.index = index;
+            app.get_current_tab_mut().max_steps = Some(0);
+            app.on_key(KeyEvent::new(KeyCode::Right, KeyModifiers::NONE))
+                .unwrap();
+            app.switch_mode();
+            assert_eq!(app.get_current_tab().step, 0);
+        }
+    }
+
+    #[test]
+    fn combs_are_simple_and_counterclockwise() {
+        let app = App::new("test", [0.0, 100.0], [0.0, 100.0]);
+        for amount in 0..40 {
+            let comb = app.generate_comb_polygon(amount);
+            assert_eq!(comb.len(), amount);
+            assert!(is_simple(&comb));
+            if amount >= 3 {
+                assert!(signed_area(&comb) > 0.0);
+            }
+        }
+    }
+}
@@ -7,10 +7,7 @@ use tui::{
     Frame,
 };
 
-use crate::{
-    algorithms::algorithm::{Algorithm, AlgorithmWrapper},
-    app::App,
-};
+use crate::app::App;
 
 use super::{footer, header};
 
@@ -33,13 +30,10 @@ pub fn draw<B: Backend>(f: &mut Frame<B>, app: &App) {
         .tabs
         .iter()
         .map(|tab| {
-            let title = match &tab.algorithm {
-                AlgorithmWrapper::GrahamScan(algorithm) => Spans::from(Span::styled(
-                    algorithm.get_title(),
-                    Style::default().fg(Color::Gray),
-                )),
-            };
-            title
+            Spans::from(Span::styled(
+                tab.algorithm.get_title(),
+                Style::default().fg(Color::Gray),
+            ))
         })
         .collect();
 
@@ -49,9 +43,43 @@ pub fn draw<B: Backend>(f: &mut Frame<B>, app: &App) {
         .select(app.tab_state.index);
     f.render_widget(tabs, chunks[0]);
 
-    header::draw(f, chunks[1]);
+    header::draw(f, chunks[1], app);
 
     app.get_current_tab().algorithm.draw(f, chunks[2], app);
 
     footer::draw(f, chunks[3], app, app.get_current_tab());
 }
+
+#[cfg(test)]
+mod tests {
+    use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+    use tui::{backend::TestBackend, Terminal};
+
+    use super::*;
+
+    fn press(app: &mut App, code: KeyCode) {
+        app.on_key(KeyEvent::new(code, KeyModifiers::NONE)).unwrap();
+    }
+
+    #[test]
+    fn small_inputs_draw_every_step() {
+        let mut terminal = Terminal::new(TestBackend::new(160, 50)).unwrap();
+        let mut app = App::new("test", [0.0, 100.0], [0.0, 100.0]);
+        for _ in 0..app.tab_state.tabs.len() {
+            for amount in 0..8 {
+                for code in [KeyCode::Char('i'), KeyCode::Char(char::from(b'0' + amount))] {
+                    press(&mut app, code);
+                }
+                press(&mut app, KeyCode::Enter);
+                app.input.clear();
+
+                let steps = app.get_current_tab().max_steps.unwrap();
+                for _ in 0..steps.max(1) {
+                    terminal.draw(|f| draw(f, &app)).unwrap();
+                    press(&mut app, KeyCode::Right);
+                }
+            }
+            press(&mut app, KeyCode::Tab);
+        }
+    }
+}
@@ -13,11 +13,13 @@ pub fn draw_progress<B>(f: &mut Frame<B>, area: Rect, app: &App)
 where
     B: Backend,
 {
-    let mut ratio = 0.0;
-    if app.get_current_tab().algorithm.get_maximum_step_count() > 0 {
-        ratio = app.get_current_tab().step as f64
-            / (app.get_current_tab().algorithm.get_maximum_step_count() - 1) as f64;
-    }
+    // A timeline with a single step is complete right away.
+    let maximum_step_count = app.get_current_tab().algorithm.get_maximum_step_count();
+    let ratio = if maximum_step_count > 1 {
+        (app.get_current_tab().step as f64 / (maximum_step_count - 1) as f64).min(1.0)
+    } else {
+        1.0
+    };
     let label = format!("{:.2}%", ratio * 100.0);
     let gauge = Gauge::default()
         .block(Block::default().borders(Borders::ALL).title("Progress"))
@@ -41,9 +43,14 @@ where
         .constraints([Constraint::Percentage(50), Constraint::Percentage(50)].as_ref())
         .split(vertical_chunks[1]);
 
+    let query_kind = app.get_current_tab().algorithm.get_query_kind();
+    let (title, input) = match (&app.input_mode, query_kind) {
+        (InputMode::Query, Some(kind)) => (format!("Query: {}", kind.format()), &app.query),
+        _ => (String::from("Point amount"), &app.input),
+    };
     let block = Block::default()
         .borders(Borders::ALL)
-        .title(Span::styled("Point amount", Style::default()));
+        .title(Span::styled(title, Style::default()));
 
     let (msg, style) = match app.input_mode {
         InputMode::Normal => (
@@ -52,7 +59,14 @@ where
                 Span::styled("q", Style::default().add_modifier(Modifier::BOLD)),
                 Span::raw(" to exit, "),
                 Span::styled("i", Style::default().add_modifier(Modifier::BOLD)),
-                Span::raw(" to edit the point amount."),
+                Span::raw(" to edit the point amount, "),
+                Span::styled("g", Style::default().add_modifier(Modifier::BOLD)),
+                Span::raw(" for new points"),
+                Span::raw(if query_kind.is_some() {
+                    ", r to enter a query."
+                } else {
+                    "."
+                }),
             ],
             Style::default().add_modifier(Modifier::RAPID_BLINK),
         ),
@@ -66,6 +80,16 @@ where
             ],
             Style::default(),
         ),
+        InputMode::Query => (
+            vec![
+                Span::raw("Press "),
+                Span::styled("Esc", Style::default().add_modifier(Modifier::BOLD)),
+                Span::raw(" to stop editing, "),
+                Span::styled("Enter", Style::default().add_modifier(Modifier::BOLD)),
+                Span::raw(" to run the query."),
+            ],
+            Style::default(),
+        ),
     };
 
     let mut text = Text::from(Spans::from(msg));
@@ -73,18 +97,18 @@ where
     let help_message = Paragraph::new(text);
     f.render_widget(help_message, vertical_chunks[0]);
 
-    let input = Paragraph::new(app.input.as_ref())
+    let input_paragraph = Paragraph::new(input.as_ref())
         .style(match app.input_mode {
             InputMode::Normal => Style::default(),
-            InputMode::Editing => Style::default().fg(Color::Yellow),
+            InputMode::Editing | InputMode::Query => Style::default().fg(Color::Yellow),
         })
         .block(block);
-    f.render_widget(input, horizontal_chunks[0]);
+    f.render_widget(input_paragraph, horizontal_chunks[0]);
 
     match app.input_mode {
         InputMode::Normal => {}
-        InputMode::Editing => f.set_cursor(
-            horizontal_chunks[0].x + app.input.len() as u16 + 1,
+        InputMode::Editing | InputMode::Query => f.set_cursor(
+            horizontal_chunks[0].x + input.len() as u16 + 1,
             horizontal_chunks[0].y + 1,
         ),
     }
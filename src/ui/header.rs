@@ -7,16 +7,57 @@ use tui::{
     Frame,
 };
 
-pub fn draw<B>(f: &mut Frame<B>, area: Rect)
+use crate::app::App;
+
+pub fn draw<B>(f: &mut Frame<B>, area: Rect, app: &App)
 where
     B: Backend,
 {
-    let text = vec![Spans::from(
-        "This will be the section explaining the graham scan algorithm.",
-    )];
+    let tab = app.get_current_tab();
+    let title = tab.algorithm.get_title();
+    // Not every algorithm describes its steps, so fall back to the progress.
+    let description = match (tab.algorithm.get_step_description(tab.step), tab.max_steps) {
+        (Some(description), _) => description,
+        (None, None) => format!(
+            "Press i, type a point amount and press Enter to run {title}. Step through it with \
+             the arrow keys."
+        ),
+        (None, Some(0)) => format!("{title} has no steps for this input."),
+        (None, Some(max_steps)) => format!("{title}: step {} of {max_steps}.", tab.step + 1),
+    };
+    let text = vec![Spans::from(description)];
     let block = Block::default()
         .borders(Borders::ALL)
         .title(Span::styled("Description", Style::default()));
     let paragraph = Paragraph::new(text).block(block).wrap(Wrap { trim: true });
     f.render_widget(paragraph, area);
 }
+
+#[cfg(test)]
+mod tests {
+    use tui::{backend::TestBackend, Terminal};
+
+    use super::*;
+
+    fn render(app: &App) -> String {
+        let mut terminal = Terminal::new(TestBackend::new(200, 5)).unwrap();
+        terminal.draw(|f| draw(f, f.size(), app)).unwrap();
+        terminal
+            .backend()
+            .buffer()
+            .content()
+            .iter()
+            .map(|cell| cell.symbol.as_str())
+            .collect()
+    }
+
+    #[test]
+    fn falls_back_to_the_title_and_progress() {
+        let mut app = App::new("test", [0.0, 100.0], [0.0, 100.0]);
+        assert!(render(&app).contains("to run Graham scan."));
+
+        app.get_current_tab_mut().algorithm.set_points(vec![]);
+        app.setup_tab();
+        assert!(render(&app).contains("Graham scan has no steps for this input."));
+    }
+}